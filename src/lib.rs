@@ -38,6 +38,22 @@
 //!     .to_string();
 //! ```
 //!
+//! # Optional features
+//!
+//! Scooby has no dependencies by default. Some optional features pull them in:
+//!
+//! - `smallvec`: store items of common clauses inline, saving a few allocations per statement
+//! - `test-helpers`: helpers to check statements against a real PostgreSQL server in tests
+//! - `postgis`: PostGIS functions and operators in `postgres::postgis`
+//! - `pgvector`: pgvector distance operators in `postgres::pgvector`
+//! - `timescale`: TimescaleDB functions and statements in `postgres::timescale`
+//! - `chrono`: literals from `chrono` dates and times
+//! - `time`: literals from `time` dates and times
+//! - `uuid`: literals from `uuid::Uuid`
+//! - `rust_decimal`: literals from `rust_decimal::Decimal`
+//! - `bigdecimal`: literals from `bigdecimal::BigDecimal`
+//! - `json`: literals from `serde_json::Value`
+//!
 //! # Requirements
 //!
 //! Requires Rust 1.54 or later.
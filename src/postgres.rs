@@ -4,29 +4,125 @@
 //!
 //! See each function's docs for details on supported clauses and features.
 //!
-//! | Entry function   | SQL statement                          |
-//! |------------------|----------------------------------------|
-//! | [`select`]       | `SELECT`                               |
-//! | [`from`]         | `SELECT` (starting from `FROM` clause) |
-//! | [`insert_into`]  | `INSERT INTO`                          |
-//! | [`delete_from`]  | `DELETE FROM`                          |
-//! | [`update`]       | `UPDATE`                               |
-//! | [`with`]         | `WITH`                                 |
-//! | [`create_table`] | `CREATE TABLE`                         |
+//! | Entry function                  | SQL statement                          |
+//! |---------------------------------|----------------------------------------|
+//! | [`select`]                      | `SELECT`                               |
+//! | [`from`]                        | `SELECT` (starting from `FROM` clause) |
+//! | [`insert_into`]                 | `INSERT INTO`                          |
+//! | [`delete_from`]                 | `DELETE FROM`                          |
+//! | [`update`]                      | `UPDATE`                               |
+//! | [`with`]                        | `WITH`                                 |
+//! | [`create_table`]                | `CREATE TABLE`                         |
+//! | [`do_block`]                    | `DO`                                   |
+//! | [`set_role`]                    | `SET ROLE`                             |
+//! | [`reset_role`]                  | `RESET ROLE`                           |
+//! | [`set_session_authorization`]   | `SET SESSION AUTHORIZATION`            |
+//! | [`reset_session_authorization`] | `RESET SESSION AUTHORIZATION`          |
+//! | [`alter_default_privileges`]    | `ALTER DEFAULT PRIVILEGES`             |
+//!
+//! # Passing one or more items
+//!
+//! Methods that add items to a clause accept a single item, a tuple or an array of items,
+//! a slice of `&str`, or a `Vec` of items. Wrap other iterators with [`tools::items`]:
+//!
+//! ```
+//! use scooby::postgres::select;
+//! use scooby::postgres::tools::items;
+//!
+//! let columns = vec!["id", "name"];
+//! let conditions = items(columns.iter().map(|c| format!("{} IS NOT NULL", c)));
+//!
+//! let sql = select(&columns).from("Person").where_(conditions).to_string();
+//!
+//! assert_eq!(sql, "SELECT id, name FROM Person WHERE id IS NOT NULL AND name IS NOT NULL");
+//! ```
+//!
+//! # Building expressions and conditions
+//!
+//! Expressions and conditions are plain SQL strings, but these extension traits and functions
+//! help to spell common operators correctly:
+//!
+//! | Helper            | Description                                                 |
+//! |-------------------|-------------------------------------------------------------|
+//! | [`Comparable`]    | `BETWEEN`, `LIKE`, `IS NULL`, `@>`, `&&`, `= ANY(...)` etc. |
+//! | [`JsonOperable`]  | `->`, `->>`, `#>`, `?`, `jsonb_path_query` etc.             |
+//! | [`RangeOperable`] | `lower`, `upper`, adjacency, `<<` and `>>`                  |
+//! | [`LtreeOperable`] | `ltree` paths: `<@`, `@>`, `~` with `lquery` etc.           |
+//! | [`TextOperable`]  | Concatenation, `lower`, `upper`, `left`, `right` etc.       |
+//! | [`Castable`]      | `CAST(x AS type)` and `x::type`                             |
+//! | [`Collatable`]    | `COLLATE`                                                   |
+//! | [`Qualifiable`]   | `t.*` and `s.t`, see also [`star`]                          |
+//! | [`func`]          | Function and aggregate calls, such as `count(DISTINCT x)`   |
+//! | [`exists`]        | `EXISTS (...)` and other subqueries, see also [`subquery`]  |
+//! | [`compare_rows`]  | `(a, b) < (c, d)` row comparison                            |
+//! | [`raw`]           | Explicitly hand-written fragments of SQL                    |
+//! | [`array`]         | `ARRAY[...]` constructor                                    |
+//! | [`row`]           | `ROW(...)` constructor                                      |
+//! | [`json_table`]    | `JSON_TABLE(...)` and `json_to_recordset(...)` in `FROM`    |
+//! | [`range`]         | `daterange(...)` and other range constructors               |
+//!
+//! ```
+//! use scooby::postgres::{array, select, Comparable, JsonOperable};
+//!
+//! let sql = select("data".json_get_text("'title'"))
+//!     .from("Post")
+//!     .where_(("tags".overlaps(array(("'rust'", "'sql'"))), "deleted_at".is_null()))
+//!     .to_string();
+//!
+//! assert_eq!(sql, "SELECT data ->> 'title' FROM Post WHERE tags && ARRAY['rust', 'sql'] AND deleted_at IS NULL");
+//! ```
 //!
 //! # Tools
 //!
-//! | Tool           | Description                                   |
-//! |----------------|-----------------------------------------------|
-//! | [`Parameters`] | Generator of statement parameter placeholders |
+//! | Tool                | Description                                   |
+//! |---------------------|-----------------------------------------------|
+//! | [`Parameters`]      | Generator of statement parameter placeholders |
+//! | [`Render`]          | Rendering of statements into existing buffers |
+//! | [`quote_ident`]     | Quoting of identifiers such as `"user"`       |
+//! | [`checked_ident`]   | Checking of identifiers from outside sources  |
+//! | [`tools::Cached`]   | Statements rendered once for repeated use     |
+//! | [`tools::Template`] | Statements with named slots to fill later     |
+//! | [`tools::SetLocal`] | Statements preceded by `SET LOCAL` settings   |
+//! | [`tools::items`]    | Clause items from any iterator                |
+//!
+//! With the `test-helpers` feature, `tools::testing` also provides helpers to check statements against a real PostgreSQL server in your own tests.
+//!
+//! # Reusable tables and columns
+//!
+//! See the [`schema`] module to declare tables and columns once and use them across statements.
+//!
+//! # Extensions
+//!
+//! Helpers for popular extensions are available behind features of the same name:
+//!
+//! | Feature     | Module      | Extension                                        |
+//! |-------------|-------------|--------------------------------------------------|
+//! | `postgis`   | `postgis`   | [PostGIS](https://postgis.net/)                  |
+//! | `pgvector`  | `pgvector`  | [pgvector](https://github.com/pgvector/pgvector) |
+//! | `timescale` | `timescale` | [TimescaleDB](https://www.timescale.com/)        |
 //!
 pub mod general;
+#[cfg(feature = "pgvector")]
+pub mod pgvector;
+#[cfg(feature = "postgis")]
+pub mod postgis;
+pub mod schema;
 pub mod statements;
+#[cfg(feature = "timescale")]
+pub mod timescale;
 pub mod tools;
 
-pub use general::{with, Aliasable};
+pub use general::{
+    array, compare_rows, concat_ws, count, count_all, exists, func, json_table, json_to_recordset,
+    jsonb_to_recordset, multirange, not_exists, range, raw, row, star, subquery, with, Aliasable,
+    Bounds, Castable, Collatable, Comparable, JsonOperable, JsonTableColumn, LtreeOperable,
+    Qualifiable, RangeOperable, TextOperable,
+};
 pub use statements::{
-    create_table, delete_from, from, insert_into, select, update, ColumnDefinitionable,
-    CreateTable, DeleteFrom, FromSelectBuilder, InsertInto, Joinable, Orderable, Select, Update,
+    alter_default_privileges, create_table, delete_from, do_block, from, insert_into, like,
+    reset_role, reset_session_authorization, select, select_all_of, set_role,
+    set_session_authorization, table, update, ColumnDefinitionable, CreateTable, DeleteFrom,
+    Direction, FromSelectBuilder, HasReturning, HasWhere, HasWith, InsertInto, Joinable,
+    LikeOption, OnCommit, Orderable, ReferentialAction, Select, Statement, TableConstraint, Update,
 };
-pub use tools::Parameters;
+pub use tools::{checked_ident, quote_ident, Parameters, Render};
@@ -1,16 +1,60 @@
 //! Bits that are usable in different types of statements
 
 mod alias;
+mod array;
+mod cast;
+mod collate;
 mod column;
+mod column_type;
 mod column_value;
+mod comment;
+mod comparable;
+mod datetime;
 mod expression;
+mod filter_set;
+mod function_call;
+mod json;
+mod json_table;
+mod literals;
+mod ltree;
+mod range;
+mod raw;
+mod row;
+mod row_comparison;
+mod star;
+mod storage_parameter;
+mod subquery;
+mod text;
 mod with;
 
 pub use alias::{Alias, Aliasable};
+pub use array::array;
+pub use cast::Castable;
+pub use collate::{Collatable, Collation};
 pub use column::Column;
+pub use column_type::ColumnTypePair;
 pub use column_value::ColumnValuePair;
-pub use expression::Expression;
-pub use with::{with, WithClause, WithQuery, WithQueryBuilder};
+pub use comment::Comment;
+pub(crate) use comment::{sanitize as sanitize_comment, Comments};
+pub use comparable::Comparable;
+pub use expression::{Expression, Literal};
+pub use filter_set::{FilterError, FilterOp, FilterSet};
+pub use function_call::{count, count_all, func, FunctionCall};
+pub use json::JsonOperable;
+pub use json_table::{
+    json_table, json_to_recordset, jsonb_to_recordset, JsonTable, JsonTableBuilder,
+    JsonTableColumn, RecordsetFunction,
+};
+pub use ltree::LtreeOperable;
+pub use range::{multirange, range, Bounds, Range, RangeOperable};
+pub use raw::{raw, Raw};
+pub use row::row;
+pub use row_comparison::compare_rows;
+pub use star::{star, Qualifiable};
+pub use storage_parameter::StorageParameter;
+pub use subquery::{exists, not_exists, subquery};
+pub use text::{concat_ws, TextOperable};
+pub use with::{with, UsableInWithQuery, WithClause, WithQuery, WithQueryBuilder};
 
 pub type SortExpression = String;
 pub type Condition = String;
@@ -1,11 +1,12 @@
 use std::fmt::{self, Display, Formatter};
 use std::iter::{once, Once};
 
+use crate::postgres::tools::{checked_ident, InvalidIdentifier};
 use crate::postgres::Select;
 use crate::tools::IntoIteratorOfSameType;
 
 /// `x AS y` alias
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Alias {
     original: String,
     alias: String,
@@ -28,6 +29,23 @@ impl From<Alias> for String {
 /// Strings and `SELECT` statements really.
 pub trait Aliasable {
     fn as_(self, alias: &str) -> Alias;
+
+    /// Alias this, checking that the alias is a plain identifier with [`checked_ident`]
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Aliasable};
+    ///
+    /// assert_eq!(select("count(*)".as_checked("total").unwrap()).to_string(), "SELECT count(*) AS total");
+    /// assert!("count(*)".as_checked("total FROM secrets --").is_err());
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn as_checked(self, alias: &str) -> Result<Alias, InvalidIdentifier>
+    where
+        Self: Sized,
+    {
+        let alias = checked_ident(alias)?;
+        Ok(self.as_(&alias))
+    }
 }
 
 impl<T> Aliasable for T
@@ -0,0 +1,46 @@
+use crate::tools::{joined, IntoIteratorOfSameType};
+
+use super::Expression;
+
+/// Construct an array out of the given elements, `ARRAY[a, b, c]` style
+///
+/// Use together with [`Comparable`][super::Comparable] to filter by array columns.
+///
+/// ```
+/// use scooby::postgres::{array, select, Comparable};
+///
+/// let sql = select("*").from("Post").where_("tags".overlaps(array(("$1", "$2")))).to_string();
+///
+/// assert_eq!(sql, "SELECT * FROM Post WHERE tags && ARRAY[$1, $2]");
+/// ```
+///
+/// An empty array needs an explicit type, for example `"'{}'::text[]"`, as PostgreSQL can't infer one for `ARRAY[]`.
+pub fn array(elements: impl IntoIteratorOfSameType<Expression>) -> Expression {
+    let elements: Vec<Expression> = elements.into_some_iter().collect();
+    Expression::from(format!("ARRAY[{}]", joined(&elements, ", ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{array, select, Comparable};
+
+    #[test]
+    fn array_operators() {
+        let sql = select(array(("1", "2", "3")))
+            .from("Post")
+            .where_((
+                "author_id".eq_any("$1"),
+                "editor_id".ne_all("$1"),
+                "tags".overlaps(array("'rust'")),
+                "tags".includes(array(("'rust'", "'sql'"))),
+                "tags".included_in("$2"),
+            ))
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "SELECT ARRAY[1, 2, 3] FROM Post WHERE author_id = ANY($1) AND editor_id <> ALL($1) AND tags && ARRAY['rust'] AND tags @> ARRAY['rust', 'sql'] AND tags <@ $2",
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use super::Expression;
+
+/// Things that may be converted to another type, `CAST(x AS type)` or `x::type` style
+///
+/// Implemented for everything that can be converted into an [`Expression`].
+pub trait Castable {
+    /// Convert this expression to another type, `CAST(x AS type)` style
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Castable};
+    ///
+    /// let sql = select("*").from("Person").where_(format!("id = {}", "$1".cast("uuid"))).to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Person WHERE id = CAST($1 AS uuid)");
+    /// ```
+    fn cast(self, type_name: impl Into<String>) -> Expression;
+
+    /// Convert this expression to another type using PostgreSQL-specific `x::type` syntax
+    ///
+    /// `::` binds tighter than any other operator, so the expression is parenthesized
+    /// unless it is a plain identifier, number, string constant, placeholder or already parenthesized.
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Castable};
+    ///
+    /// let sql = select(("created_at".cast_short("date"), "a + b".cast_short("int")))
+    ///     .from("Person")
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "SELECT created_at::date, (a + b)::int FROM Person");
+    /// ```
+    fn cast_short(self, type_name: impl Into<String>) -> Expression;
+}
+
+impl<T> Castable for T
+where
+    T: Into<Expression>,
+{
+    fn cast(self, type_name: impl Into<String>) -> Expression {
+        Expression::from(format!("CAST({} AS {})", self.into(), type_name.into()))
+    }
+
+    fn cast_short(self, type_name: impl Into<String>) -> Expression {
+        let expression = String::from(self.into());
+
+        if is_atomic(&expression) {
+            Expression::from(format!("{}::{}", expression, type_name.into()))
+        } else {
+            Expression::from(format!("({})::{}", expression, type_name.into()))
+        }
+    }
+}
+
+/// Whether nothing can bind to parts of this expression more tightly than `::` binds to all of it
+fn is_atomic(expression: &str) -> bool {
+    let bytes = expression.as_bytes();
+
+    match bytes.first() {
+        None => false,
+        Some(b'$') => bytes.len() > 1 && bytes[1..].iter().all(u8::is_ascii_digit),
+        Some(b'0'..=b'9') => bytes.iter().all(|&b| b.is_ascii_digit() || b == b'.'),
+        Some(b'\'') => closing_quote(bytes, 0) == Some(bytes.len() - 1),
+        Some(b'(') => closing_parenthesis(bytes) == Some(bytes.len() - 1),
+        Some(_) => is_identifier_chain(bytes),
+    }
+}
+
+fn is_identifier_chain(bytes: &[u8]) -> bool {
+    let mut i = 0;
+
+    loop {
+        match bytes.get(i) {
+            Some(b'"') => match closing_quote(bytes, i) {
+                Some(end) => i = end + 1,
+                None => return false,
+            },
+            Some(b) if b.is_ascii_alphabetic() || *b == b'_' || !b.is_ascii() => {
+                while matches!(bytes.get(i), Some(&b) if is_identifier_byte(b)) {
+                    i += 1;
+                }
+            }
+            _ => return false,
+        }
+
+        match bytes.get(i) {
+            None => return true,
+            Some(b'.') => i += 1,
+            Some(_) => return false,
+        }
+    }
+}
+
+fn is_identifier_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'$' || !byte.is_ascii()
+}
+
+/// Find the quote closing the one at `start`, treating doubled quotes as escaped
+fn closing_quote(bytes: &[u8], start: usize) -> Option<usize> {
+    let quote = bytes[start];
+    let mut i = start + 1;
+
+    while i < bytes.len() {
+        if bytes[i] == quote {
+            if bytes.get(i + 1) == Some(&quote) {
+                i += 2;
+                continue;
+            }
+            return Some(i);
+        }
+        i += 1;
+    }
+
+    None
+}
+
+/// Find the parenthesis closing the one at the start, skipping over quoted parts
+fn closing_parenthesis(bytes: &[u8]) -> Option<usize> {
+    let mut depth = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'(' => depth += 1,
+            b')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            b'\'' | b'"' => i = closing_quote(bytes, i)?,
+            _ => {}
+        }
+        i += 1;
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::postgres::general::Expression;
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{select, Castable, Comparable, JsonOperable};
+
+    #[test]
+    fn casts() {
+        let sql = select(("$1".cast("uuid"), "(1 + 2)".cast_short("text")))
+            .where_("$2".cast_short("int").between("1", "10"))
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "SELECT CAST($1 AS uuid), (1 + 2)::text WHERE $2::int BETWEEN 1 AND 10",
+        );
+    }
+
+    #[test]
+    fn short_casts_of_compound_expressions() {
+        let sql = select((
+            "data".json_get_text("'age'").cast_short("int"),
+            Expression::from(-2).cast_short("text"),
+            "(a) + (b)".cast_short("int"),
+            "'it''s'".cast_short("text"),
+            "'a' || 'b'".cast_short("text"),
+            r#"p."Full Name""#.cast_short("text"),
+            "1.5".cast_short("real"),
+        ))
+        .from("Person AS p")
+        .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            r#"SELECT (data ->> 'age')::int, (-2)::text, ((a) + (b))::int, 'it''s'::text, ('a' || 'b')::text, p."Full Name"::text, 1.5::real FROM Person AS p"#,
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::tools::quoted;
+
+use super::Expression;
+
+/// Name of a collation, rendered quoted as `"name"`
+///
+/// Collation names such as `de-DE-x-icu` are almost never valid bare identifiers,
+/// so unlike most other things, these are always quoted.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Collation(String);
+
+impl<T: Into<String>> From<T> for Collation {
+    fn from(name: T) -> Self {
+        Collation(name.into())
+    }
+}
+
+impl Display for Collation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        quoted(&self.0).fmt(f)
+    }
+}
+
+/// Things that may have a collation applied to them, `x COLLATE "y"` style
+pub trait Collatable {
+    /// Apply a `COLLATE` clause to this expression
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Collatable};
+    ///
+    /// let sql = select("name".collate("und-x-icu")).from("Person").to_string();
+    ///
+    /// assert_eq!(sql, r#"SELECT name COLLATE "und-x-icu" FROM Person"#);
+    /// ```
+    ///
+    /// Collated expressions may be sorted as well:
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Collatable, Orderable};
+    ///
+    /// let sql = select("name").from("Person").order_by("name".collate("de-DE-x-icu").asc()).to_string();
+    ///
+    /// assert_eq!(sql, r#"SELECT name FROM Person ORDER BY name COLLATE "de-DE-x-icu" ASC"#);
+    /// ```
+    fn collate(self, collation: impl Into<Collation>) -> Expression;
+}
+
+impl<T> Collatable for T
+where
+    T: Into<Expression>,
+{
+    fn collate(self, collation: impl Into<Collation>) -> Expression {
+        Expression::from(format!("{} COLLATE {}", self.into(), collation.into()))
+    }
+}
@@ -1,11 +1,44 @@
 use std::fmt::{self, Display, Formatter};
 
+use crate::postgres::tools::{checked_ident, InvalidIdentifier};
 use crate::tools::IntoNonZeroArray;
 
 /// Column name and things that can be converted into one
-#[derive(Debug, Clone, Eq, PartialEq)]
+///
+/// A `(table, column)` pair makes a column qualified with its table name, avoiding ambiguity in joins.
+/// Quote either part with [`quote_ident`][crate::postgres::quote_ident] if needed:
+///
+/// ```
+/// use scooby::postgres::{quote_ident, select, Aliasable, Orderable};
+/// use scooby::postgres::general::Column;
+///
+/// let name = Column::from(("p", "name"));
+/// let sql = select(name.clone().as_("person_name"))
+///     .from("Person AS p")
+///     .order_by(name.desc())
+///     .to_string();
+/// assert_eq!(sql, "SELECT p.name AS person_name FROM Person AS p ORDER BY p.name DESC");
+///
+/// let name = Column::from((quote_ident("Person"), quote_ident("name")));
+/// assert_eq!(name.to_string(), r#""Person"."name""#);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Column(String);
 
+impl Column {
+    /// Make a column out of a name, checking that it's a plain identifier with [`checked_ident`]
+    ///
+    /// ```
+    /// use scooby::postgres::general::Column;
+    ///
+    /// assert_eq!(Column::checked("name").unwrap().to_string(), "name");
+    /// assert!(Column::checked("name; --").is_err());
+    /// ```
+    pub fn checked(name: impl Into<String>) -> Result<Column, InvalidIdentifier> {
+        checked_ident(name).map(Column)
+    }
+}
+
 impl From<&str> for Column {
     fn from(s: &str) -> Self {
         Column(s.to_owned())
@@ -18,6 +51,30 @@ impl From<String> for Column {
     }
 }
 
+impl From<(&str, &str)> for Column {
+    fn from((table, column): (&str, &str)) -> Self {
+        Column(format!("{}.{}", table, column))
+    }
+}
+
+impl From<(String, String)> for Column {
+    fn from((table, column): (String, String)) -> Self {
+        Column(format!("{}.{}", table, column))
+    }
+}
+
+impl From<Column> for String {
+    fn from(column: Column) -> Self {
+        column.0
+    }
+}
+
+impl AsRef<str> for Column {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
 impl Display for Column {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
@@ -35,3 +92,41 @@ impl IntoNonZeroArray<Column, 1> for String {
         [Column(self)]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Column;
+
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{quote_ident, select, update, Aliasable, Joinable, Orderable};
+
+    #[test]
+    fn qualified() {
+        let id = Column::from(("Person", "id"));
+        let pet_owner_id = Column::from((quote_ident("Pet"), quote_ident("owner_id")));
+
+        let sql = select((id.clone().as_("id"), pet_owner_id.clone()))
+            .from(
+                "Person"
+                    .inner_join(quote_ident("Pet"))
+                    .on(&format!("{} = {}", pet_owner_id, id)),
+            )
+            .order_by(id.asc())
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            r#"SELECT Person.id AS id, "Pet"."owner_id" FROM Person INNER JOIN "Pet" ON "Pet"."owner_id" = Person.id ORDER BY Person.id ASC"#,
+        );
+    }
+
+    #[test]
+    fn qualified_in_where() {
+        let sql = update("Person")
+            .set("name", "$1")
+            .where_(format!("{} = $2", Column::from(("Person", "id"))))
+            .to_string();
+
+        assert_correct_postgresql(&sql, "UPDATE Person SET name = $1 WHERE Person.id = $2");
+    }
+}
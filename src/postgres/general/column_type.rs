@@ -0,0 +1,23 @@
+use std::fmt::{self, Display, Formatter};
+
+/// Column name along with its type, `name type` style, for column definition lists of table functions
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ColumnTypePair {
+    name: String,
+    type_name: String,
+}
+
+impl Display for ColumnTypePair {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.name, self.type_name)
+    }
+}
+
+impl<T: Into<String>, U: Into<String>> From<(T, U)> for ColumnTypePair {
+    fn from(value: (T, U)) -> Self {
+        ColumnTypePair {
+            name: value.0.into(),
+            type_name: value.1.into(),
+        }
+    }
+}
@@ -2,7 +2,7 @@ use std::fmt::{self, Display, Formatter};
 
 use super::{Column, Expression};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ColumnValuePair {
     column: Column,
     expression: Expression,
@@ -0,0 +1,115 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::tools::clause_break;
+
+/// A `key:value` annotation attached to a statement as a trailing SQL comment
+///
+/// Use the `comment` method on any statement to add one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Comment {
+    key: String,
+    value: String,
+}
+
+impl Comment {
+    pub(crate) fn new(key: impl Into<String>, value: impl Into<String>) -> Comment {
+        Comment {
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+}
+
+impl Display for Comment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", sanitize(&self.key), sanitize(&self.value))
+    }
+}
+
+/// Break up anything that would open or close a comment, since PostgreSQL comments nest
+pub(crate) fn sanitize(text: &str) -> Sanitized<'_> {
+    Sanitized(text)
+}
+
+pub(crate) struct Sanitized<'a>(&'a str);
+
+impl<'a> Display for Sanitized<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut previous = None;
+        let mut start = 0;
+
+        for (i, c) in self.0.char_indices() {
+            if matches!((previous, c), (Some('*'), '/') | (Some('/'), '*')) {
+                f.write_str(&self.0[start..i])?;
+                f.write_str(" ")?;
+                start = i;
+            }
+            previous = Some(c);
+        }
+
+        f.write_str(&self.0[start..])
+    }
+}
+
+/// All comments added to a statement, rendered as a single `/* ... */` trailer
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub(crate) struct Comments(Vec<Comment>);
+
+impl Comments {
+    pub(crate) fn push(&mut self, comment: Comment) {
+        self.0.push(comment);
+    }
+}
+
+impl Display for Comments {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return Ok(());
+        }
+
+        clause_break(f)?;
+        write!(f, "/* ")?;
+
+        for (i, comment) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            comment.fmt(f)?;
+        }
+
+        write!(f, " */")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Comment, Comments};
+
+    #[test]
+    fn empty() {
+        assert_eq!(Comments::default().to_string(), "");
+    }
+
+    #[test]
+    fn several() {
+        let mut comments = Comments::default();
+        comments.push(Comment::new("application", "billing"));
+        comments.push(Comment::new("route", "/invoices"));
+
+        assert_eq!(
+            comments.to_string(),
+            " /* application:billing,route:/invoices */"
+        );
+    }
+
+    #[test]
+    fn sanitized() {
+        let mut comments = Comments::default();
+        comments.push(Comment::new("evil*/key", "*/ DROP TABLE x; /* /*/"));
+
+        assert_eq!(
+            comments.to_string(),
+            " /* evil* /key:* / DROP TABLE x; / * / * / */"
+        );
+    }
+}
@@ -0,0 +1,355 @@
+use crate::postgres::statements::Select;
+
+use super::{Condition, Expression};
+
+/// Things that may be compared with patterns, ranges, `NULL` or other values, producing conditions
+///
+/// Implemented for everything that can be converted into an [`Expression`].
+#[allow(clippy::wrong_self_convention)]
+pub trait Comparable {
+    /// Make a `x BETWEEN low AND high` condition
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Comparable};
+    ///
+    /// let sql = select("*").from("Person").where_("age".between("$1", "$2")).to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Person WHERE age BETWEEN $1 AND $2");
+    /// ```
+    fn between(self, low: impl Into<Expression>, high: impl Into<Expression>) -> Condition;
+
+    /// Make a `x LIKE pattern` condition
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Comparable};
+    ///
+    /// let sql = select("*").from("Person").where_("name".like("'J%'")).to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Person WHERE name LIKE 'J%'");
+    /// ```
+    fn like(self, pattern: impl Into<Expression>) -> Condition;
+
+    /// Make a case-insensitive `x ILIKE pattern` condition
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Comparable};
+    ///
+    /// let sql = select("*").from("Person").where_("name".ilike("$1")).to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Person WHERE name ILIKE $1");
+    /// ```
+    fn ilike(self, pattern: impl Into<Expression>) -> Condition;
+
+    /// Make a `x SIMILAR TO pattern` condition
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Comparable};
+    ///
+    /// let sql = select("*").from("Person").where_("name".similar_to("'(J|K)%'")).to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Person WHERE name SIMILAR TO '(J|K)%'");
+    /// ```
+    fn similar_to(self, pattern: impl Into<Expression>) -> Condition;
+
+    /// Make a `x ~ pattern` regular expression match condition
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Comparable};
+    ///
+    /// let sql = select("*").from("Person").where_("email".matches_regex("'@example\\.com$'")).to_string();
+    ///
+    /// assert_eq!(sql, r"SELECT * FROM Person WHERE email ~ '@example\.com$'");
+    /// ```
+    fn matches_regex(self, pattern: impl Into<Expression>) -> Condition;
+
+    /// Make a case-insensitive `x ~* pattern` regular expression match condition
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Comparable};
+    ///
+    /// let sql = select("*").from("Person").where_("email".imatches_regex("$1")).to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Person WHERE email ~* $1");
+    /// ```
+    fn imatches_regex(self, pattern: impl Into<Expression>) -> Condition;
+
+    /// Make a `x IS NULL` condition
+    ///
+    /// Comparing with `NULL` using `=` is never true, so use this instead.
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Comparable};
+    ///
+    /// let sql = select("*").from("Person").where_("deleted_at".is_null()).to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Person WHERE deleted_at IS NULL");
+    /// ```
+    fn is_null(self) -> Condition;
+
+    /// Make a `x IS NOT NULL` condition
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Comparable};
+    ///
+    /// let sql = select("*").from("Person").where_("email".is_not_null()).to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Person WHERE email IS NOT NULL");
+    /// ```
+    fn is_not_null(self) -> Condition;
+
+    /// Make a `x IS DISTINCT FROM y` condition, which treats `NULL` as a comparable value
+    ///
+    /// ```
+    /// use scooby::postgres::{update, Comparable};
+    ///
+    /// let sql = update("Person")
+    ///     .set("name", "$1")
+    ///     .where_("name".is_distinct_from("$1"))
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "UPDATE Person SET name = $1 WHERE name IS DISTINCT FROM $1");
+    /// ```
+    fn is_distinct_from(self, other: impl Into<Expression>) -> Condition;
+
+    /// Make a `x IS NOT DISTINCT FROM y` condition, a `NULL`-safe equality check
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Comparable};
+    ///
+    /// let sql = select("*").from("Person").where_("manager_id".is_not_distinct_from("$1")).to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Person WHERE manager_id IS NOT DISTINCT FROM $1");
+    /// ```
+    fn is_not_distinct_from(self, other: impl Into<Expression>) -> Condition;
+
+    /// Make a `x @> y` condition, checking that `x` contains `y`
+    ///
+    /// Works for `jsonb` values, arrays and ranges alike.
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Comparable};
+    ///
+    /// let sql = select("*").from("Person").where_("data".includes(r#"'{"active": true}'"#)).to_string();
+    ///
+    /// assert_eq!(sql, r#"SELECT * FROM Person WHERE data @> '{"active": true}'"#);
+    /// ```
+    fn includes(self, other: impl Into<Expression>) -> Condition;
+
+    /// Make a `x <@ y` condition, checking that `x` is contained in `y`
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Comparable};
+    ///
+    /// let sql = select("*").from("Person").where_("data".included_in("$1")).to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Person WHERE data <@ $1");
+    /// ```
+    fn included_in(self, other: impl Into<Expression>) -> Condition;
+
+    /// Make a `x && y` condition, checking that arrays or ranges have anything in common
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Comparable};
+    ///
+    /// let sql = select("*").from("Post").where_("tags".overlaps("$1")).to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Post WHERE tags && $1");
+    /// ```
+    fn overlaps(self, other: impl Into<Expression>) -> Condition;
+
+    /// Make a `x = ANY(array)` condition, checking that `x` equals any element of an array
+    ///
+    /// Unlike `IN`, this accepts a single array parameter of any length.
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Comparable};
+    ///
+    /// let sql = select("*").from("Person").where_("id".eq_any("$1")).to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Person WHERE id = ANY($1)");
+    /// ```
+    fn eq_any(self, array: impl Into<Expression>) -> Condition;
+
+    /// Make a `x <> ALL(array)` condition, checking that `x` differs from every element of an array
+    ///
+    /// Counterpart of [`eq_any`](Comparable::eq_any) to use instead of `NOT IN`. Unlike `NOT IN`, it is true for an empty array.
+    ///
+    /// Beware that, just like `NOT IN`, it is never true if the array contains any `NULL`s.
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Comparable, Parameters};
+    ///
+    /// let mut params = Parameters::new();
+    ///
+    /// let sql = select("*").from("Person").where_("id".ne_all(params.next())).to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Person WHERE id <> ALL($1)");
+    /// ```
+    fn ne_all(self, array: impl Into<Expression>) -> Condition;
+
+    /// Make an `x IN (SELECT ...)` condition
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Comparable};
+    ///
+    /// let sql = select("*").from("Sale").where_("region".in_(select("region").from("TopRegion"))).to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Sale WHERE region IN (SELECT region FROM TopRegion)");
+    /// ```
+    fn in_(self, subquery: Select) -> Condition;
+
+    /// Make an `x NOT IN (SELECT ...)` condition
+    ///
+    /// Beware that it is never true if the subquery returns any `NULL`s.
+    fn not_in(self, subquery: Select) -> Condition;
+
+    /// Make an `x op ANY (SELECT ...)` condition, true if comparison holds for any row
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Comparable};
+    ///
+    /// let sql = select("*").from("Sale").where_("amount".compare_any(">", select("amount").from("Refund"))).to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Sale WHERE amount > ANY (SELECT amount FROM Refund)");
+    /// ```
+    fn compare_any(self, operator: &str, subquery: Select) -> Condition;
+
+    /// Make an `x op ALL (SELECT ...)` condition, true if comparison holds for all rows
+    fn compare_all(self, operator: &str, subquery: Select) -> Condition;
+}
+
+#[allow(clippy::wrong_self_convention)]
+impl<T> Comparable for T
+where
+    T: Into<Expression>,
+{
+    fn between(self, low: impl Into<Expression>, high: impl Into<Expression>) -> Condition {
+        format!("{} BETWEEN {} AND {}", self.into(), low.into(), high.into())
+    }
+
+    fn like(self, pattern: impl Into<Expression>) -> Condition {
+        format!("{} LIKE {}", self.into(), pattern.into())
+    }
+
+    fn ilike(self, pattern: impl Into<Expression>) -> Condition {
+        format!("{} ILIKE {}", self.into(), pattern.into())
+    }
+
+    fn similar_to(self, pattern: impl Into<Expression>) -> Condition {
+        format!("{} SIMILAR TO {}", self.into(), pattern.into())
+    }
+
+    fn matches_regex(self, pattern: impl Into<Expression>) -> Condition {
+        format!("{} ~ {}", self.into(), pattern.into())
+    }
+
+    fn imatches_regex(self, pattern: impl Into<Expression>) -> Condition {
+        format!("{} ~* {}", self.into(), pattern.into())
+    }
+
+    fn is_null(self) -> Condition {
+        format!("{} IS NULL", self.into())
+    }
+
+    fn is_not_null(self) -> Condition {
+        format!("{} IS NOT NULL", self.into())
+    }
+
+    fn is_distinct_from(self, other: impl Into<Expression>) -> Condition {
+        format!("{} IS DISTINCT FROM {}", self.into(), other.into())
+    }
+
+    fn is_not_distinct_from(self, other: impl Into<Expression>) -> Condition {
+        format!("{} IS NOT DISTINCT FROM {}", self.into(), other.into())
+    }
+
+    fn includes(self, other: impl Into<Expression>) -> Condition {
+        format!("{} @> {}", self.into(), other.into())
+    }
+
+    fn included_in(self, other: impl Into<Expression>) -> Condition {
+        format!("{} <@ {}", self.into(), other.into())
+    }
+
+    fn overlaps(self, other: impl Into<Expression>) -> Condition {
+        format!("{} && {}", self.into(), other.into())
+    }
+
+    fn eq_any(self, array: impl Into<Expression>) -> Condition {
+        format!("{} = ANY({})", self.into(), array.into())
+    }
+
+    fn ne_all(self, array: impl Into<Expression>) -> Condition {
+        format!("{} <> ALL({})", self.into(), array.into())
+    }
+
+    fn in_(self, subquery: Select) -> Condition {
+        format!("{} IN ({})", self.into(), subquery)
+    }
+
+    fn not_in(self, subquery: Select) -> Condition {
+        format!("{} NOT IN ({})", self.into(), subquery)
+    }
+
+    fn compare_any(self, operator: &str, subquery: Select) -> Condition {
+        format!("{} {} ANY ({})", self.into(), operator, subquery)
+    }
+
+    fn compare_all(self, operator: &str, subquery: Select) -> Condition {
+        format!("{} {} ALL ({})", self.into(), operator, subquery)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{select, Comparable};
+
+    #[test]
+    fn all_operators() {
+        let sql = select("*")
+            .from("Person")
+            .where_((
+                "age".between("18", "65"),
+                "name".like("'J%'"),
+                "name".ilike("'j%'"),
+                "name".similar_to("'(J|K)%'"),
+                "email".matches_regex("'@'"),
+                "email".imatches_regex("'@EXAMPLE'"),
+            ))
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "SELECT * FROM Person WHERE age BETWEEN 18 AND 65 AND name LIKE 'J%' AND name ILIKE 'j%' AND name SIMILAR TO '(J|K)%' AND email ~ '@' AND email ~* '@EXAMPLE'",
+        );
+    }
+
+    #[test]
+    fn negated() {
+        let sql = select("*")
+            .from("Person")
+            .where_(format!("NOT {}", "age".between("$1", "$2")))
+            .to_string();
+
+        assert_correct_postgresql(&sql, "SELECT * FROM Person WHERE NOT age BETWEEN $1 AND $2");
+    }
+
+    #[test]
+    fn null_aware() {
+        let sql = select("*")
+            .from("Person")
+            .where_((
+                "deleted_at".is_null(),
+                "email".is_not_null(),
+                "name".is_distinct_from("$1"),
+                "manager_id".is_not_distinct_from("$2"),
+            ))
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "SELECT * FROM Person WHERE deleted_at IS NULL AND email IS NOT NULL AND name IS DISTINCT FROM $1 AND manager_id IS NOT DISTINCT FROM $2",
+        );
+    }
+}
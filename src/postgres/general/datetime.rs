@@ -0,0 +1,298 @@
+// Conversions of date and time values of `chrono` and `time` crates into typed literals,
+// available with features of the same name. Time zone aware values are converted to UTC first,
+// so they denote the same instant regardless of the `TimeZone` setting of the session.
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+use crate::tools::literal;
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+use super::Expression;
+
+/// Date and time of day parts of a literal, any of which may be missing
+#[cfg(any(feature = "chrono", feature = "time"))]
+struct DateTimeLiteral {
+    type_name: &'static str,
+    date: Option<(i32, u32, u32)>,
+    time: Option<(u32, u32, u32, u32)>,
+    is_utc: bool,
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+impl From<DateTimeLiteral> for Expression {
+    fn from(parts: DateTimeLiteral) -> Self {
+        let mut value = String::new();
+        let mut is_bc = false;
+
+        if let Some((year, month, day)) = parts.date {
+            // There's no year 0, 1 BC comes right before 1 AD
+            let year = if year > 0 {
+                year
+            } else {
+                is_bc = true;
+                1 - year
+            };
+
+            value.push_str(&format!("{:04}-{:02}-{:02}", year, month, day));
+        }
+
+        if let Some((hour, minute, second, nanosecond)) = parts.time {
+            // Leap seconds are represented with nanoseconds overflowing into the next second
+            let (second, nanosecond) = if nanosecond >= 1_000_000_000 {
+                (second + 1, nanosecond - 1_000_000_000)
+            } else {
+                (second, nanosecond)
+            };
+
+            if !value.is_empty() {
+                value.push(' ');
+            }
+
+            value.push_str(&format!("{:02}:{:02}:{:02}", hour, minute, second));
+
+            if nanosecond > 0 {
+                let fraction = format!("{:09}", nanosecond);
+                value.push('.');
+                value.push_str(fraction.trim_end_matches('0'));
+            }
+        }
+
+        if parts.is_utc {
+            value.push_str("+00");
+        }
+
+        if is_bc {
+            value.push_str(" BC");
+        }
+
+        Expression::from(format!("{} {}", parts.type_name, literal(&value)))
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_conversions {
+    use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike};
+
+    use super::DateTimeLiteral;
+    use crate::postgres::general::{Expression, Literal};
+
+    fn date(date: &NaiveDate) -> Option<(i32, u32, u32)> {
+        Some((date.year(), date.month(), date.day()))
+    }
+
+    fn time(time: &NaiveTime) -> Option<(u32, u32, u32, u32)> {
+        Some((time.hour(), time.minute(), time.second(), time.nanosecond()))
+    }
+
+    /// Render as a `DATE '2024-01-31'` literal
+    impl From<NaiveDate> for Expression {
+        fn from(value: NaiveDate) -> Self {
+            Expression::from(DateTimeLiteral {
+                type_name: "DATE",
+                date: date(&value),
+                time: None,
+                is_utc: false,
+            })
+        }
+    }
+
+    /// Render as a `TIME '12:30:00.5'` literal
+    impl From<NaiveTime> for Expression {
+        fn from(value: NaiveTime) -> Self {
+            Expression::from(DateTimeLiteral {
+                type_name: "TIME",
+                date: None,
+                time: time(&value),
+                is_utc: false,
+            })
+        }
+    }
+
+    /// Render as a `TIMESTAMP '2024-01-31 12:30:00'` literal
+    impl From<NaiveDateTime> for Expression {
+        fn from(value: NaiveDateTime) -> Self {
+            Expression::from(DateTimeLiteral {
+                type_name: "TIMESTAMP",
+                date: date(&value.date()),
+                time: time(&value.time()),
+                is_utc: false,
+            })
+        }
+    }
+
+    /// Render as a `TIMESTAMPTZ '2024-01-31 12:30:00+00'` literal, converted to UTC
+    impl<Tz: TimeZone> From<DateTime<Tz>> for Expression {
+        fn from(value: DateTime<Tz>) -> Self {
+            let value = value.naive_utc();
+
+            Expression::from(DateTimeLiteral {
+                type_name: "TIMESTAMPTZ",
+                date: date(&value.date()),
+                time: time(&value.time()),
+                is_utc: true,
+            })
+        }
+    }
+
+    impl Literal for NaiveDate {}
+    impl Literal for NaiveTime {}
+    impl Literal for NaiveDateTime {}
+    impl<Tz: TimeZone> Literal for DateTime<Tz> {}
+}
+
+#[cfg(feature = "time")]
+mod time_conversions {
+    use time::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+    use super::DateTimeLiteral;
+    use crate::postgres::general::{Expression, Literal};
+
+    fn date(date: Date) -> Option<(i32, u32, u32)> {
+        Some((
+            date.year(),
+            u8::from(date.month()).into(),
+            date.day().into(),
+        ))
+    }
+
+    fn time(time: Time) -> Option<(u32, u32, u32, u32)> {
+        Some((
+            time.hour().into(),
+            time.minute().into(),
+            time.second().into(),
+            time.nanosecond(),
+        ))
+    }
+
+    /// Render as a `DATE '2024-01-31'` literal
+    impl From<Date> for Expression {
+        fn from(value: Date) -> Self {
+            Expression::from(DateTimeLiteral {
+                type_name: "DATE",
+                date: date(value),
+                time: None,
+                is_utc: false,
+            })
+        }
+    }
+
+    /// Render as a `TIME '12:30:00.5'` literal
+    impl From<Time> for Expression {
+        fn from(value: Time) -> Self {
+            Expression::from(DateTimeLiteral {
+                type_name: "TIME",
+                date: None,
+                time: time(value),
+                is_utc: false,
+            })
+        }
+    }
+
+    /// Render as a `TIMESTAMP '2024-01-31 12:30:00'` literal
+    impl From<PrimitiveDateTime> for Expression {
+        fn from(value: PrimitiveDateTime) -> Self {
+            Expression::from(DateTimeLiteral {
+                type_name: "TIMESTAMP",
+                date: date(value.date()),
+                time: time(value.time()),
+                is_utc: false,
+            })
+        }
+    }
+
+    /// Render as a `TIMESTAMPTZ '2024-01-31 12:30:00+00'` literal, converted to UTC
+    impl From<OffsetDateTime> for Expression {
+        fn from(value: OffsetDateTime) -> Self {
+            let value = value.to_offset(UtcOffset::UTC);
+
+            Expression::from(DateTimeLiteral {
+                type_name: "TIMESTAMPTZ",
+                date: date(value.date()),
+                time: time(value.time()),
+                is_utc: true,
+            })
+        }
+    }
+
+    impl Literal for Date {}
+    impl Literal for Time {}
+    impl Literal for PrimitiveDateTime {}
+    impl Literal for OffsetDateTime {}
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod chrono_tests {
+    use chrono::{FixedOffset, NaiveDate, TimeZone, Utc};
+
+    use crate::postgres::general::Expression;
+    use crate::postgres::insert_into;
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+
+    #[test]
+    fn naive() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        let time = date.and_hms_micro_opt(9, 5, 7, 250_000).unwrap();
+
+        let sql = insert_into("Event")
+            .columns(("day", "at", "time_of_day"))
+            .values([(date, time, time.time())])
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "INSERT INTO Event (day, at, time_of_day) VALUES (DATE '2024-01-31', TIMESTAMP '2024-01-31 09:05:07.25', TIME '09:05:07.25')",
+        );
+    }
+
+    #[test]
+    fn time_zones() {
+        let offset = FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
+        let local = offset.with_ymd_and_hms(2024, 1, 1, 2, 0, 0).unwrap();
+        let utc = Utc.with_ymd_and_hms(2023, 12, 31, 20, 30, 0).unwrap();
+
+        assert_eq!(Expression::from(local), Expression::from(utc));
+        assert_correct_postgresql(
+            &format!("SELECT {}", Expression::from(local)),
+            "SELECT TIMESTAMPTZ '2023-12-31 20:30:00+00'",
+        );
+    }
+
+    #[test]
+    fn before_christ() {
+        let date = NaiveDate::from_ymd_opt(-43, 3, 15).unwrap();
+
+        assert_eq!(Expression::from(date).to_string(), "DATE '0044-03-15 BC'");
+    }
+}
+
+#[cfg(all(test, feature = "time"))]
+mod time_tests {
+    use time::{Date, Month, PrimitiveDateTime, Time, UtcOffset};
+
+    use crate::postgres::general::Expression;
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::update;
+
+    #[test]
+    fn conversions() {
+        let date = Date::from_calendar_date(2024, Month::February, 29).unwrap();
+        let time = Time::from_hms_nano(23, 59, 59, 123_456_789).unwrap();
+        let date_time = PrimitiveDateTime::new(date, time);
+        let offset_date_time = date_time.assume_offset(UtcOffset::from_hms(-1, 0, 0).unwrap());
+
+        let sql = update("Event")
+            .set("day", date)
+            .set("time_of_day", time)
+            .set("at", date_time)
+            .set("at_tz", offset_date_time)
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "UPDATE Event SET day = DATE '2024-02-29', time_of_day = TIME '23:59:59.123456789', at = TIMESTAMP '2024-02-29 23:59:59.123456789', at_tz = TIMESTAMPTZ '2024-03-01 00:59:59.123456789+00'",
+        );
+        assert_eq!(
+            Expression::from(Date::from_calendar_date(0, Month::January, 1).unwrap()).to_string(),
+            "DATE '0001-01-01 BC'"
+        );
+    }
+}
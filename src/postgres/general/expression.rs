@@ -1,15 +1,39 @@
 use std::{
     fmt::{self, Display, Formatter},
-    iter::{Copied, Map},
+    iter::{once, Copied, Map, Once},
     slice,
 };
 
-use crate::tools::{transform_array, IntoIteratorOfSameType, IntoNonZeroArray};
+use crate::tools::{IntoIteratorOfSameType, IntoNonZeroArray};
 
 use super::{Alias, Column};
 
 /// Expression and things that can be converted into one
-#[derive(Debug, Clone, Eq, PartialEq)]
+///
+/// Stored as an owned `String`, even when made from a `'static` literal. Borrowing literals would take a
+/// `From<&'static str>` implementation, which conflicts with the `From<&str>` one accepting strings of any lifetime,
+/// and those have to be copied anyway to outlive the call.
+///
+/// Integers are rendered as numeric constants, and `bool` as `TRUE` or `FALSE`.
+/// Floats become `FLOAT8 '2.5e0'` or `FLOAT4 '2.5e0'` literals, so that they are typed as floats rather than `numeric`,
+/// keep their exact value, and stay short whatever their magnitude. `NaN` and infinities become `FLOAT8 'NaN'` and so on.
+///
+/// [`Option`]s of these and of the feature-gated values below are accepted too, with `None` becoming `NULL`.
+/// Strings are SQL fragments rather than values, so `Option<&str>` and `Option<String>` are deliberately not accepted:
+/// `Some` would be spliced into SQL as is. Bind text values as parameters instead.
+///
+/// Values of some other crates may be converted into typed literals with these features:
+///
+/// | Feature                      | Types                                                 | Literal           |
+/// |------------------------------|-------------------------------------------------------|-------------------|
+/// | `chrono`                     | `NaiveDate`, `NaiveTime`, `NaiveDateTime`, `DateTime` | `DATE '...'` etc. |
+/// | `time`                       | `Date`, `Time`, `PrimitiveDateTime`, `OffsetDateTime` | `DATE '...'` etc. |
+/// | `uuid`                       | `Uuid`                                                | `UUID '...'`      |
+/// | `rust_decimal`, `bigdecimal` | `Decimal`, `BigDecimal`                               | `NUMERIC '...'`   |
+/// | `json`                       | `serde_json::Value`                                   | `JSONB '...'`     |
+///
+/// Time zone aware values become `TIMESTAMPTZ '...'` literals converted to UTC.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Expression(String);
 
 impl From<&str> for Expression {
@@ -36,6 +60,115 @@ impl From<Alias> for Expression {
     }
 }
 
+/// Value rendered as a literal when converted into an [`Expression`], which makes its [`Option`] convertible too
+///
+/// Implemented for `bool`, integers, floats, and values of other crates enabled with features.
+/// Not implemented for strings, which are SQL fragments:
+///
+/// ```compile_fail
+/// use scooby::postgres::update;
+///
+/// let nickname: Option<&str> = Some("'; DROP TABLE Person; --");
+///
+/// update("Person").set("nickname", nickname);
+/// ```
+pub trait Literal: Into<Expression> {}
+
+impl From<bool> for Expression {
+    fn from(value: bool) -> Self {
+        Expression::from(if value { "TRUE" } else { "FALSE" })
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty)+) => {
+        $(
+            impl From<$t> for Expression {
+                fn from(value: $t) -> Self {
+                    Expression(value.to_string())
+                }
+            }
+
+            impl Literal for $t {}
+
+            impl IntoNonZeroArray<Expression, 1> for $t {
+                fn into_non_zero_array(self) -> [Expression; 1] {
+                    [Expression::from(self)]
+                }
+            }
+        )+
+    };
+}
+
+impl_from_integer!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+// Numeric constants with a fraction or an exponent are `numeric`, and special values have none at all,
+// so floats are written as typed string literals instead
+macro_rules! impl_from_float {
+    ($($t:ty: $type_name:literal)+) => {
+        $(
+            impl From<$t> for Expression {
+                fn from(value: $t) -> Self {
+                    if value.is_nan() {
+                        Expression::from(concat!($type_name, " 'NaN'"))
+                    } else if value.is_infinite() && value > 0.0 {
+                        Expression::from(concat!($type_name, " 'Infinity'"))
+                    } else if value.is_infinite() {
+                        Expression::from(concat!($type_name, " '-Infinity'"))
+                    } else {
+                        Expression(format!(concat!($type_name, " '{:e}'"), value))
+                    }
+                }
+            }
+
+            impl Literal for $t {}
+
+            impl IntoNonZeroArray<Expression, 1> for $t {
+                fn into_non_zero_array(self) -> [Expression; 1] {
+                    [Expression::from(self)]
+                }
+            }
+        )+
+    };
+}
+
+impl_from_float!(f32: "FLOAT4" f64: "FLOAT8");
+
+impl Literal for bool {}
+
+impl IntoNonZeroArray<Expression, 1> for bool {
+    fn into_non_zero_array(self) -> [Expression; 1] {
+        [Expression::from(self)]
+    }
+}
+
+impl<T> From<Option<T>> for Expression
+where
+    T: Literal,
+{
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(value) => value.into(),
+            None => Expression::from("NULL"),
+        }
+    }
+}
+
+impl<T> IntoNonZeroArray<Expression, 1> for Option<T>
+where
+    T: Literal,
+{
+    fn into_non_zero_array(self) -> [Expression; 1] {
+        [Expression::from(self)]
+    }
+}
+
+impl From<Expression> for String {
+    fn from(value: Expression) -> Self {
+        value.0
+    }
+}
+
 impl Display for Expression {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
@@ -54,9 +187,14 @@ impl IntoNonZeroArray<Expression, 1> for String {
     }
 }
 
-impl<const N: usize> IntoNonZeroArray<Expression, N> for [String; N] {
-    fn into_non_zero_array(self) -> [Expression; N] {
-        transform_array(self, Expression)
+impl<T> IntoIteratorOfSameType<T> for Expression
+where
+    T: From<Expression>,
+{
+    type Iterator = Once<T>;
+
+    fn into_some_iter(self) -> Self::Iterator {
+        once(self.into())
     }
 }
 
@@ -71,3 +209,76 @@ where
         self.as_ref().iter().copied().map(Expression::from)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Expression;
+
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{insert_into, select, update};
+
+    #[test]
+    fn primitives() {
+        let sql = insert_into("Reading")
+            .columns(("sensor_id", "value", "valid"))
+            .values([(1, 2.5, true), (-2, -0.125, false)])
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "INSERT INTO Reading (sensor_id, value, valid) VALUES (1, FLOAT8 '2.5e0', TRUE), (-2, FLOAT8 '-1.25e-1', FALSE)",
+        );
+
+        let sql = insert_into("Sensor")
+            .columns("id")
+            .values([u64::MAX])
+            .values([i64::MIN])
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "INSERT INTO Sensor (id) VALUES (18446744073709551615), (-9223372036854775808)",
+        );
+    }
+
+    #[test]
+    fn options() {
+        let score: Option<f64> = None;
+
+        let sql = update("Person")
+            .set("age", Some(42))
+            .set("score", score)
+            .set("verified", Option::<bool>::None)
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "UPDATE Person SET age = 42, score = NULL, verified = NULL",
+        );
+
+        let sql = insert_into("Person")
+            .columns("age")
+            .values([Some(1), None])
+            .to_string();
+
+        assert_correct_postgresql(&sql, "INSERT INTO Person (age) VALUES (1), (NULL)");
+    }
+
+    #[test]
+    fn special_floats() {
+        let sql = select((
+            Expression::from(f64::NAN),
+            Expression::from(f64::INFINITY),
+            Expression::from(f32::NEG_INFINITY),
+            Expression::from(1e-7),
+            Expression::from(1e300),
+            Expression::from(0.1f32),
+        ))
+        .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "SELECT FLOAT8 'NaN', FLOAT8 'Infinity', FLOAT4 '-Infinity', FLOAT8 '1e-7', FLOAT8 '1e300', FLOAT4 '1e-1'",
+        );
+    }
+}
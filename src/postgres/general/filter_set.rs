@@ -0,0 +1,286 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use crate::postgres::tools::Parameters;
+
+use super::{Condition, Expression};
+
+/// Allow-list of fields that users may filter by, e.g. through query parameters of an API
+///
+/// Each field maps a public name to an expression and a [`FilterOp`]. Applying the set to request
+/// parameters gives conditions with placeholders, and the values to bind to those placeholders, in order.
+/// Values never end up in SQL, and parameters that are not on the list are ignored.
+///
+/// ```
+/// use scooby::postgres::{select, Parameters};
+/// use scooby::postgres::general::{FilterOp, FilterSet};
+///
+/// let filters = FilterSet::new()
+///     .field("status", "Person.status", FilterOp::In)
+///     .field("age", "Person.age", FilterOp::Range)
+///     .field("q", "Person.name", FilterOp::ILike);
+///
+/// let request = [("q", "ann"), ("page", "2"), ("age", "18.."), ("status", "active,invited")];
+///
+/// let mut params = Parameters::new();
+/// let (conditions, values) = filters.apply(request, &mut params).unwrap();
+///
+/// let sql = select("*").from("Person").where_(conditions).to_string();
+///
+/// assert_eq!(
+///     sql,
+///     "SELECT * FROM Person WHERE Person.status IN ($1, $2) AND Person.age >= $3 AND Person.name ILIKE $4",
+/// );
+/// assert_eq!(values, ["active", "invited", "18", "%ann%"]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct FilterSet {
+    fields: Vec<(String, Expression, FilterOp)>,
+}
+
+/// Way of filtering by a field of a [`FilterSet`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FilterOp {
+    /// `x = $1`
+    Eq,
+    /// `x IN ($1, $2)`, from a comma-separated list of values such as `a,b`
+    In,
+    /// `x >= $1 AND x <= $2`, from an inclusive range of values such as `1..10`, where either end may be omitted
+    Range,
+    /// `x ILIKE $1`, matching values containing the given one, with `%`, `_` and `\` in it escaped
+    ILike,
+}
+
+impl FilterSet {
+    /// Create an empty allow-list
+    pub fn new() -> FilterSet {
+        FilterSet::default()
+    }
+
+    /// Allow filtering by the given name, applying the operator to the given expression
+    pub fn field(
+        mut self,
+        name: impl Into<String>,
+        expression: impl Into<Expression>,
+        op: FilterOp,
+    ) -> FilterSet {
+        self.fields.push((name.into(), expression.into(), op));
+        self
+    }
+
+    /// Turn request parameters into conditions with placeholders from `params`, and values to bind to them
+    ///
+    /// Conditions come in the order of fields in this set, regardless of the order of request parameters,
+    /// so that the same filters always result in the same SQL. On error, no placeholders are taken from `params`.
+    pub fn apply<K, V>(
+        &self,
+        request: impl IntoIterator<Item = (K, V)>,
+        params: &mut Parameters,
+    ) -> Result<(Vec<Condition>, Vec<String>), FilterError>
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let request: Vec<(K, V)> = request.into_iter().collect();
+        let mut filters = Vec::new();
+
+        // Values are all checked before taking any placeholders, so that none are wasted on errors
+        for (name, expression, op) in &self.fields {
+            let value = match request.iter().find(|(key, _)| key.as_ref() == name) {
+                Some((_, value)) => value.as_ref(),
+                None => continue,
+            };
+
+            let filter = match op {
+                FilterOp::Eq => Filter::Eq(value.to_owned()),
+                FilterOp::In => {
+                    let items: Vec<String> = value
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|item| !item.is_empty())
+                        .map(str::to_owned)
+                        .collect();
+
+                    if items.is_empty() {
+                        return Err(FilterError::EmptyList {
+                            field: name.clone(),
+                        });
+                    }
+
+                    Filter::In(items)
+                }
+                FilterOp::Range => {
+                    let (low, high) = match value.find("..") {
+                        Some(index) => (value[..index].trim(), value[index + 2..].trim()),
+                        None => {
+                            return Err(FilterError::InvalidRange {
+                                field: name.clone(),
+                            })
+                        }
+                    };
+
+                    if low.is_empty() && high.is_empty() {
+                        return Err(FilterError::InvalidRange {
+                            field: name.clone(),
+                        });
+                    }
+
+                    let bound = |end: &str| Some(end.to_owned()).filter(|end| !end.is_empty());
+                    Filter::Range(bound(low), bound(high))
+                }
+                FilterOp::ILike => Filter::ILike(format!("%{}%", escape_like(value))),
+            };
+
+            filters.push((expression, filter));
+        }
+
+        let mut conditions = Vec::new();
+        let mut values = Vec::new();
+
+        for (expression, filter) in filters {
+            match filter {
+                Filter::Eq(value) => {
+                    conditions.push(format!("{} = {}", expression, params.next()));
+                    values.push(value);
+                }
+                Filter::In(items) => {
+                    conditions.push(format!(
+                        "{} IN ({})",
+                        expression,
+                        params.next_n(items.len())
+                    ));
+                    values.extend(items);
+                }
+                Filter::Range(low, high) => {
+                    if let Some(low) = low {
+                        conditions.push(format!("{} >= {}", expression, params.next()));
+                        values.push(low);
+                    }
+
+                    if let Some(high) = high {
+                        conditions.push(format!("{} <= {}", expression, params.next()));
+                        values.push(high);
+                    }
+                }
+                Filter::ILike(pattern) => {
+                    conditions.push(format!("{} ILIKE {}", expression, params.next()));
+                    values.push(pattern);
+                }
+            }
+        }
+
+        Ok((conditions, values))
+    }
+}
+
+/// Checked value of a request parameter, ready to be bound
+enum Filter {
+    Eq(String),
+    In(Vec<String>),
+    Range(Option<String>, Option<String>),
+    ILike(String),
+}
+
+fn escape_like(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+/// Error of applying a [`FilterSet`] to request parameters with malformed values
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FilterError {
+    /// Value of an [`In`][FilterOp::In] field has no items
+    EmptyList { field: String },
+    /// Value of a [`Range`][FilterOp::Range] field is not of `low..high` form, or has neither end
+    InvalidRange { field: String },
+}
+
+impl Display for FilterError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            FilterError::EmptyList { field } => write!(f, "no values given to filter {} by", field),
+            FilterError::InvalidRange { field } => {
+                write!(f, "range to filter {} by is not of low..high form", field)
+            }
+        }
+    }
+}
+
+impl Error for FilterError {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{FilterError, FilterOp, FilterSet};
+
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{delete_from, Parameters};
+
+    fn filters() -> FilterSet {
+        FilterSet::new()
+            .field("id", "id", FilterOp::Eq)
+            .field("created", "created_at", FilterOp::Range)
+            .field("name", "lower(name)", FilterOp::ILike)
+    }
+
+    #[test]
+    fn apply_in_field_order() {
+        let request: HashMap<String, String> = [
+            ("name", "50%_off"),
+            ("created", "..2024-01-01"),
+            ("id", "7"),
+        ]
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+
+        let mut params = Parameters::starting_from(2);
+        let (conditions, values) = filters().apply(&request, &mut params).unwrap();
+
+        let sql = delete_from("Dummy")
+            .where_("kind = $1")
+            .where_(conditions)
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "DELETE FROM Dummy WHERE kind = $1 AND id = $2 AND created_at <= $3 AND lower(name) ILIKE $4",
+        );
+        assert_eq!(values, ["7", "2024-01-01", r"%50\%\_off%"]);
+    }
+
+    #[test]
+    fn malformed_values() {
+        let mut params = Parameters::new();
+        let filters = filters().field("tags", "tag", FilterOp::In);
+
+        assert_eq!(
+            filters.apply([("created", "2024")], &mut params),
+            Err(FilterError::InvalidRange {
+                field: "created".to_owned()
+            })
+        );
+        assert_eq!(
+            filters.apply([("tags", " , ")], &mut params),
+            Err(FilterError::EmptyList {
+                field: "tags".to_owned()
+            })
+        );
+        assert_eq!(
+            filters.apply([("id", "7"), ("created", "..")], &mut params),
+            Err(FilterError::InvalidRange {
+                field: "created".to_owned()
+            })
+        );
+        assert_eq!(params.next(), "$1");
+    }
+}
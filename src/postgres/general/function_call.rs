@@ -0,0 +1,186 @@
+use std::fmt::{self, Display, Formatter};
+use std::iter::{once, Once};
+
+use crate::postgres::statements::OrderBy;
+use crate::tools::{joined, IntoIteratorOfSameType};
+
+use super::{Alias, Aliasable, Condition, Expression};
+
+/// Call a function or an aggregate with the given arguments, `name(a, b)` style
+///
+/// Aggregate-specific `DISTINCT`, `ORDER BY` and `FILTER` options may be applied on top.
+///
+/// ```
+/// use scooby::postgres::{func, select, Aliasable, Orderable};
+///
+/// let sql = select(func("string_agg", ("name", "', '")).order_by("name".desc()).as_("names"))
+///     .from("Person")
+///     .to_string();
+///
+/// assert_eq!(sql, "SELECT string_agg(name, ', ' ORDER BY name DESC) AS names FROM Person");
+/// ```
+pub fn func(
+    name: impl Into<String>,
+    arguments: impl IntoIteratorOfSameType<Expression>,
+) -> FunctionCall {
+    FunctionCall {
+        name: name.into(),
+        distinct: false,
+        arguments: arguments.into_some_iter().collect(),
+        order_by: Vec::new(),
+        filter: Vec::new(),
+    }
+}
+
+/// Call the `count` aggregate with the given arguments
+///
+/// ```
+/// use scooby::postgres::{count, select};
+///
+/// let sql = select(count("city").distinct()).from("Person").to_string();
+///
+/// assert_eq!(sql, "SELECT count(DISTINCT city) FROM Person");
+/// ```
+pub fn count(arguments: impl IntoIteratorOfSameType<Expression>) -> FunctionCall {
+    func("count", arguments)
+}
+
+/// Count all rows, `count(*)` style
+///
+/// ```
+/// use scooby::postgres::{count_all, select};
+///
+/// let sql = select(count_all().filter("age >= 18")).from("Person").to_string();
+///
+/// assert_eq!(sql, "SELECT count(*) FILTER (WHERE age >= 18) FROM Person");
+/// ```
+pub fn count_all() -> FunctionCall {
+    func("count", "*")
+}
+
+/// Function or aggregate call, created with [`func`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[must_use = "Making a function call without using it is pointless"]
+pub struct FunctionCall {
+    name: String,
+    distinct: bool,
+    arguments: Vec<Expression>,
+    order_by: Vec<OrderBy>,
+    filter: Vec<Condition>,
+}
+
+impl FunctionCall {
+    /// Aggregate only distinct values of arguments, `name(DISTINCT x)` style
+    pub fn distinct(mut self) -> Self {
+        self.distinct = true;
+        self
+    }
+
+    /// Sort aggregated values, `name(x ORDER BY y)` style
+    ///
+    /// Each call to this method adds expressions to the existing list.
+    pub fn order_by(mut self, expressions: impl IntoIteratorOfSameType<OrderBy>) -> Self {
+        self.order_by.extend(expressions.into_some_iter());
+        self
+    }
+
+    /// Aggregate only rows matching conditions, `name(x) FILTER (WHERE y)` style
+    ///
+    /// Each call to this method adds conditions to the existing list, joined with `AND`.
+    pub fn filter(mut self, conditions: impl IntoIteratorOfSameType<Condition>) -> Self {
+        self.filter.extend(conditions.into_some_iter());
+        self
+    }
+}
+
+impl Display for FunctionCall {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}(", self.name)?;
+
+        if self.distinct {
+            write!(f, "DISTINCT ")?;
+        }
+
+        write!(f, "{}", joined(&self.arguments, ", "))?;
+
+        if !self.order_by.is_empty() {
+            write!(f, " ORDER BY {}", joined(&self.order_by, ", "))?;
+        }
+
+        write!(f, ")")?;
+
+        if !self.filter.is_empty() {
+            write!(f, " FILTER (WHERE {})", joined(&self.filter, " AND "))?;
+        }
+
+        Ok(())
+    }
+}
+
+/* Conversions */
+
+impl From<FunctionCall> for Expression {
+    fn from(call: FunctionCall) -> Self {
+        Expression::from(call.to_string())
+    }
+}
+
+impl From<FunctionCall> for String {
+    fn from(call: FunctionCall) -> Self {
+        call.to_string()
+    }
+}
+
+impl<T> IntoIteratorOfSameType<T> for FunctionCall
+where
+    T: From<FunctionCall>,
+{
+    type Iterator = Once<T>;
+
+    fn into_some_iter(self) -> Self::Iterator {
+        once(self.into())
+    }
+}
+
+impl Aliasable for FunctionCall {
+    fn as_(self, alias: &str) -> Alias {
+        self.to_string().as_(alias)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{count, count_all, func, select, Aliasable, Orderable};
+
+    #[test]
+    fn aggregates() {
+        let sql = select((
+            "country",
+            count_all().as_("total"),
+            count(("city",)).distinct(),
+            func("array_agg", "name")
+                .distinct()
+                .order_by("name".asc())
+                .filter(("age >= 18", "name IS NOT NULL")),
+        ))
+        .from("Person")
+        .group_by("country")
+        .order_by(count_all().desc())
+        .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "SELECT country, count(*) AS total, count(DISTINCT city), array_agg(DISTINCT name ORDER BY name ASC) FILTER (WHERE age >= 18 AND name IS NOT NULL) FROM Person GROUP BY country ORDER BY count(*) DESC",
+        );
+    }
+
+    #[test]
+    fn plain_function() {
+        let sql = select(func("coalesce", ("nickname", "name", "'?'")))
+            .from("Person")
+            .to_string();
+
+        assert_correct_postgresql(&sql, "SELECT coalesce(nickname, name, '?') FROM Person");
+    }
+}
@@ -0,0 +1,181 @@
+use super::{Condition, Expression};
+
+/// Things that may be navigated and queried as `json` or `jsonb` values
+///
+/// Implemented for everything that can be converted into an [`Expression`].
+/// Keys and paths are expressions too, so string literals need to be quoted: `"'name'"`.
+pub trait JsonOperable {
+    /// Get an object field or array element, `x -> key` style
+    ///
+    /// ```
+    /// use scooby::postgres::{select, JsonOperable};
+    ///
+    /// let sql = select("data".json_get("'address'").json_get("'city'")).from("Person").to_string();
+    ///
+    /// assert_eq!(sql, "SELECT data -> 'address' -> 'city' FROM Person");
+    /// ```
+    fn json_get(self, key: impl Into<Expression>) -> Expression;
+
+    /// Get an object field or array element as `text`, `x ->> key` style
+    ///
+    /// ```
+    /// use scooby::postgres::{select, JsonOperable};
+    ///
+    /// let sql = select("data".json_get_text("'name'")).from("Person").to_string();
+    ///
+    /// assert_eq!(sql, "SELECT data ->> 'name' FROM Person");
+    /// ```
+    fn json_get_text(self, key: impl Into<Expression>) -> Expression;
+
+    /// Get a value at the given path, `x #> path` style
+    ///
+    /// ```
+    /// use scooby::postgres::{select, JsonOperable};
+    ///
+    /// let sql = select("data".json_get_path("'{address,city}'")).from("Person").to_string();
+    ///
+    /// assert_eq!(sql, "SELECT data #> '{address,city}' FROM Person");
+    /// ```
+    fn json_get_path(self, path: impl Into<Expression>) -> Expression;
+
+    /// Get a value at the given path as `text`, `x #>> path` style
+    ///
+    /// ```
+    /// use scooby::postgres::{select, JsonOperable};
+    ///
+    /// let sql = select("data".json_get_path_text("'{address,city}'")).from("Person").to_string();
+    ///
+    /// assert_eq!(sql, "SELECT data #>> '{address,city}' FROM Person");
+    /// ```
+    fn json_get_path_text(self, path: impl Into<Expression>) -> Expression;
+
+    /// Make a `x ? key` condition, checking that a top-level key or array element exists
+    ///
+    /// ```
+    /// use scooby::postgres::{select, JsonOperable};
+    ///
+    /// let sql = select("*").from("Person").where_("data".has_key("'email'")).to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Person WHERE data ? 'email'");
+    /// ```
+    fn has_key(self, key: impl Into<Expression>) -> Condition;
+
+    /// Make a `x ?| keys` condition, checking that any of the keys exist
+    ///
+    /// ```
+    /// use scooby::postgres::{select, JsonOperable};
+    ///
+    /// let sql = select("*").from("Person").where_("data".has_any_key("'{email,phone}'")).to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Person WHERE data ?| '{email,phone}'");
+    /// ```
+    fn has_any_key(self, keys: impl Into<Expression>) -> Condition;
+
+    /// Make a `x ?& keys` condition, checking that all of the keys exist
+    ///
+    /// ```
+    /// use scooby::postgres::{select, JsonOperable};
+    ///
+    /// let sql = select("*").from("Person").where_("data".has_all_keys("$1")).to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Person WHERE data ?& $1");
+    /// ```
+    fn has_all_keys(self, keys: impl Into<Expression>) -> Condition;
+
+    /// Make a `x @? path` condition, checking that a JSON path returns any items
+    ///
+    /// ```
+    /// use scooby::postgres::{select, JsonOperable};
+    ///
+    /// let sql = select("*").from("Person").where_("data".json_path_exists("'$.tags[*]'")).to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Person WHERE data @? '$.tags[*]'");
+    /// ```
+    fn json_path_exists(self, path: impl Into<Expression>) -> Condition;
+
+    /// Get all items returned by a JSON path, `jsonb_path_query(x, path)` style
+    ///
+    /// ```
+    /// use scooby::postgres::{select, JsonOperable};
+    ///
+    /// let sql = select("data".jsonb_path_query("'$.tags[*]'")).from("Person").to_string();
+    ///
+    /// assert_eq!(sql, "SELECT jsonb_path_query(data, '$.tags[*]') FROM Person");
+    /// ```
+    fn jsonb_path_query(self, path: impl Into<Expression>) -> Expression;
+}
+
+impl<T> JsonOperable for T
+where
+    T: Into<Expression>,
+{
+    fn json_get(self, key: impl Into<Expression>) -> Expression {
+        Expression::from(format!("{} -> {}", self.into(), key.into()))
+    }
+
+    fn json_get_text(self, key: impl Into<Expression>) -> Expression {
+        Expression::from(format!("{} ->> {}", self.into(), key.into()))
+    }
+
+    fn json_get_path(self, path: impl Into<Expression>) -> Expression {
+        Expression::from(format!("{} #> {}", self.into(), path.into()))
+    }
+
+    fn json_get_path_text(self, path: impl Into<Expression>) -> Expression {
+        Expression::from(format!("{} #>> {}", self.into(), path.into()))
+    }
+
+    fn has_key(self, key: impl Into<Expression>) -> Condition {
+        format!("{} ? {}", self.into(), key.into())
+    }
+
+    fn has_any_key(self, keys: impl Into<Expression>) -> Condition {
+        format!("{} ?| {}", self.into(), keys.into())
+    }
+
+    fn has_all_keys(self, keys: impl Into<Expression>) -> Condition {
+        format!("{} ?& {}", self.into(), keys.into())
+    }
+
+    fn json_path_exists(self, path: impl Into<Expression>) -> Condition {
+        format!("{} @? {}", self.into(), path.into())
+    }
+
+    fn jsonb_path_query(self, path: impl Into<Expression>) -> Expression {
+        Expression::from(format!(
+            "jsonb_path_query({}, {})",
+            self.into(),
+            path.into()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{select, Comparable, JsonOperable};
+
+    #[test]
+    fn get_and_filter() {
+        let sql = select((
+            "data".json_get("'address'").json_get_text("'city'"),
+            "data".json_get_path_text("'{a,b}'"),
+            "data".jsonb_path_query("'$.tags[*]'"),
+        ))
+        .from("Person")
+        .where_((
+            "data".json_get_text("'name'").like("'J%'"),
+            "data".includes(r#"'{"active": true}'"#),
+            "data".has_key("'email'"),
+            "data".has_any_key("'{a,b}'"),
+            "data".has_all_keys("'{a,b}'"),
+            "data".json_path_exists("'$.tags'"),
+        ))
+        .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            r#"SELECT data -> 'address' ->> 'city', data #>> '{a,b}', jsonb_path_query(data, '$.tags[*]') FROM Person WHERE data ->> 'name' LIKE 'J%' AND data @> '{"active": true}' AND data ? 'email' AND data ?| '{a,b}' AND data ?& '{a,b}' AND data @? '$.tags'"#,
+        );
+    }
+}
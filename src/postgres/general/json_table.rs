@@ -0,0 +1,374 @@
+use std::fmt::{self, Display, Formatter};
+use std::iter::{once, Once};
+
+use crate::postgres::statements::FromItem;
+use crate::tools::{joined, IntoIteratorOfSameType, IntoNonZeroArray};
+
+use super::{Alias, Aliasable, ColumnTypePair, Expression};
+
+/// Start building a `JSON_TABLE(...)` item of a `FROM` clause, turning a JSON value into rows
+///
+/// Available in PostgreSQL 17 and later. Paths are expressions, so they need to be quoted: `"'$.items[*]'"`.
+///
+/// ```
+/// use scooby::postgres::{json_table, select, JsonTableColumn};
+///
+/// let sql = select(("id", "name"))
+///     .from(
+///         json_table("$1", "'$.items[*]'")
+///             .columns([
+///                 JsonTableColumn::new("id", "integer"),
+///                 JsonTableColumn::new("name", "text").path("'$.title'"),
+///             ])
+///             .as_("item"),
+///     )
+///     .to_string();
+///
+/// assert_eq!(
+///     sql,
+///     "SELECT id, name FROM JSON_TABLE($1, '$.items[*]' COLUMNS (id integer, name text PATH '$.title')) AS item",
+/// );
+/// ```
+pub fn json_table(context: impl Into<Expression>, path: impl Into<Expression>) -> JsonTableBuilder {
+    JsonTableBuilder {
+        context: context.into(),
+        path: path.into(),
+        passing: Vec::new(),
+    }
+}
+
+/// Start building a `json_to_recordset(...)` item of a `FROM` clause, turning a JSON array of objects into rows
+///
+/// ```
+/// use scooby::postgres::{json_to_recordset, select};
+///
+/// let sql = select("*")
+///     .from(json_to_recordset("$1").as_("x", [("id", "integer"), ("name", "text")]))
+///     .to_string();
+///
+/// assert_eq!(sql, "SELECT * FROM json_to_recordset($1) AS x(id integer, name text)");
+/// ```
+pub fn json_to_recordset(json: impl Into<Expression>) -> RecordsetFunction {
+    RecordsetFunction {
+        name: "json_to_recordset",
+        json: json.into(),
+    }
+}
+
+/// Start building a `jsonb_to_recordset(...)` item of a `FROM` clause, same as [`json_to_recordset`] but for `jsonb`
+pub fn jsonb_to_recordset(json: impl Into<Expression>) -> RecordsetFunction {
+    RecordsetFunction {
+        name: "jsonb_to_recordset",
+        json: json.into(),
+    }
+}
+
+/// `JSON_TABLE(...)` without columns yet, created with [`json_table`]
+#[must_use = "Making a JSON_TABLE without columns is pointless"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonTableBuilder {
+    context: Expression,
+    path: Expression,
+    passing: Vec<(Expression, String)>,
+}
+
+impl JsonTableBuilder {
+    /// Make a value available to the path as a variable, i.e. `PASSING value AS name`
+    ///
+    /// ```
+    /// use scooby::postgres::json_table;
+    ///
+    /// let table = json_table("data", "'$.items[*] ? (@.price > $min)'")
+    ///     .passing("$1", "min")
+    ///     .columns([("price", "numeric")]);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "JSON_TABLE(data, '$.items[*] ? (@.price > $min)' PASSING $1 AS min COLUMNS (price numeric))",
+    /// );
+    /// ```
+    pub fn passing(mut self, value: impl Into<Expression>, name: &str) -> Self {
+        self.passing.push((value.into(), name.to_string()));
+        self
+    }
+
+    /// Specify columns of the resulting rows, i.e. `COLUMNS (...)`
+    pub fn columns<const N: usize>(
+        self,
+        columns: impl IntoNonZeroArray<JsonTableColumn, N>,
+    ) -> JsonTable {
+        JsonTable {
+            builder: self,
+            columns: columns.into_non_zero_array().into(),
+        }
+    }
+}
+
+/// `JSON_TABLE(...)` item of a `FROM` clause, created with [`json_table`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonTable {
+    builder: JsonTableBuilder,
+    columns: Vec<JsonTableColumn>,
+}
+
+impl JsonTable {
+    /// Alias the resulting rows, i.e. `JSON_TABLE(...) AS alias`
+    pub fn as_(self, alias: &str) -> Alias {
+        self.to_string().as_(alias)
+    }
+}
+
+impl Display for JsonTable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "JSON_TABLE({}, {}",
+            self.builder.context, self.builder.path
+        )?;
+
+        if !self.builder.passing.is_empty() {
+            let passing = self
+                .builder
+                .passing
+                .iter()
+                .map(|(value, name)| PassingArgument(value, name));
+
+            write!(f, " PASSING {}", joined(passing, ", "))?;
+        }
+
+        write!(f, " COLUMNS ({}))", joined(&self.columns, ", "))
+    }
+}
+
+/// Single `value AS name` item of a `PASSING` clause
+#[derive(Clone)]
+struct PassingArgument<'a>(&'a Expression, &'a str);
+
+impl<'a> Display for PassingArgument<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} AS {}", self.0, self.1)
+    }
+}
+
+/// Column of a [`JsonTable`]
+///
+/// Plain `(name, type)` tuples may be used for columns taking the value under their own name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonTableColumn(JsonTableColumnKind);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum JsonTableColumnKind {
+    Regular {
+        name: String,
+        type_name: String,
+        exists: bool,
+        path: Option<Expression>,
+    },
+    Ordinality(String),
+    Nested {
+        path: Expression,
+        columns: Vec<JsonTableColumn>,
+    },
+}
+
+impl JsonTableColumn {
+    /// Make a `name type` column, taking the value of the key with the same name
+    pub fn new(name: impl Into<String>, type_name: impl Into<String>) -> Self {
+        JsonTableColumn(JsonTableColumnKind::Regular {
+            name: name.into(),
+            type_name: type_name.into(),
+            exists: false,
+            path: None,
+        })
+    }
+
+    /// Make a `name FOR ORDINALITY` column, numbering the rows starting from 1
+    pub fn for_ordinality(name: impl Into<String>) -> Self {
+        JsonTableColumn(JsonTableColumnKind::Ordinality(name.into()))
+    }
+
+    /// Make a `NESTED PATH path COLUMNS (...)` column, producing a row for each item at the path
+    ///
+    /// ```
+    /// use scooby::postgres::{json_table, JsonTableColumn};
+    ///
+    /// let table = json_table("data", "'$.posts[*]'").columns([
+    ///     JsonTableColumn::new("title", "text"),
+    ///     JsonTableColumn::nested("'$.tags[*]'", [JsonTableColumn::new("tag", "text").path("'$'")]),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "JSON_TABLE(data, '$.posts[*]' COLUMNS (title text, NESTED PATH '$.tags[*]' COLUMNS (tag text PATH '$')))",
+    /// );
+    /// ```
+    pub fn nested<const N: usize>(
+        path: impl Into<Expression>,
+        columns: impl IntoNonZeroArray<JsonTableColumn, N>,
+    ) -> Self {
+        JsonTableColumn(JsonTableColumnKind::Nested {
+            path: path.into(),
+            columns: columns.into_non_zero_array().into(),
+        })
+    }
+
+    /// Take the value at the given path instead, i.e. `name type PATH path`
+    ///
+    /// Does nothing for ordinality and nested columns.
+    pub fn path(mut self, new_path: impl Into<Expression>) -> Self {
+        if let JsonTableColumnKind::Regular { path, .. } = &mut self.0 {
+            *path = Some(new_path.into());
+        }
+        self
+    }
+
+    /// Check whether the path matches anything instead of taking the value there, i.e. `name type EXISTS`
+    ///
+    /// Does nothing for ordinality and nested columns.
+    ///
+    /// ```
+    /// use scooby::postgres::JsonTableColumn;
+    ///
+    /// let column = JsonTableColumn::new("has_tags", "boolean").exists().path("'$.tags'");
+    ///
+    /// assert_eq!(column.to_string(), "has_tags boolean EXISTS PATH '$.tags'");
+    /// ```
+    pub fn exists(mut self) -> Self {
+        if let JsonTableColumnKind::Regular { exists, .. } = &mut self.0 {
+            *exists = true;
+        }
+        self
+    }
+}
+
+impl Display for JsonTableColumn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            JsonTableColumnKind::Regular {
+                name,
+                type_name,
+                exists,
+                path,
+            } => {
+                write!(f, "{} {}", name, type_name)?;
+
+                if *exists {
+                    write!(f, " EXISTS")?;
+                }
+
+                if let Some(path) = path {
+                    write!(f, " PATH {}", path)?;
+                }
+
+                Ok(())
+            }
+            JsonTableColumnKind::Ordinality(name) => write!(f, "{} FOR ORDINALITY", name),
+            JsonTableColumnKind::Nested { path, columns } => {
+                write!(
+                    f,
+                    "NESTED PATH {} COLUMNS ({})",
+                    path,
+                    joined(columns, ", ")
+                )
+            }
+        }
+    }
+}
+
+impl<T: Into<String>, U: Into<String>> From<(T, U)> for JsonTableColumn {
+    fn from(value: (T, U)) -> Self {
+        JsonTableColumn::new(value.0, value.1)
+    }
+}
+
+/// `json_to_recordset(...)` or `jsonb_to_recordset(...)` call, to be aliased along with its column definitions
+#[must_use = "Making a recordset function call without aliasing it is pointless"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RecordsetFunction {
+    name: &'static str,
+    json: Expression,
+}
+
+impl RecordsetFunction {
+    /// Alias the resulting rows and define their columns, i.e. `json_to_recordset(...) AS alias(name type, ...)`
+    ///
+    /// Column definitions are `(name, type)` tuples, and there must be at least one.
+    pub fn as_<const N: usize>(
+        self,
+        alias: &str,
+        columns: impl IntoNonZeroArray<ColumnTypePair, N>,
+    ) -> Alias {
+        let columns = columns.into_non_zero_array();
+
+        format!("{}({})", self.name, self.json).as_(&format!(
+            "{}({})",
+            alias,
+            joined(&columns, ", ")
+        ))
+    }
+}
+
+/* Conversions */
+
+impl From<JsonTable> for FromItem {
+    fn from(table: JsonTable) -> Self {
+        FromItem::from(table.to_string())
+    }
+}
+
+impl<T> IntoIteratorOfSameType<T> for JsonTable
+where
+    T: From<JsonTable>,
+{
+    type Iterator = Once<T>;
+
+    fn into_some_iter(self) -> Self::Iterator {
+        once(self.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{json_table, jsonb_to_recordset, select, Aliasable, JsonTableColumn};
+
+    #[test]
+    fn json_table_columns() {
+        let sql = select(("o.id", "item.*"))
+            .from((
+                "Purchase".as_("o"),
+                json_table("o.data", "'$.items[*] ? (@.quantity >= $min)'")
+                    .passing("$1", "min")
+                    .columns([
+                        JsonTableColumn::for_ordinality("position"),
+                        JsonTableColumn::new("sku", "text"),
+                        JsonTableColumn::new("quantity", "integer").path("'$.qty'"),
+                        JsonTableColumn::new("discounted", "boolean")
+                            .exists()
+                            .path("'$.discount'"),
+                        JsonTableColumn::nested("'$.notes[*]'", [("note", "text")]),
+                    ])
+                    .as_("item"),
+            ))
+            .to_string();
+
+        // JSON_TABLE is only available in PostgreSQL 17 and later, so it's not checked against the server
+        assert_eq!(
+            sql,
+            "SELECT o.id, item.* FROM Purchase AS o, JSON_TABLE(o.data, '$.items[*] ? (@.quantity >= $min)' PASSING $1 AS min COLUMNS (position FOR ORDINALITY, sku text, quantity integer PATH '$.qty', discounted boolean EXISTS PATH '$.discount', NESTED PATH '$.notes[*]' COLUMNS (note text))) AS item",
+        );
+    }
+
+    #[test]
+    fn recordset() {
+        let sql = select("*")
+            .from(jsonb_to_recordset("$1").as_("x", [("id", "integer"), ("tags", "text[]")]))
+            .where_("x.id > 0")
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "SELECT * FROM jsonb_to_recordset($1) AS x(id integer, tags text[]) WHERE x.id > 0",
+        );
+    }
+}
@@ -0,0 +1,153 @@
+// Conversions of values of other crates into literal expressions, available with features of the same name,
+// except for `serde_json` which is enabled with the `json` feature
+
+#[cfg(feature = "json")]
+use crate::tools::literal;
+
+#[cfg(any(
+    feature = "uuid",
+    feature = "rust_decimal",
+    feature = "bigdecimal",
+    feature = "json"
+))]
+use super::{Expression, Literal};
+
+/// Render as a `UUID '67e55044-10b1-426f-9247-bb680e5fe0c8'` literal
+#[cfg(feature = "uuid")]
+impl From<uuid::Uuid> for Expression {
+    fn from(value: uuid::Uuid) -> Self {
+        Expression::from(format!("UUID '{}'", value.hyphenated()))
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl Literal for uuid::Uuid {}
+
+/// Render as a `NUMERIC '-123.45'` literal, keeping all of its digits
+#[cfg(feature = "rust_decimal")]
+impl From<rust_decimal::Decimal> for Expression {
+    fn from(value: rust_decimal::Decimal) -> Self {
+        Expression::from(format!("NUMERIC '{}'", value))
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl Literal for rust_decimal::Decimal {}
+
+/// Render as a `NUMERIC '-123.45'` literal, keeping all of its digits
+#[cfg(feature = "bigdecimal")]
+impl From<bigdecimal::BigDecimal> for Expression {
+    fn from(value: bigdecimal::BigDecimal) -> Self {
+        Expression::from(format!("NUMERIC '{}'", value))
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl Literal for bigdecimal::BigDecimal {}
+
+/// Render as a `JSONB '{"key": "value"}'` literal
+///
+/// The literal is escaped for `standard_conforming_strings`, which PostgreSQL has on by default.
+#[cfg(feature = "json")]
+impl From<serde_json::Value> for Expression {
+    fn from(value: serde_json::Value) -> Self {
+        Expression::from(format!("JSONB {}", literal(&value.to_string())))
+    }
+}
+
+#[cfg(feature = "json")]
+impl Literal for serde_json::Value {}
+
+#[cfg(all(test, feature = "uuid"))]
+mod uuid_tests {
+    use uuid::Uuid;
+
+    use crate::postgres::general::Expression;
+    use crate::postgres::insert_into;
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+
+    #[test]
+    fn uuid() {
+        let id = Uuid::from_u128(0x67E5504410B1426F9247BB680E5FE0C8);
+
+        let sql = insert_into("Person")
+            .columns(("id", "name"))
+            .values([(id, "$1")])
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "INSERT INTO Person (id, name) VALUES (UUID '67e55044-10b1-426f-9247-bb680e5fe0c8', $1)",
+        );
+        assert_eq!(
+            Expression::from(Uuid::nil()).to_string(),
+            "UUID '00000000-0000-0000-0000-000000000000'"
+        );
+        assert_eq!(Expression::from(Option::<Uuid>::None).to_string(), "NULL");
+    }
+}
+
+#[cfg(all(test, feature = "rust_decimal"))]
+mod rust_decimal_tests {
+    use rust_decimal::Decimal;
+
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::update;
+
+    #[test]
+    fn decimal() {
+        let sql = update("Account")
+            .set("balance", Decimal::new(-1234567890123456789, 10))
+            .set("rate", Decimal::new(5, 3))
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "UPDATE Account SET balance = NUMERIC '-123456789.0123456789', rate = NUMERIC '0.005'",
+        );
+    }
+}
+
+#[cfg(all(test, feature = "bigdecimal"))]
+mod bigdecimal_tests {
+    use std::str::FromStr;
+
+    use bigdecimal::BigDecimal;
+
+    use crate::postgres::general::Expression;
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+
+    #[test]
+    fn big_decimal() {
+        let value = BigDecimal::from_str("-98765432109876543210.000000000000000001").unwrap();
+
+        assert_correct_postgresql(
+            &format!("SELECT {}", Expression::from(value)),
+            "SELECT NUMERIC '-98765432109876543210.000000000000000001'",
+        );
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod json_tests {
+    use serde_json::json;
+
+    use crate::postgres::insert_into;
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+
+    #[test]
+    fn json() {
+        let settings =
+            json!({"name": "O'Brien", "path": "C:\\", "tags": ["a", "b"], "quote": "\""});
+
+        let sql = insert_into("Person")
+            .columns(("settings", "extra"))
+            .values([(settings, json!(null))])
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            r#"INSERT INTO Person (settings, extra) VALUES (JSONB '{"name":"O''Brien","path":"C:\\","quote":"\"","tags":["a","b"]}', JSONB 'null')"#,
+        );
+    }
+}
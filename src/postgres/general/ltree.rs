@@ -0,0 +1,116 @@
+use super::{Condition, Expression};
+
+/// Things that may be used as `ltree` label paths of the [ltree](https://www.postgresql.org/docs/current/ltree.html) extension
+///
+/// Implemented for everything that can be converted into an [`Expression`].
+#[allow(clippy::wrong_self_convention)]
+pub trait LtreeOperable {
+    /// Make a `x <@ y` condition, checking that `x` is a descendant of `y` or equal to it
+    ///
+    /// ```
+    /// use scooby::postgres::{select, LtreeOperable};
+    ///
+    /// let sql = select("*").from("Category").where_("path".is_descendant_of("'Top.Science'")).to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Category WHERE path <@ 'Top.Science'");
+    /// ```
+    fn is_descendant_of(self, ancestor: impl Into<Expression>) -> Condition;
+
+    /// Make a `x @> y` condition, checking that `x` is an ancestor of `y` or equal to it
+    fn is_ancestor_of(self, descendant: impl Into<Expression>) -> Condition;
+
+    /// Make a `x ~ y` condition, checking that the path matches an `lquery` pattern
+    ///
+    /// ```
+    /// use scooby::postgres::{select, LtreeOperable};
+    ///
+    /// let sql = select("*").from("Category").where_("path".matches_lquery("'*.Astronomy.*'")).to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Category WHERE path ~ '*.Astronomy.*'");
+    /// ```
+    fn matches_lquery(self, lquery: impl Into<Expression>) -> Condition;
+
+    /// Make a `x ? y` condition, checking that the path matches any of an array of `lquery` patterns
+    fn matches_any_lquery(self, lqueries: impl Into<Expression>) -> Condition;
+
+    /// Make a `x @ y` condition, checking that the path matches an `ltxtquery` full text search
+    fn matches_ltxtquery(self, ltxtquery: impl Into<Expression>) -> Condition;
+
+    /// Get the number of labels in the path, `nlevel(x)` style
+    fn nlevel(self) -> Expression;
+
+    /// Get a part of the path starting at `offset` and `len` labels long, `subpath(x, offset, len)` style
+    ///
+    /// ```
+    /// use scooby::postgres::{select, LtreeOperable};
+    ///
+    /// let sql = select("path".subpath("0", "2")).from("Category").to_string();
+    ///
+    /// assert_eq!(sql, "SELECT subpath(path, 0, 2) FROM Category");
+    /// ```
+    fn subpath(self, offset: impl Into<Expression>, len: impl Into<Expression>) -> Expression;
+}
+
+#[allow(clippy::wrong_self_convention)]
+impl<T> LtreeOperable for T
+where
+    T: Into<Expression>,
+{
+    fn is_descendant_of(self, ancestor: impl Into<Expression>) -> Condition {
+        format!("{} <@ {}", self.into(), ancestor.into())
+    }
+
+    fn is_ancestor_of(self, descendant: impl Into<Expression>) -> Condition {
+        format!("{} @> {}", self.into(), descendant.into())
+    }
+
+    fn matches_lquery(self, lquery: impl Into<Expression>) -> Condition {
+        format!("{} ~ {}", self.into(), lquery.into())
+    }
+
+    fn matches_any_lquery(self, lqueries: impl Into<Expression>) -> Condition {
+        format!("{} ? {}", self.into(), lqueries.into())
+    }
+
+    fn matches_ltxtquery(self, ltxtquery: impl Into<Expression>) -> Condition {
+        format!("{} @ {}", self.into(), ltxtquery.into())
+    }
+
+    fn nlevel(self) -> Expression {
+        Expression::from(format!("nlevel({})", self.into()))
+    }
+
+    fn subpath(self, offset: impl Into<Expression>, len: impl Into<Expression>) -> Expression {
+        Expression::from(format!(
+            "subpath({}, {}, {})",
+            self.into(),
+            offset.into(),
+            len.into()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{select, LtreeOperable};
+
+    #[test]
+    fn path_matching() {
+        let sql = select(("path".nlevel(), "path".subpath("1", "2")))
+            .from("Category")
+            .where_((
+                "path".is_descendant_of("$1"),
+                "$1".is_ancestor_of("path"),
+                "path".matches_lquery("'Top.*{1,2}'"),
+                "path".matches_any_lquery("$2"),
+                "path".matches_ltxtquery("'Astro* & !pictures'"),
+            ))
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "SELECT nlevel(path), subpath(path, 1, 2) FROM Category WHERE path <@ $1 AND $1 @> path AND path ~ 'Top.*{1,2}' AND path ? $2 AND path @ 'Astro* & !pictures'",
+        );
+    }
+}
@@ -0,0 +1,202 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::tools::{joined, IntoIteratorOfSameType};
+
+use super::{Condition, Expression};
+
+/// Construct a range of the given type, `daterange(lower, upper)` style
+///
+/// Bounds are `[)`, i.e. lower bound inclusive and upper bound exclusive, unless specified otherwise.
+/// Use together with [`Comparable`][super::Comparable] and [`RangeOperable`] to filter by ranges.
+///
+/// ```
+/// use scooby::postgres::{range, select, Bounds, Comparable};
+///
+/// let sql = select("*")
+///     .from("Booking")
+///     .where_("during".overlaps(range("daterange", "$1", "$2").bounds(Bounds::Inclusive)))
+///     .to_string();
+///
+/// assert_eq!(sql, "SELECT * FROM Booking WHERE during && daterange($1, $2, '[]')");
+/// ```
+pub fn range(
+    range_type: impl Into<String>,
+    lower: impl Into<Expression>,
+    upper: impl Into<Expression>,
+) -> Range {
+    Range {
+        range_type: range_type.into(),
+        lower: lower.into(),
+        upper: upper.into(),
+        bounds: None,
+    }
+}
+
+/// Construct a multirange of the given type out of ranges, `datemultirange(a, b)` style
+///
+/// ```
+/// use scooby::postgres::{multirange, range, select};
+///
+/// let sql = select(multirange("datemultirange", (range("daterange", "$1", "$2"), "$3"))).to_string();
+///
+/// assert_eq!(sql, "SELECT datemultirange(daterange($1, $2), $3)");
+/// ```
+pub fn multirange(
+    multirange_type: impl Into<String>,
+    ranges: impl IntoIteratorOfSameType<Expression>,
+) -> Expression {
+    let ranges: Vec<Expression> = ranges.into_some_iter().collect();
+    Expression::from(format!(
+        "{}({})",
+        multirange_type.into(),
+        joined(&ranges, ", ")
+    ))
+}
+
+/// Range constructor, created with [`range`]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[must_use = "Making a range without using it is pointless"]
+pub struct Range {
+    range_type: String,
+    lower: Expression,
+    upper: Expression,
+    bounds: Option<Bounds>,
+}
+
+impl Range {
+    /// Specify which bounds of this range are inclusive
+    pub fn bounds(mut self, bounds: Bounds) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+}
+
+impl Display for Range {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({}, {}", self.range_type, self.lower, self.upper)?;
+
+        if let Some(bounds) = &self.bounds {
+            write!(f, ", {}", bounds)?;
+        }
+
+        write!(f, ")")
+    }
+}
+
+impl From<Range> for Expression {
+    fn from(range: Range) -> Self {
+        Expression::from(range.to_string())
+    }
+}
+
+/// Inclusivity of bounds of a [`Range`]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Bounds {
+    /// `'[)'`, lower bound inclusive and upper bound exclusive, which is what PostgreSQL does by default
+    InclusiveExclusive,
+    /// `'(]'`, lower bound exclusive and upper bound inclusive
+    ExclusiveInclusive,
+    /// `'[]'`, both bounds inclusive
+    Inclusive,
+    /// `'()'`, both bounds exclusive
+    Exclusive,
+}
+
+impl Display for Bounds {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Bounds::InclusiveExclusive => write!(f, "'[)'"),
+            Bounds::ExclusiveInclusive => write!(f, "'(]'"),
+            Bounds::Inclusive => write!(f, "'[]'"),
+            Bounds::Exclusive => write!(f, "'()'"),
+        }
+    }
+}
+
+/// Things that may be used as ranges or multiranges
+///
+/// Implemented for everything that can be converted into an [`Expression`].
+/// See [`Comparable`][super::Comparable] for containment and overlap conditions.
+pub trait RangeOperable {
+    /// Get the lower bound of this range, `lower(x)` style
+    ///
+    /// ```
+    /// use scooby::postgres::{select, RangeOperable};
+    ///
+    /// let sql = select(("during".lower_bound(), "during".upper_bound())).from("Booking").to_string();
+    ///
+    /// assert_eq!(sql, "SELECT lower(during), upper(during) FROM Booking");
+    /// ```
+    fn lower_bound(self) -> Expression;
+
+    /// Get the upper bound of this range, `upper(x)` style
+    fn upper_bound(self) -> Expression;
+
+    /// Make a `x -|- y` condition, checking that ranges are adjacent
+    ///
+    /// ```
+    /// use scooby::postgres::{select, RangeOperable};
+    ///
+    /// let sql = select("*").from("Booking").where_("during".adjacent_to("$1")).to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Booking WHERE during -|- $1");
+    /// ```
+    fn adjacent_to(self, other: impl Into<Expression>) -> Condition;
+
+    /// Make a `x << y` condition, checking that `x` is strictly left of `y`
+    fn strictly_left_of(self, other: impl Into<Expression>) -> Condition;
+
+    /// Make a `x >> y` condition, checking that `x` is strictly right of `y`
+    fn strictly_right_of(self, other: impl Into<Expression>) -> Condition;
+}
+
+impl<T> RangeOperable for T
+where
+    T: Into<Expression>,
+{
+    fn lower_bound(self) -> Expression {
+        Expression::from(format!("lower({})", self.into()))
+    }
+
+    fn upper_bound(self) -> Expression {
+        Expression::from(format!("upper({})", self.into()))
+    }
+
+    fn adjacent_to(self, other: impl Into<Expression>) -> Condition {
+        format!("{} -|- {}", self.into(), other.into())
+    }
+
+    fn strictly_left_of(self, other: impl Into<Expression>) -> Condition {
+        format!("{} << {}", self.into(), other.into())
+    }
+
+    fn strictly_right_of(self, other: impl Into<Expression>) -> Condition {
+        format!("{} >> {}", self.into(), other.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{range, select, Bounds, Comparable, RangeOperable};
+
+    #[test]
+    fn range_operators() {
+        let sql = select(("during".lower_bound(), "during".upper_bound()))
+            .from("Booking")
+            .where_((
+                "during".includes(range("tstzrange", "$1", "$2").bounds(Bounds::Exclusive)),
+                "during".includes("now()"),
+                "during".overlaps(range("tstzrange", "$3", "NULL")),
+                "during".adjacent_to("$4"),
+                "during".strictly_left_of("$5"),
+                "during".strictly_right_of("$6"),
+            ))
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "SELECT lower(during), upper(during) FROM Booking WHERE during @> tstzrange($1, $2, '()') AND during @> now() AND during && tstzrange($3, NULL) AND during -|- $4 AND during << $5 AND during >> $6",
+        );
+    }
+}
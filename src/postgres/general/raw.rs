@@ -0,0 +1,106 @@
+use std::fmt::{self, Display, Formatter};
+use std::iter::{once, Once};
+
+use crate::postgres::statements::FromItem;
+use crate::tools::IntoIteratorOfSameType;
+
+use super::{Column, Expression};
+
+/// Mark a fragment of SQL as deliberately hand-written, to be included into a statement as it is
+///
+/// Plain strings are accepted everywhere too, and are rendered just the same. Use this to make
+/// fragments that come from elsewhere, or that are assembled with `format!`, stand out in code review.
+///
+/// ```
+/// use scooby::postgres::{raw, select};
+///
+/// let sql = select("*")
+///     .from(raw("generate_series(1, 10) AS n"))
+///     .where_(raw("n % 2 = 0"))
+///     .to_string();
+///
+/// assert_eq!(sql, "SELECT * FROM generate_series(1, 10) AS n WHERE n % 2 = 0");
+/// ```
+pub fn raw(sql: impl Into<String>) -> Raw {
+    Raw(sql.into())
+}
+
+/// Hand-written fragment of SQL, created with [`raw`]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Raw(String);
+
+impl Display for Raw {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl AsRef<str> for Raw {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/* Conversions */
+
+impl From<Raw> for String {
+    fn from(raw: Raw) -> Self {
+        raw.0
+    }
+}
+
+impl From<Raw> for Expression {
+    fn from(raw: Raw) -> Self {
+        Expression::from(raw.0)
+    }
+}
+
+impl From<Raw> for Column {
+    fn from(raw: Raw) -> Self {
+        Column::from(raw.0)
+    }
+}
+
+impl From<Raw> for FromItem {
+    fn from(raw: Raw) -> Self {
+        FromItem::from(raw.0)
+    }
+}
+
+impl<T> IntoIteratorOfSameType<T> for Raw
+where
+    T: From<Raw>,
+{
+    type Iterator = Once<T>;
+
+    fn into_some_iter(self) -> Self::Iterator {
+        once(self.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{raw, select, update, Aliasable, Comparable, Orderable};
+
+    #[test]
+    fn everywhere() {
+        let sql = select((raw("count(*)").as_("n"), raw("max(age)")))
+            .from(raw("Person"))
+            .where_((raw("age > 18"), raw("name").like("'J%'")))
+            .group_by(raw("city"))
+            .order_by(raw("n").desc())
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "SELECT count(*) AS n, max(age) FROM Person WHERE age > 18 AND name LIKE 'J%' GROUP BY city ORDER BY n DESC",
+        );
+
+        let sql = update(raw("Person"))
+            .set(raw("age"), raw("age + 1"))
+            .to_string();
+
+        assert_correct_postgresql(&sql, "UPDATE Person SET age = age + 1");
+    }
+}
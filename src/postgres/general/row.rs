@@ -0,0 +1,45 @@
+use crate::tools::{joined, IntoIteratorOfSameType};
+
+use super::Expression;
+
+/// Construct a row value out of the given fields, `ROW(a, b, c)` style
+///
+/// Useful for comparing composite values and for inserting into columns of composite types.
+///
+/// ```
+/// use scooby::postgres::{insert_into, row};
+///
+/// let sql = insert_into("Shop")
+///     .columns(("name", "address"))
+///     .values([("$1", row(("$2", "$3", "$4")))])
+///     .to_string();
+///
+/// assert_eq!(sql, "INSERT INTO Shop (name, address) VALUES ($1, ROW($2, $3, $4))");
+/// ```
+///
+/// For lexicographic comparison of several columns at once, see [`compare_rows`][super::compare_rows].
+pub fn row(fields: impl IntoIteratorOfSameType<Expression>) -> Expression {
+    let fields: Vec<Expression> = fields.into_some_iter().collect();
+    Expression::from(format!("ROW({})", joined(&fields, ", ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{row, select, update, Comparable};
+
+    #[test]
+    fn composite_values() {
+        let sql = update("Shop")
+            .set("address", row(("'Main St'", "1", "NULL")))
+            .where_("address".is_distinct_from(row(("$1", "$2", "$3"))))
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "UPDATE Shop SET address = ROW('Main St', 1, NULL) WHERE address IS DISTINCT FROM ROW($1, $2, $3)",
+        );
+
+        assert_correct_postgresql(&select(row(())).to_string(), "SELECT ROW()");
+    }
+}
@@ -0,0 +1,69 @@
+use crate::tools::{joined, IntoNonZeroArray};
+
+use super::{Condition, Expression};
+
+/// Compare two row values of the same size, `(a, b) < (c, d)` style
+///
+/// Rows are compared lexicographically, which is what filtering by compound keys needs.
+/// Both rows must have the same number of items, which is checked at compile time:
+///
+/// ```
+/// use scooby::postgres::{compare_rows, select};
+///
+/// let sql = select("*")
+///     .from("Post")
+///     .where_(compare_rows(("created_at", "id"), "<", ("$1", "$2")))
+///     .to_string();
+///
+/// assert_eq!(sql, "SELECT * FROM Post WHERE (created_at, id) < ($1, $2)");
+/// ```
+///
+/// ```compile_fail
+/// use scooby::postgres::compare_rows;
+///
+/// compare_rows(("created_at", "id"), "<", ("$1",));
+/// ```
+pub fn compare_rows<const N: usize>(
+    left: impl IntoNonZeroArray<Expression, N>,
+    operator: &str,
+    right: impl IntoNonZeroArray<Expression, N>,
+) -> Condition {
+    format!(
+        "({}) {} ({})",
+        joined(&left.into_non_zero_array(), ", "),
+        operator,
+        joined(&right.into_non_zero_array(), ", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{compare_rows, select, Aliasable, Joinable};
+
+    #[test]
+    fn where_and_join() {
+        let sql = select("*")
+            .from(
+                "Version"
+                    .as_("v")
+                    .inner_join("Release".as_("r"))
+                    .on(&compare_rows(
+                        ["v.major", "v.minor"],
+                        "=",
+                        ["r.major", "r.minor"],
+                    )),
+            )
+            .where_(compare_rows(
+                ("v.major", "v.minor", "v.patch"),
+                ">=",
+                ["1", "2", "3"],
+            ))
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "SELECT * FROM Version AS v INNER JOIN Release AS r ON (v.major, v.minor) = (r.major, r.minor) WHERE (v.major, v.minor, v.patch) >= (1, 2, 3)",
+        );
+    }
+}
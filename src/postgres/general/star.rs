@@ -0,0 +1,102 @@
+use super::{Expression, TableName};
+
+/// Select all columns, `*`
+///
+/// ```
+/// use scooby::postgres::{select, star};
+///
+/// assert_eq!(select(star()).from("City").to_string(), "SELECT * FROM City");
+/// ```
+pub fn star() -> Expression {
+    Expression::from("*")
+}
+
+/// Table names that may qualify columns or be qualified with a schema, e.g. `t.*` or `s.t`
+///
+/// Implemented for everything that can be converted into a table name, including [`Table`][crate::postgres::schema::Table] references.
+pub trait Qualifiable {
+    /// Select all columns of this table, `t.*`
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Aliasable, Joinable, Qualifiable};
+    ///
+    /// let sql = select(("c".star(), "country.name".as_("country")))
+    ///     .from("City".as_("c").inner_join("Country".as_("country")).on("c.country_id = country.id"))
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "SELECT c.*, country.name AS country FROM City AS c INNER JOIN Country AS country ON c.country_id = country.id");
+    /// ```
+    fn star(self) -> Expression;
+
+    /// Qualify this table name with a schema name, `s.t`
+    ///
+    /// Names are rendered as is. Quote them with [`quote_ident`][crate::postgres::quote_ident], or declare
+    /// the table as a [`Table`][crate::postgres::schema::Table] to have it quoted and reused.
+    ///
+    /// ```
+    /// use scooby::postgres::{quote_ident, select, Qualifiable};
+    ///
+    /// let sql = select("*").from("Person".in_schema("app")).to_string();
+    /// assert_eq!(sql, "SELECT * FROM app.Person");
+    ///
+    /// let sql = select("*").from(quote_ident("Person").in_schema(quote_ident("app"))).to_string();
+    /// assert_eq!(sql, r#"SELECT * FROM "app"."Person""#);
+    /// ```
+    fn in_schema(self, schema: impl Into<String>) -> TableName;
+}
+
+impl<T> Qualifiable for T
+where
+    T: Into<TableName>,
+{
+    fn star(self) -> Expression {
+        Expression::from(format!("{}.*", self.into()))
+    }
+
+    fn in_schema(self, schema: impl Into<String>) -> TableName {
+        format!("{}.{}", schema.into(), self.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::postgres::schema::Table;
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{delete_from, insert_into, select_all_of, update, Joinable, Qualifiable};
+
+    #[test]
+    fn stars() {
+        let person = Table::new("Person").quoted();
+
+        let sql = select_all_of(&person)
+            .and_select("Pet".star())
+            .from(
+                (&person)
+                    .inner_join("Pet")
+                    .on(r#"Pet.owner_id = "Person".id"#),
+            )
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            r#"SELECT "Person".*, Pet.* FROM "Person" INNER JOIN Pet ON Pet.owner_id = "Person".id"#,
+        );
+    }
+
+    #[test]
+    fn schema_qualified() {
+        let sql = insert_into("Person".in_schema("app"))
+            .columns("name")
+            .values(["$1"])
+            .to_string();
+        assert_correct_postgresql(&sql, "INSERT INTO app.Person (name) VALUES ($1)");
+
+        let sql = update("Person".in_schema("app"))
+            .set("name", "$1")
+            .to_string();
+        assert_correct_postgresql(&sql, "UPDATE app.Person SET name = $1");
+
+        let sql = delete_from("Person".in_schema("app")).to_string();
+        assert_correct_postgresql(&sql, "DELETE FROM app.Person");
+    }
+}
@@ -0,0 +1,27 @@
+use std::fmt::{self, Display, Formatter};
+
+use super::Expression;
+
+/// Storage parameter of a table or an index, i.e. `name = value` in a `WITH (...)` clause
+///
+/// Create it from a `(name, value)` tuple.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StorageParameter {
+    name: String,
+    value: Expression,
+}
+
+impl Display for StorageParameter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = {}", self.name, self.value)
+    }
+}
+
+impl<T: Into<String>, U: Into<Expression>> From<(T, U)> for StorageParameter {
+    fn from((name, value): (T, U)) -> Self {
+        StorageParameter {
+            name: name.into(),
+            value: value.into(),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crate::postgres::statements::Select;
+
+use super::{Condition, Expression};
+
+/// Use a `SELECT` statement returning a single value as an expression, `(SELECT ...)` style
+///
+/// ```
+/// use scooby::postgres::{select, subquery};
+///
+/// let sql = select(("name", subquery(select("count(*)").from("Post").where_("author_id = Person.id"))))
+///     .from("Person")
+///     .to_string();
+///
+/// assert_eq!(sql, "SELECT name, (SELECT count(*) FROM Post WHERE author_id = Person.id) FROM Person");
+/// ```
+pub fn subquery(query: Select) -> Expression {
+    Expression::from(query)
+}
+
+/// Make an `EXISTS (SELECT ...)` condition
+///
+/// ```
+/// use scooby::postgres::{exists, select};
+///
+/// let sql = select("*")
+///     .from("Person")
+///     .where_(exists(select("1").from("Post").where_("author_id = Person.id")))
+///     .to_string();
+///
+/// assert_eq!(sql, "SELECT * FROM Person WHERE EXISTS (SELECT 1 FROM Post WHERE author_id = Person.id)");
+/// ```
+pub fn exists(query: Select) -> Condition {
+    format!("EXISTS ({})", query)
+}
+
+/// Make a `NOT EXISTS (SELECT ...)` condition
+///
+/// ```
+/// use scooby::postgres::{not_exists, select};
+///
+/// let sql = select("*")
+///     .from("Person")
+///     .where_(not_exists(select("1").from("Post").where_("author_id = Person.id")))
+///     .to_string();
+///
+/// assert_eq!(sql, "SELECT * FROM Person WHERE NOT EXISTS (SELECT 1 FROM Post WHERE author_id = Person.id)");
+/// ```
+pub fn not_exists(query: Select) -> Condition {
+    format!("NOT EXISTS ({})", query)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{exists, not_exists, select, subquery, Comparable};
+
+    #[test]
+    fn all_wrappers() {
+        let sql = select(("name", subquery(select("max(amount)").from("Sale"))))
+            .from("Person")
+            .where_((
+                exists(select("1").from("Sale")),
+                not_exists(select("1").from("Refund")),
+                "region".in_(select("region").from("TopRegion")),
+                "id".not_in(select("person_id").from("Banned")),
+                "age".compare_any(">", select("age").from("Person")),
+                "age".compare_all("<=", select("age").from("Person")),
+            ))
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "SELECT name, (SELECT max(amount) FROM Sale) FROM Person WHERE EXISTS (SELECT 1 FROM Sale) AND NOT EXISTS (SELECT 1 FROM Refund) AND region IN (SELECT region FROM TopRegion) AND id NOT IN (SELECT person_id FROM Banned) AND age > ANY (SELECT age FROM Person) AND age <= ALL (SELECT age FROM Person)",
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use crate::tools::IntoIteratorOfSameType;
+
+use super::{func, Expression, FunctionCall};
+
+/// Things that may be manipulated as strings
+///
+/// Implemented for everything that can be converted into an [`Expression`].
+pub trait TextOperable {
+    /// Concatenate this expression with another one, `x || y` style
+    ///
+    /// ```
+    /// use scooby::postgres::{select, TextOperable};
+    ///
+    /// let sql = select("first_name".concat("' '").concat("last_name")).from("Person").to_string();
+    ///
+    /// assert_eq!(sql, "SELECT first_name || ' ' || last_name FROM Person");
+    /// ```
+    fn concat(self, other: impl Into<Expression>) -> Expression;
+
+    /// Convert this string to lower case, `lower(x)` style
+    ///
+    /// ```
+    /// use scooby::postgres::{select, TextOperable};
+    ///
+    /// let sql = select("*").from("Person").where_(format!("{} = lower($1)", "email".lower())).to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Person WHERE lower(email) = lower($1)");
+    /// ```
+    fn lower(self) -> Expression;
+
+    /// Convert this string to upper case, `upper(x)` style
+    fn upper(self) -> Expression;
+
+    /// Get first `n` characters of this string, `left(x, n)` style
+    ///
+    /// ```
+    /// use scooby::postgres::{select, TextOperable};
+    ///
+    /// let sql = select(("name".left("1"), "name".right("3"))).from("Person").to_string();
+    ///
+    /// assert_eq!(sql, "SELECT left(name, 1), right(name, 3) FROM Person");
+    /// ```
+    fn left(self, n: impl Into<Expression>) -> Expression;
+
+    /// Get last `n` characters of this string, `right(x, n)` style
+    fn right(self, n: impl Into<Expression>) -> Expression;
+
+    /// Find the location of a substring in this string, `position(substring IN x)` style
+    ///
+    /// ```
+    /// use scooby::postgres::{select, TextOperable};
+    ///
+    /// let sql = select("email".position("'@'")).from("Person").to_string();
+    ///
+    /// assert_eq!(sql, "SELECT position('@' IN email) FROM Person");
+    /// ```
+    fn position(self, substring: impl Into<Expression>) -> Expression;
+}
+
+impl<T> TextOperable for T
+where
+    T: Into<Expression>,
+{
+    fn concat(self, other: impl Into<Expression>) -> Expression {
+        Expression::from(format!("{} || {}", self.into(), other.into()))
+    }
+
+    fn lower(self) -> Expression {
+        Expression::from(format!("lower({})", self.into()))
+    }
+
+    fn upper(self) -> Expression {
+        Expression::from(format!("upper({})", self.into()))
+    }
+
+    fn left(self, n: impl Into<Expression>) -> Expression {
+        Expression::from(format!("left({}, {})", self.into(), n.into()))
+    }
+
+    fn right(self, n: impl Into<Expression>) -> Expression {
+        Expression::from(format!("right({}, {})", self.into(), n.into()))
+    }
+
+    fn position(self, substring: impl Into<Expression>) -> Expression {
+        Expression::from(format!("position({} IN {})", substring.into(), self.into()))
+    }
+}
+
+/// Concatenate strings with a separator, skipping `NULL`s, `concat_ws(separator, a, b)` style
+///
+/// ```
+/// use scooby::postgres::{concat_ws, select};
+///
+/// let sql = select(concat_ws("' '", ("first_name", "middle_name", "last_name"))).from("Person").to_string();
+///
+/// assert_eq!(sql, "SELECT concat_ws(' ', first_name, middle_name, last_name) FROM Person");
+/// ```
+pub fn concat_ws(
+    separator: impl Into<Expression>,
+    strings: impl IntoIteratorOfSameType<Expression>,
+) -> FunctionCall {
+    let arguments: Vec<Expression> = std::iter::once(separator.into())
+        .chain(strings.into_some_iter())
+        .collect();
+
+    func("concat_ws", arguments)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{concat_ws, select, Comparable, TextOperable};
+
+    #[test]
+    fn normalized_search() {
+        let sql = select((
+            concat_ws("' '", ("first_name", "last_name")),
+            "first_name".concat("' '").concat("last_name").upper(),
+            "email".position("'@'"),
+            "name".left("1").concat("name".right("1")),
+        ))
+        .from("Person")
+        .where_("email".lower().like("lower($1)"))
+        .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "SELECT concat_ws(' ', first_name, last_name), upper(first_name || ' ' || last_name), position('@' IN email), left(name, 1) || right(name, 1) FROM Person WHERE lower(email) LIKE lower($1)",
+        );
+    }
+}
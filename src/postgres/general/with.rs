@@ -1,11 +1,12 @@
 use std::fmt::Display;
+use std::sync::Arc;
 
 use crate::postgres::general::{Expression, TableName};
 use crate::postgres::statements::{
     delete_from_with, insert_into_with, select_with, update_with, BareInsertInto, BareUpdate,
-    DeleteFrom, InsertInto, Select, Update, Values,
+    DeleteFrom, InsertInto, Select, TableCommand, Update, Values,
 };
-use crate::tools::{joined, IntoIteratorOfSameType};
+use crate::tools::{joined, listed, IntoIteratorOfSameType};
 
 use super::Column;
 
@@ -30,7 +31,7 @@ pub fn with(name: impl Into<TableName>) -> WithQueryBuilder {
 /// - [`insert_into`][WithClause::insert_into]
 ///
 /// See [`with`] docs for more details and examples.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct WithClause {
     queries: Vec<WithQuery>,
 }
@@ -51,6 +52,19 @@ impl WithClause {
         }
     }
 
+    pub(crate) fn push(
+        &mut self,
+        name: TableName,
+        columns: Vec<Column>,
+        target: impl UsableInWithQuery,
+    ) {
+        self.queries.push(WithQuery {
+            name,
+            columns,
+            as_: Arc::from(target.to_string()),
+        });
+    }
+
     /// Start building a `SELECT` statement with this `WITH` clause
     pub fn select(self, expressions: impl IntoIteratorOfSameType<Expression>) -> Select {
         select_with(expressions.into_some_iter().collect(), self)
@@ -74,16 +88,18 @@ impl WithClause {
 
 impl Display for WithClause {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "WITH {}", joined(&self.queries, ", "))
+        write!(f, "WITH")?;
+        listed(&self.queries, ", ").fmt(f)
     }
 }
 
 /// Specific table inside a `WITH` clause
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct WithQuery {
     name: TableName,
     columns: Vec<Column>,
-    as_: String,
+    // Shared, so that cloning statements with big `WITH` clauses is cheap
+    as_: Arc<str>,
 }
 
 impl Display for WithQuery {
@@ -116,11 +132,7 @@ impl WithQueryBuilder {
 
     /// Specify the statement that will be used as a basis for the table
     pub fn as_(mut self, target: impl UsableInWithQuery) -> WithClause {
-        self.clause.queries.push(WithQuery {
-            name: self.name,
-            columns: self.columns,
-            as_: target.to_string(),
-        });
+        self.clause.push(self.name, self.columns, target);
         self.clause
     }
 }
@@ -131,9 +143,27 @@ impl WithQueryBuilder {
 /// - `INSERT INTO`
 /// - `DELETE FROM`
 /// - `UPDATE`
+/// - `TABLE`
 pub trait UsableInWithQuery: Display {}
 
 impl UsableInWithQuery for Select {}
 impl<V: Values> UsableInWithQuery for InsertInto<V> {}
 impl UsableInWithQuery for Update {}
 impl UsableInWithQuery for DeleteFrom {}
+impl UsableInWithQuery for TableCommand {}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::with;
+    use crate::postgres::select;
+
+    #[test]
+    fn clone_shares_queries() {
+        let clause = with("big").as_(select("*").from("Big"));
+        let copy = clause.clone();
+
+        assert!(Arc::ptr_eq(&clause.queries[0].as_, &copy.queries[0].as_));
+    }
+}
@@ -0,0 +1,203 @@
+//! Helpers for the [pgvector](https://github.com/pgvector/pgvector) extension
+//!
+//! Available with the `pgvector` feature.
+//!
+//! ```
+//! use scooby::postgres::{select, Parameters};
+//! use scooby::postgres::pgvector::{NearestNeighbours, VectorDistance, VectorOperable};
+//!
+//! let mut params = Parameters::new();
+//!
+//! let sql = select(("id", "content"))
+//!     .from("Document")
+//!     .nearest_neighbours("embedding", params.next().to_vector(), VectorDistance::Cosine, 5)
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     sql,
+//!     "SELECT id, content FROM Document ORDER BY embedding <=> CAST($1 AS vector) LIMIT 5",
+//! );
+//! ```
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::postgres::general::Expression;
+use crate::postgres::statements::Select;
+use crate::tools::joined;
+
+/// Make a vector literal out of its components, `CAST('[1,2,3]' AS vector)` style
+///
+/// ```
+/// use scooby::postgres::pgvector::vector_literal;
+///
+/// assert_eq!(vector_literal(&[1.0, 0.5, -2.0]).to_string(), "CAST('[1,0.5,-2]' AS vector)");
+/// ```
+pub fn vector_literal(components: &[f32]) -> Expression {
+    Expression::from(format!("CAST('[{}]' AS vector)", joined(components, ",")))
+}
+
+/// Distance metric between vectors, each with its own pgvector operator
+///
+/// Indexes are built for a specific metric, so queries need to use the same one to make use of them.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum VectorDistance {
+    /// `<->`, Euclidean distance
+    L2,
+    /// `<#>`, negative inner product, so that smaller is closer like with other metrics
+    InnerProduct,
+    /// `<=>`, cosine distance
+    Cosine,
+}
+
+impl Display for VectorDistance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            VectorDistance::L2 => write!(f, "<->"),
+            VectorDistance::InnerProduct => write!(f, "<#>"),
+            VectorDistance::Cosine => write!(f, "<=>"),
+        }
+    }
+}
+
+/// Things that may be used as pgvector vectors
+///
+/// Implemented for everything that can be converted into an [`Expression`].
+pub trait VectorOperable {
+    /// Get the distance between vectors using the given metric, `x <-> y` style
+    ///
+    /// ```
+    /// use scooby::postgres::select;
+    /// use scooby::postgres::pgvector::{VectorDistance, VectorOperable};
+    ///
+    /// let sql = select("embedding".distance_to("$1", VectorDistance::InnerProduct)).from("Document").to_string();
+    ///
+    /// assert_eq!(sql, "SELECT embedding <#> $1 FROM Document");
+    /// ```
+    fn distance_to(self, other: impl Into<Expression>, distance: VectorDistance) -> Expression;
+
+    /// Get the Euclidean distance between vectors, `x <-> y` style
+    fn l2_distance(self, other: impl Into<Expression>) -> Expression;
+
+    /// Get the negative inner product of vectors, `x <#> y` style
+    fn negative_inner_product(self, other: impl Into<Expression>) -> Expression;
+
+    /// Get the cosine distance between vectors, `x <=> y` style
+    fn cosine_distance(self, other: impl Into<Expression>) -> Expression;
+
+    /// Convert this expression, most often a placeholder, to a vector, `CAST(x AS vector)` style
+    ///
+    /// ```
+    /// use scooby::postgres::pgvector::VectorOperable;
+    ///
+    /// assert_eq!("$1".to_vector().to_string(), "CAST($1 AS vector)");
+    /// ```
+    fn to_vector(self) -> Expression;
+}
+
+impl<T> VectorOperable for T
+where
+    T: Into<Expression>,
+{
+    fn distance_to(self, other: impl Into<Expression>, distance: VectorDistance) -> Expression {
+        Expression::from(format!("{} {} {}", self.into(), distance, other.into()))
+    }
+
+    fn l2_distance(self, other: impl Into<Expression>) -> Expression {
+        self.distance_to(other, VectorDistance::L2)
+    }
+
+    fn negative_inner_product(self, other: impl Into<Expression>) -> Expression {
+        self.distance_to(other, VectorDistance::InnerProduct)
+    }
+
+    fn cosine_distance(self, other: impl Into<Expression>) -> Expression {
+        self.distance_to(other, VectorDistance::Cosine)
+    }
+
+    fn to_vector(self) -> Expression {
+        Expression::from(format!("CAST({} AS vector)", self.into()))
+    }
+}
+
+/// Statements that may look for nearest neighbours of a vector
+///
+/// Implemented for [`Select`].
+pub trait NearestNeighbours {
+    /// Only return `k` rows with `column` closest to `query`, i.e. `ORDER BY column <-> query LIMIT k`
+    ///
+    /// Any `ORDER BY` items added before take precedence over the distance, and prevent use of vector indexes.
+    ///
+    /// ```
+    /// use scooby::postgres::select;
+    /// use scooby::postgres::pgvector::{vector_literal, NearestNeighbours, VectorDistance};
+    ///
+    /// let sql = select("id")
+    ///     .from("Item")
+    ///     .nearest_neighbours("embedding", vector_literal(&[1.0, 2.0, 3.0]), VectorDistance::L2, 10)
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "SELECT id FROM Item ORDER BY embedding <-> CAST('[1,2,3]' AS vector) LIMIT 10");
+    /// ```
+    fn nearest_neighbours(
+        self,
+        column: impl Into<Expression>,
+        query: impl Into<Expression>,
+        distance: VectorDistance,
+        k: usize,
+    ) -> Self;
+}
+
+impl NearestNeighbours for Select {
+    fn nearest_neighbours(
+        self,
+        column: impl Into<Expression>,
+        query: impl Into<Expression>,
+        distance: VectorDistance,
+        k: usize,
+    ) -> Self {
+        self.order_by(column.distance_to(query, distance)).limit(k)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{vector_literal, NearestNeighbours, VectorDistance, VectorOperable};
+
+    use crate::postgres::select;
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+
+    #[test]
+    fn distances() {
+        let sql = select((
+            "embedding".l2_distance("$1"),
+            "embedding".negative_inner_product("$1"),
+            "embedding".cosine_distance(vector_literal(&[0.25])),
+        ))
+        .from("Item")
+        .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "SELECT embedding <-> $1, embedding <#> $1, embedding <=> CAST('[0.25]' AS vector) FROM Item",
+        );
+    }
+
+    #[test]
+    fn nearest_neighbours() {
+        let sql = select("id")
+            .from("Item")
+            .where_("category_id = $2")
+            .nearest_neighbours(
+                "embedding",
+                "$1".to_vector(),
+                VectorDistance::InnerProduct,
+                3,
+            )
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "SELECT id FROM Item WHERE category_id = $2 ORDER BY embedding <#> CAST($1 AS vector) LIMIT 3",
+        );
+    }
+}
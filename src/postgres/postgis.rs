@@ -0,0 +1,194 @@
+//! Helpers for the [PostGIS](https://postgis.net/) extension
+//!
+//! Available with the `postgis` feature.
+//!
+//! ```
+//! use scooby::postgres::{select, Parameters};
+//! use scooby::postgres::postgis::{st_point, SpatialOperable};
+//!
+//! let mut params = Parameters::new();
+//! let [lon, lat, meters] = params.next_array();
+//!
+//! let sql = select(("id", "location".st_as_geojson()))
+//!     .from("Shop")
+//!     .where_("location".to_geography().st_dwithin(st_point(lon, lat, 4326).to_geography(), meters))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     sql,
+//!     "SELECT id, ST_AsGeoJSON(location) FROM Shop \
+//!      WHERE ST_DWithin(CAST(location AS geography), CAST(ST_SetSRID(ST_MakePoint($1, $2), 4326) AS geography), $3)",
+//! );
+//! ```
+
+use crate::postgres::general::{Condition, Expression};
+
+/// Make a point geometry out of its coordinates with the given spatial reference system,
+/// `ST_SetSRID(ST_MakePoint(x, y), srid)` style
+///
+/// ```
+/// use scooby::postgres::postgis::st_point;
+///
+/// assert_eq!(st_point("$1", "$2", 4326).to_string(), "ST_SetSRID(ST_MakePoint($1, $2), 4326)");
+/// ```
+pub fn st_point(x: impl Into<Expression>, y: impl Into<Expression>, srid: u32) -> Expression {
+    Expression::from(format!(
+        "ST_SetSRID(ST_MakePoint({}, {}), {})",
+        x.into(),
+        y.into(),
+        srid
+    ))
+}
+
+/// Things that may be used as PostGIS geometries or geographies
+///
+/// Implemented for everything that can be converted into an [`Expression`].
+pub trait SpatialOperable {
+    /// Make a `ST_DWithin(x, y, distance)` condition, checking that geometries are within the distance of each other
+    ///
+    /// Distance is in units of the spatial reference system for geometries, and in meters for geographies.
+    ///
+    /// ```
+    /// use scooby::postgres::select;
+    /// use scooby::postgres::postgis::SpatialOperable;
+    ///
+    /// let sql = select("*").from("Shop").where_("location".st_dwithin("$1", "1000")).to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Shop WHERE ST_DWithin(location, $1, 1000)");
+    /// ```
+    fn st_dwithin(self, other: impl Into<Expression>, distance: impl Into<Expression>)
+        -> Condition;
+
+    /// Make a `ST_Contains(x, y)` condition, checking that `y` lies completely inside of `x`
+    ///
+    /// ```
+    /// use scooby::postgres::select;
+    /// use scooby::postgres::postgis::SpatialOperable;
+    ///
+    /// let sql = select("*").from("Region").where_("boundary".st_contains("$1")).to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Region WHERE ST_Contains(boundary, $1)");
+    /// ```
+    fn st_contains(self, other: impl Into<Expression>) -> Condition;
+
+    /// Make a `ST_Within(x, y)` condition, checking that `x` lies completely inside of `y`
+    fn st_within(self, other: impl Into<Expression>) -> Condition;
+
+    /// Make a `ST_Intersects(x, y)` condition, checking that geometries share any space
+    fn st_intersects(self, other: impl Into<Expression>) -> Condition;
+
+    /// Make a `x && y` condition, checking that bounding boxes of geometries overlap
+    ///
+    /// Much cheaper than exact checks, since it can be answered from a spatial index alone.
+    ///
+    /// ```
+    /// use scooby::postgres::select;
+    /// use scooby::postgres::postgis::SpatialOperable;
+    ///
+    /// let sql = select("*")
+    ///     .from("Shop")
+    ///     .where_("location".bbox_overlaps("ST_MakeEnvelope($1, $2, $3, $4, 4326)"))
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Shop WHERE location && ST_MakeEnvelope($1, $2, $3, $4, 4326)");
+    /// ```
+    fn bbox_overlaps(self, other: impl Into<Expression>) -> Condition;
+
+    /// Get the distance between geometries, `ST_Distance(x, y)` style
+    fn st_distance(self, other: impl Into<Expression>) -> Expression;
+
+    /// Get this geometry as GeoJSON text, `ST_AsGeoJSON(x)` style
+    fn st_as_geojson(self) -> Expression;
+
+    /// Convert this expression to a geometry, `CAST(x AS geometry)` style
+    ///
+    /// ```
+    /// use scooby::postgres::select;
+    /// use scooby::postgres::postgis::SpatialOperable;
+    ///
+    /// let sql = select("'POINT(0 0)'".to_geometry()).to_string();
+    ///
+    /// assert_eq!(sql, "SELECT CAST('POINT(0 0)' AS geometry)");
+    /// ```
+    fn to_geometry(self) -> Expression;
+
+    /// Convert this expression to a geography, `CAST(x AS geography)` style
+    fn to_geography(self) -> Expression;
+}
+
+impl<T> SpatialOperable for T
+where
+    T: Into<Expression>,
+{
+    fn st_dwithin(
+        self,
+        other: impl Into<Expression>,
+        distance: impl Into<Expression>,
+    ) -> Condition {
+        format!(
+            "ST_DWithin({}, {}, {})",
+            self.into(),
+            other.into(),
+            distance.into()
+        )
+    }
+
+    fn st_contains(self, other: impl Into<Expression>) -> Condition {
+        format!("ST_Contains({}, {})", self.into(), other.into())
+    }
+
+    fn st_within(self, other: impl Into<Expression>) -> Condition {
+        format!("ST_Within({}, {})", self.into(), other.into())
+    }
+
+    fn st_intersects(self, other: impl Into<Expression>) -> Condition {
+        format!("ST_Intersects({}, {})", self.into(), other.into())
+    }
+
+    fn bbox_overlaps(self, other: impl Into<Expression>) -> Condition {
+        format!("{} && {}", self.into(), other.into())
+    }
+
+    fn st_distance(self, other: impl Into<Expression>) -> Expression {
+        Expression::from(format!("ST_Distance({}, {})", self.into(), other.into()))
+    }
+
+    fn st_as_geojson(self) -> Expression {
+        Expression::from(format!("ST_AsGeoJSON({})", self.into()))
+    }
+
+    fn to_geometry(self) -> Expression {
+        Expression::from(format!("CAST({} AS geometry)", self.into()))
+    }
+
+    fn to_geography(self) -> Expression {
+        Expression::from(format!("CAST({} AS geography)", self.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{st_point, SpatialOperable};
+
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{select, Orderable};
+
+    #[test]
+    fn spatial_filters() {
+        let sql = select(("id", "location".st_as_geojson()))
+            .from("Shop")
+            .where_((
+                "location".bbox_overlaps("$1"),
+                "location".st_within("$1"),
+                "location".st_intersects("$1"),
+                "$1".to_geometry().st_contains("location"),
+            ))
+            .order_by("location".st_distance(st_point("$2", "$3", 4326)).asc())
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "SELECT id, ST_AsGeoJSON(location) FROM Shop WHERE location && $1 AND ST_Within(location, $1) AND ST_Intersects(location, $1) AND ST_Contains(CAST($1 AS geometry), location) ORDER BY ST_Distance(location, ST_SetSRID(ST_MakePoint($2, $3), 4326)) ASC",
+        );
+    }
+}
@@ -0,0 +1,362 @@
+//! Reusable table and column declarations
+//!
+//! Declare a [`Table`] and its [`TableColumn`]s once, and pass references to them
+//! anywhere a table name, column or expression is accepted:
+//!
+//! ```
+//! use scooby::postgres::{insert_into, select, update};
+//! use scooby::postgres::schema::Table;
+//!
+//! let person = Table::new("Person").in_schema("app").quoted();
+//! let id = person.column("id");
+//! let name = person.column("name");
+//!
+//! let sql = select((&id, &name)).from(&person).where_(format!("{} = $1", id)).to_string();
+//! assert_eq!(sql, r#"SELECT "id", "name" FROM "app"."Person" WHERE "id" = $1"#);
+//!
+//! let sql = insert_into(&person).columns((&id, &name)).values([("$1", "$2")]).to_string();
+//! assert_eq!(sql, r#"INSERT INTO "app"."Person" ("id", "name") VALUES ($1, $2)"#);
+//!
+//! let sql = update(&person).set(&name, "$1").to_string();
+//! assert_eq!(sql, r#"UPDATE "app"."Person" SET "name" = $1"#);
+//! ```
+//!
+//! Lists of columns used in several statements may be declared once as a [`ColumnList`].
+
+use std::fmt::{self, Display, Formatter};
+use std::iter::{once, Map, Once};
+use std::slice;
+
+use crate::postgres::general::{Alias, Aliasable, Column, Expression};
+use crate::postgres::statements::FromItem;
+use crate::tools::{quoted, IntoIteratorOfSameType, IntoNonZeroArray};
+
+/// Table name, optionally qualified with a schema and quoted
+///
+/// Usable by reference wherever a table name or a `FROM` item is expected.
+///
+/// ```
+/// use scooby::postgres::{select, Aliasable, Joinable};
+/// use scooby::postgres::schema::Table;
+///
+/// let country = Table::new("Country");
+/// let city = Table::new("City");
+///
+/// let sql = select("*")
+///     .from(country.as_("country").inner_join(&city).on("City.country_id = country.id"))
+///     .to_string();
+///
+/// assert_eq!(sql, "SELECT * FROM Country AS country INNER JOIN City ON City.country_id = country.id");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Table {
+    schema: Option<String>,
+    name: String,
+    quoted: bool,
+}
+
+impl Table {
+    /// Declare a table with the given name
+    pub fn new(name: impl Into<String>) -> Table {
+        Table {
+            schema: None,
+            name: name.into(),
+            quoted: false,
+        }
+    }
+
+    /// Qualify this table with a schema name
+    ///
+    /// ```
+    /// use scooby::postgres::schema::Table;
+    ///
+    /// assert_eq!(Table::new("Person").in_schema("app").to_string(), "app.Person");
+    /// ```
+    pub fn in_schema(mut self, schema: impl Into<String>) -> Table {
+        self.schema = Some(schema.into());
+        self
+    }
+
+    /// Quote names of this table, its schema and all of its columns when rendering
+    ///
+    /// ```
+    /// use scooby::postgres::schema::Table;
+    ///
+    /// let table = Table::new("Person").quoted();
+    ///
+    /// assert_eq!(table.to_string(), r#""Person""#);
+    /// assert_eq!(table.column("name").to_string(), r#""name""#);
+    /// ```
+    pub fn quoted(mut self) -> Table {
+        self.quoted = true;
+        self
+    }
+
+    /// Declare a column of this table
+    pub fn column(&self, name: impl Into<String>) -> TableColumn {
+        TableColumn {
+            table: self.clone(),
+            name: name.into(),
+        }
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(schema) = &self.schema {
+            write_identifier(f, schema, self.quoted)?;
+            write!(f, ".")?;
+        }
+
+        write_identifier(f, &self.name, self.quoted)
+    }
+}
+
+/// Column of a [`Table`]
+///
+/// Renders as a bare column name, which is what `INSERT` and `UPDATE` expect.
+/// Use [`qualified`][TableColumn::qualified] to get a `table.column` expression instead.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TableColumn {
+    table: Table,
+    name: String,
+}
+
+impl TableColumn {
+    /// Get the table this column belongs to
+    pub fn table(&self) -> &Table {
+        &self.table
+    }
+
+    /// Get this column as an expression qualified with its table name
+    ///
+    /// ```
+    /// use scooby::postgres::select;
+    /// use scooby::postgres::schema::Table;
+    ///
+    /// let person = Table::new("Person").in_schema("app");
+    /// let name = person.column("name");
+    ///
+    /// let sql = select(name.qualified()).from(&person).to_string();
+    ///
+    /// assert_eq!(sql, "SELECT app.Person.name FROM app.Person");
+    /// ```
+    pub fn qualified(&self) -> Expression {
+        Expression::from(format!("{}.{}", self.table, self))
+    }
+}
+
+impl Display for TableColumn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_identifier(f, &self.name, self.table.quoted)
+    }
+}
+
+/// List of columns, declared once and used by reference in `SELECT`, `INSERT INTO` and `RETURNING` alike
+///
+/// ```
+/// use scooby::postgres::{insert_into, select};
+/// use scooby::postgres::schema::ColumnList;
+///
+/// let columns = ColumnList::new(("id", "name", "email"));
+///
+/// let sql = select(&columns).from("Person").to_string();
+/// assert_eq!(sql, "SELECT id, name, email FROM Person");
+///
+/// let sql = insert_into("Person")
+///     .columns(&columns)
+///     .values([("$1", "$2", "$3")])
+///     .returning(&columns)
+///     .to_string();
+/// assert_eq!(sql, "INSERT INTO Person (id, name, email) VALUES ($1, $2, $3) RETURNING id, name, email");
+/// ```
+///
+/// As with `columns`, the number of values to insert is checked against the number of columns at compile time.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ColumnList<const N: usize> {
+    columns: [Column; N],
+}
+
+impl<const N: usize> ColumnList<N> {
+    /// Declare a list of one or more columns
+    pub fn new(columns: impl IntoNonZeroArray<Column, N>) -> ColumnList<N> {
+        ColumnList {
+            columns: columns.into_non_zero_array(),
+        }
+    }
+
+    /// Get the columns of this list
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+}
+
+fn write_identifier(f: &mut Formatter<'_>, identifier: &str, is_quoted: bool) -> fmt::Result {
+    if is_quoted {
+        quoted(identifier).fmt(f)
+    } else {
+        f.write_str(identifier)
+    }
+}
+
+/* Conversions */
+
+impl From<&Table> for String {
+    fn from(table: &Table) -> Self {
+        table.to_string()
+    }
+}
+
+impl From<&Table> for FromItem {
+    fn from(table: &Table) -> Self {
+        FromItem::from(table.to_string())
+    }
+}
+
+impl<'a, T> IntoIteratorOfSameType<T> for &'a Table
+where
+    T: From<&'a Table>,
+{
+    type Iterator = Once<T>;
+
+    fn into_some_iter(self) -> Self::Iterator {
+        once(self.into())
+    }
+}
+
+impl Aliasable for &Table {
+    fn as_(self, alias: &str) -> Alias {
+        self.to_string().as_(alias)
+    }
+}
+
+impl From<&TableColumn> for String {
+    fn from(column: &TableColumn) -> Self {
+        column.to_string()
+    }
+}
+
+impl From<&TableColumn> for Column {
+    fn from(column: &TableColumn) -> Self {
+        Column::from(column.to_string())
+    }
+}
+
+impl From<&TableColumn> for Expression {
+    fn from(column: &TableColumn) -> Self {
+        Expression::from(column.to_string())
+    }
+}
+
+impl<'a, T> IntoIteratorOfSameType<T> for &'a TableColumn
+where
+    T: From<&'a TableColumn>,
+{
+    type Iterator = Once<T>;
+
+    fn into_some_iter(self) -> Self::Iterator {
+        once(self.into())
+    }
+}
+
+impl IntoNonZeroArray<Column, 1> for &TableColumn {
+    fn into_non_zero_array(self) -> [Column; 1] {
+        [self.into()]
+    }
+}
+
+impl Aliasable for &TableColumn {
+    fn as_(self, alias: &str) -> Alias {
+        self.to_string().as_(alias)
+    }
+}
+
+impl<const N: usize> IntoNonZeroArray<Column, N> for &ColumnList<N> {
+    fn into_non_zero_array(self) -> [Column; N] {
+        self.columns.clone()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIteratorOfSameType<T> for &'a ColumnList<N>
+where
+    T: From<String>,
+{
+    type Iterator = Map<slice::Iter<'a, Column>, fn(&'a Column) -> T>;
+
+    fn into_some_iter(self) -> Self::Iterator {
+        self.columns
+            .iter()
+            .map(|column| T::from(column.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ColumnList, Table};
+
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{create_table, delete_from, insert_into, select, Aliasable, Orderable};
+
+    #[test]
+    fn plain() {
+        let person = Table::new("Person");
+        let id = person.column("id");
+
+        let sql = select(&id).from(&person).order_by((&id).desc()).to_string();
+
+        assert_correct_postgresql(&sql, "SELECT id FROM Person ORDER BY id DESC");
+    }
+
+    #[test]
+    fn quoted_with_schema() {
+        let person = Table::new("Per\"son").in_schema("app").quoted();
+
+        let sql = delete_from(&person).to_string();
+
+        assert_correct_postgresql(&sql, r#"DELETE FROM "app"."Per""son""#);
+    }
+
+    #[test]
+    fn qualified_and_aliased() {
+        let person = Table::new("Person").quoted();
+        let name = person.column("name");
+
+        let sql = select((name.qualified(), (&name).as_("n")))
+            .from(&person)
+            .to_string();
+
+        assert_correct_postgresql(&sql, r#"SELECT "Person"."name", "name" AS n FROM "Person""#);
+    }
+
+    #[test]
+    fn create_table_columns() {
+        let person = Table::new("Person");
+        let id = person.column("id");
+
+        let sql = create_table(&person)
+            .columns([(&id, "integer")])
+            .primary_key(&id)
+            .to_string();
+
+        assert_correct_postgresql(&sql, "CREATE TABLE Person (id integer, PRIMARY KEY (id))");
+    }
+
+    #[test]
+    fn column_list_of_table_columns() {
+        let person = Table::new("Person").quoted();
+        let columns = ColumnList::new((&person.column("id"), &person.column("name")));
+
+        let sql = insert_into(&person)
+            .columns(&columns)
+            .values([("$1", "$2")])
+            .on_conflict_columns(&columns)
+            .do_nothing()
+            .returning(&columns)
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            r#"INSERT INTO "Person" ("id", "name") VALUES ($1, $2) ON CONFLICT ("id", "name") DO NOTHING RETURNING "id", "name""#,
+        );
+    }
+}
@@ -2,22 +2,48 @@
 //!
 //! Most likely you want documentation for the [main module][`crate::postgres`].
 
+mod alter_default_privileges;
+mod build_error;
+mod capabilities;
 mod create_table;
 mod delete_from;
+mod do_block;
 mod insert_into;
 mod select;
+mod set_role;
+mod statement;
+mod table;
 mod update;
 
+pub use alter_default_privileges::{
+    alter_default_privileges, AlterDefaultPrivileges, AlterDefaultPrivilegesBuilder,
+    DefaultPrivilegesObjects,
+};
+pub use build_error::BuildError;
+pub use capabilities::{HasReturning, HasWhere, HasWith};
 pub use create_table::{
-    create_table, ColumnDefinition, ColumnDefinitionBuilder, ColumnDefinitionable, CreateTable,
-    CreateTableBuilder,
+    create_table, like, ColumnDefinition, ColumnDefinitionBuilder, ColumnDefinitionable,
+    CreateTable, CreateTableBuilder, DeferrableConstraint, ExcludeElement, ForeignKeyBuilder,
+    ForeignKeyConstraint, ForeignKeyConstraintBuilder, Like, LikeOption, OnCommit,
+    PartitionFromBuilder, PartitionOfBuilder, ReferentialAction, TableConstraint, TableElement,
 };
 pub use delete_from::{delete_from, DeleteFrom};
+pub use do_block::{do_block, DoBlock};
 pub use insert_into::{
-    insert_into, BareInsertInto, InsertInto, InsertIntoColumnsBuilder, OnConflictClauseBuilder,
-    Values,
+    insert_into, BareInsertInto, DynamicValuesError, InsertBatches, InsertInto,
+    InsertIntoColumnsBuilder, InsertIntoDynamicColumnsBuilder, OnConflictClauseBuilder, Values,
+    WithConflictTarget, WithoutConflictTarget,
+};
+pub use select::{
+    from, select, select_all_of, Direction, FromItem, FromSelectBuilder, Joinable, Limit, Offset,
+    OrderBy, Orderable, SeekKey, Select, SortSpec, UnknownSortField,
+};
+pub use set_role::{
+    reset_role, reset_session_authorization, set_role, set_session_authorization, SetRole,
+    SetSessionAuthorization,
 };
-pub use select::{from, select, FromItem, FromSelectBuilder, Joinable, OrderBy, Orderable, Select};
+pub use statement::{Statement, StatementKind};
+pub use table::{table, TableCommand};
 pub use update::{update, BareUpdate, Update};
 
 pub(crate) use delete_from::delete_from_with;
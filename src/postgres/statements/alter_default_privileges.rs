@@ -0,0 +1,283 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::tools::{joined, IntoIteratorOfSameType, IntoNonZeroArray};
+
+/// Start building a new `ALTER DEFAULT PRIVILEGES` statement, changing privileges of objects created in the future
+///
+/// Optionally narrow it down with [`for_role`][AlterDefaultPrivilegesBuilder::for_role] and
+/// [`in_schema`][AlterDefaultPrivilegesBuilder::in_schema], then finish it with
+/// [`grant`][AlterDefaultPrivilegesBuilder::grant] or [`revoke`][AlterDefaultPrivilegesBuilder::revoke].
+///
+/// ```
+/// use scooby::postgres::alter_default_privileges;
+/// use scooby::postgres::statements::DefaultPrivilegesObjects;
+///
+/// let sql = alter_default_privileges()
+///     .for_role("migrator")
+///     .in_schema("app")
+///     .grant(("SELECT", "INSERT"), DefaultPrivilegesObjects::Tables, "reader")
+///     .to_string();
+///
+/// assert_eq!(
+///     sql,
+///     "ALTER DEFAULT PRIVILEGES FOR ROLE migrator IN SCHEMA app GRANT SELECT, INSERT ON TABLES TO reader",
+/// );
+/// ```
+pub fn alter_default_privileges() -> AlterDefaultPrivilegesBuilder {
+    AlterDefaultPrivilegesBuilder {
+        for_roles: Vec::new(),
+        in_schemas: Vec::new(),
+    }
+}
+
+/// Kind of objects that default privileges apply to, i.e. `ON TABLES`
+///
+/// Pass it to [`AlterDefaultPrivilegesBuilder::grant`] or [`AlterDefaultPrivilegesBuilder::revoke`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum DefaultPrivilegesObjects {
+    /// `ON TABLES`, including views and foreign tables
+    Tables,
+    /// `ON SEQUENCES`
+    Sequences,
+    /// `ON FUNCTIONS`, including procedures
+    Functions,
+    /// `ON ROUTINES`, same as `ON FUNCTIONS`
+    Routines,
+    /// `ON TYPES`, including domains
+    Types,
+    /// `ON SCHEMAS`, which can't be combined with `IN SCHEMA`
+    Schemas,
+}
+
+impl Display for DefaultPrivilegesObjects {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DefaultPrivilegesObjects::Tables => write!(f, "TABLES"),
+            DefaultPrivilegesObjects::Sequences => write!(f, "SEQUENCES"),
+            DefaultPrivilegesObjects::Functions => write!(f, "FUNCTIONS"),
+            DefaultPrivilegesObjects::Routines => write!(f, "ROUTINES"),
+            DefaultPrivilegesObjects::Types => write!(f, "TYPES"),
+            DefaultPrivilegesObjects::Schemas => write!(f, "SCHEMAS"),
+        }
+    }
+}
+
+/// Unfinished `ALTER DEFAULT PRIVILEGES` statement, created with [`alter_default_privileges`]
+///
+/// You will want to use [`grant`][AlterDefaultPrivilegesBuilder::grant] or
+/// [`revoke`][AlterDefaultPrivilegesBuilder::revoke] to turn this into a usable statement.
+#[must_use = "Making an ALTER DEFAULT PRIVILEGES statement without granting or revoking anything is pointless"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterDefaultPrivilegesBuilder {
+    for_roles: Vec<String>,
+    in_schemas: Vec<String>,
+}
+
+impl AlterDefaultPrivilegesBuilder {
+    /// Only change default privileges of objects created by these roles, instead of the current one
+    ///
+    /// Can be called multiple times, adding more roles.
+    pub fn for_role(mut self, roles: impl IntoIteratorOfSameType<String>) -> Self {
+        self.for_roles.extend(roles.into_some_iter());
+        self
+    }
+
+    /// Only change default privileges of objects created in these schemas, instead of anywhere
+    ///
+    /// Can be called multiple times, adding more schemas.
+    pub fn in_schema(mut self, schemas: impl IntoIteratorOfSameType<String>) -> Self {
+        self.in_schemas.extend(schemas.into_some_iter());
+        self
+    }
+
+    /// Finish the statement with a `GRANT privileges ON objects TO grantees` action
+    ///
+    /// Privileges and grantees are rendered as is, so `ALL` and `PUBLIC` work too. There must be at least one of each.
+    ///
+    /// ```
+    /// use scooby::postgres::alter_default_privileges;
+    /// use scooby::postgres::statements::DefaultPrivilegesObjects;
+    ///
+    /// let sql = alter_default_privileges()
+    ///     .grant("USAGE", DefaultPrivilegesObjects::Sequences, ["reader", "writer"])
+    ///     .with_grant_option()
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     sql,
+    ///     "ALTER DEFAULT PRIVILEGES GRANT USAGE ON SEQUENCES TO reader, writer WITH GRANT OPTION",
+    /// );
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use scooby::postgres::alter_default_privileges;
+    /// use scooby::postgres::statements::DefaultPrivilegesObjects;
+    ///
+    /// let nobody: [&str; 0] = [];
+    /// alter_default_privileges().grant("SELECT", DefaultPrivilegesObjects::Tables, nobody);
+    /// ```
+    pub fn grant<const P: usize, const G: usize>(
+        self,
+        privileges: impl IntoNonZeroArray<String, P>,
+        objects: DefaultPrivilegesObjects,
+        grantees: impl IntoNonZeroArray<String, G>,
+    ) -> AlterDefaultPrivileges {
+        self.finish(true, privileges, objects, grantees)
+    }
+
+    /// Finish the statement with a `REVOKE privileges ON objects FROM grantees` action
+    ///
+    /// There must be at least one privilege and one grantee.
+    ///
+    /// ```
+    /// use scooby::postgres::alter_default_privileges;
+    /// use scooby::postgres::statements::DefaultPrivilegesObjects;
+    ///
+    /// let sql = alter_default_privileges()
+    ///     .revoke("EXECUTE", DefaultPrivilegesObjects::Functions, "PUBLIC")
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "ALTER DEFAULT PRIVILEGES REVOKE EXECUTE ON FUNCTIONS FROM PUBLIC");
+    /// ```
+    pub fn revoke<const P: usize, const G: usize>(
+        self,
+        privileges: impl IntoNonZeroArray<String, P>,
+        objects: DefaultPrivilegesObjects,
+        grantees: impl IntoNonZeroArray<String, G>,
+    ) -> AlterDefaultPrivileges {
+        self.finish(false, privileges, objects, grantees)
+    }
+
+    fn finish<const P: usize, const G: usize>(
+        self,
+        is_grant: bool,
+        privileges: impl IntoNonZeroArray<String, P>,
+        objects: DefaultPrivilegesObjects,
+        grantees: impl IntoNonZeroArray<String, G>,
+    ) -> AlterDefaultPrivileges {
+        AlterDefaultPrivileges {
+            for_roles: self.for_roles,
+            in_schemas: self.in_schemas,
+            is_grant,
+            privileges: privileges.into_non_zero_array().into(),
+            objects,
+            grantees: grantees.into_non_zero_array().into(),
+            grant_option: false,
+        }
+    }
+}
+
+/// `ALTER DEFAULT PRIVILEGES` statement, created with [`AlterDefaultPrivilegesBuilder::grant`]
+/// or [`AlterDefaultPrivilegesBuilder::revoke`]
+#[must_use = "Making an ALTER DEFAULT PRIVILEGES statement without using it is pointless"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterDefaultPrivileges {
+    for_roles: Vec<String>,
+    in_schemas: Vec<String>,
+    is_grant: bool,
+    privileges: Vec<String>,
+    objects: DefaultPrivilegesObjects,
+    grantees: Vec<String>,
+    grant_option: bool,
+}
+
+impl AlterDefaultPrivileges {
+    /// Allow grantees to grant the privileges to others, i.e. `WITH GRANT OPTION`
+    ///
+    /// When revoking, only the grant option is revoked instead of the privileges themselves,
+    /// i.e. `REVOKE GRANT OPTION FOR`.
+    ///
+    /// ```
+    /// use scooby::postgres::alter_default_privileges;
+    /// use scooby::postgres::statements::DefaultPrivilegesObjects;
+    ///
+    /// let sql = alter_default_privileges()
+    ///     .revoke("SELECT", DefaultPrivilegesObjects::Tables, "reader")
+    ///     .with_grant_option()
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "ALTER DEFAULT PRIVILEGES REVOKE GRANT OPTION FOR SELECT ON TABLES FROM reader");
+    /// ```
+    pub fn with_grant_option(mut self) -> Self {
+        self.grant_option = true;
+        self
+    }
+}
+
+impl Display for AlterDefaultPrivileges {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "ALTER DEFAULT PRIVILEGES")?;
+
+        if !self.for_roles.is_empty() {
+            write!(f, " FOR ROLE {}", joined(&self.for_roles, ", "))?;
+        }
+
+        if !self.in_schemas.is_empty() {
+            write!(f, " IN SCHEMA {}", joined(&self.in_schemas, ", "))?;
+        }
+
+        if self.is_grant {
+            write!(f, " GRANT ")?;
+        } else if self.grant_option {
+            write!(f, " REVOKE GRANT OPTION FOR ")?;
+        } else {
+            write!(f, " REVOKE ")?;
+        }
+
+        write!(
+            f,
+            "{} ON {} {} {}",
+            joined(&self.privileges, ", "),
+            self.objects,
+            if self.is_grant { "TO" } else { "FROM" },
+            joined(&self.grantees, ", "),
+        )?;
+
+        if self.is_grant && self.grant_option {
+            write!(f, " WITH GRANT OPTION")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{alter_default_privileges, DefaultPrivilegesObjects};
+
+    use crate::postgres::statements::StatementKind;
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::Statement;
+
+    #[test]
+    fn grant() {
+        let statement = alter_default_privileges()
+            .for_role(["dummy", "other"])
+            .in_schema("public")
+            .grant("ALL", DefaultPrivilegesObjects::Types, "PUBLIC")
+            .with_grant_option();
+
+        assert_eq!(statement.kind(), StatementKind::AlterDefaultPrivileges);
+        assert_correct_postgresql(
+            &statement.to_string(),
+            "ALTER DEFAULT PRIVILEGES FOR ROLE dummy, other IN SCHEMA public GRANT ALL ON TYPES TO PUBLIC WITH GRANT OPTION",
+        );
+    }
+
+    #[test]
+    fn revoke() {
+        let sql = alter_default_privileges()
+            .for_role("dummy")
+            .revoke(
+                ("USAGE", "CREATE"),
+                DefaultPrivilegesObjects::Schemas,
+                "PUBLIC",
+            )
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "ALTER DEFAULT PRIVILEGES FOR ROLE dummy REVOKE USAGE, CREATE ON SCHEMAS FROM PUBLIC",
+        );
+    }
+}
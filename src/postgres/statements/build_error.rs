@@ -0,0 +1,67 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// Problem with a statement that would make PostgreSQL reject it
+///
+/// Returned by opt-in checks such as [`Statement::try_build`][super::Statement::try_build], [`Select::verify`][super::Select::verify]
+/// and [`Render::to_strict_string`][crate::postgres::Render::to_strict_string],
+/// as well as by filling a [`Template`][crate::postgres::tools::Template].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BuildError {
+    /// An `ORDER BY` expression comes before all `DISTINCT ON` expressions are
+    /// sorted by, but is not one of them
+    DistinctOnMismatch { order_by: String },
+    /// Rendered SQL contains something that is unlikely to be intended, such as a `;` or an unterminated string,
+    /// at the given byte position
+    SuspiciousSql {
+        position: usize,
+        problem: &'static str,
+    },
+    /// `INSERT INTO` statement has no rows of values to insert
+    NoRows,
+    /// `ON CONFLICT DO UPDATE` has no conflict target, which PostgreSQL requires for it
+    MissingConflictTarget,
+    /// A column of an `ON CONFLICT` target is not one of the inserted columns
+    UnknownConflictColumn { column: String },
+    /// `UPDATE` or `DELETE FROM` statement has no `WHERE` conditions, and is not marked as meant to affect the whole table
+    MissingWhere,
+    /// Rendered SQL has an `IN ()` list without any items at the given byte position, which PostgreSQL rejects
+    EmptyInList { position: usize },
+    /// A [`Template`][crate::postgres::tools::Template] slot with this name was not given any SQL to fill it
+    UnfilledSlot { name: String },
+}
+
+impl Display for BuildError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::DistinctOnMismatch { order_by } => write!(
+                f,
+                "ORDER BY expression {} does not match any DISTINCT ON expression",
+                order_by
+            ),
+            BuildError::SuspiciousSql { position, problem } => {
+                write!(f, "suspicious SQL at position {}: {}", position, problem)
+            }
+            BuildError::NoRows => write!(f, "no rows of values to insert"),
+            BuildError::MissingConflictTarget => {
+                write!(f, "ON CONFLICT DO UPDATE requires a conflict target")
+            }
+            BuildError::UnknownConflictColumn { column } => write!(
+                f,
+                "ON CONFLICT column {} is not one of the inserted columns",
+                column
+            ),
+            BuildError::MissingWhere => write!(
+                f,
+                "no WHERE conditions, call allow_full_table if this is intended"
+            ),
+            BuildError::EmptyInList { position } => {
+                write!(f, "empty IN list at position {}", position)
+            }
+            BuildError::UnfilledSlot { name } => write!(f, "template slot {} is not filled", name),
+        }
+    }
+}
+
+impl Error for BuildError {}
@@ -0,0 +1,260 @@
+use crate::postgres::general::{
+    Condition, Expression, OutputExpression, TableName, UsableInWithQuery, WithClause,
+};
+use crate::postgres::statements::{DeleteFrom, InsertInto, Select, Update, Values};
+use crate::postgres::tools::Parameters;
+use crate::tools::IntoIteratorOfSameType;
+
+/// Statements with a `WHERE` clause: `SELECT`, `UPDATE` and `DELETE FROM`
+///
+/// Useful for generic helpers that add the same conditions to statements of different kinds.
+///
+/// ```
+/// use scooby::postgres::{delete_from, select, update, HasWhere};
+///
+/// fn for_tenant<T: HasWhere>(statement: T) -> T {
+///     statement.where_("tenant_id = $1")
+/// }
+///
+/// assert_eq!(
+///     for_tenant(select("*").from("Person")).to_string(),
+///     "SELECT * FROM Person WHERE tenant_id = $1",
+/// );
+/// assert_eq!(
+///     for_tenant(update("Person").set("name", "$2")).to_string(),
+///     "UPDATE Person SET name = $2 WHERE tenant_id = $1",
+/// );
+/// assert_eq!(
+///     for_tenant(delete_from("Person")).to_string(),
+///     "DELETE FROM Person WHERE tenant_id = $1",
+/// );
+/// ```
+pub trait HasWhere: Sized {
+    /// Add one or more `WHERE` conditions, `AND`'ed together with existing ones
+    fn where_(self, conditions: impl IntoIteratorOfSameType<Condition>) -> Self;
+
+    /// Add a `column = $n` condition with the next placeholder from `params`, `AND`'ed with existing conditions
+    fn where_eq(self, column: impl Into<Expression>, params: &mut Parameters) -> Self;
+
+    /// Get conditions of the `WHERE` clause added so far
+    fn where_conditions(&self) -> &[Condition];
+}
+
+/// Statements with a `RETURNING` clause: `INSERT INTO`, `UPDATE` and `DELETE FROM`
+///
+/// ```
+/// use scooby::postgres::{delete_from, insert_into, HasReturning};
+///
+/// fn with_id<T: HasReturning>(statement: T) -> T {
+///     statement.returning("id")
+/// }
+///
+/// assert_eq!(
+///     with_id(insert_into("Person").default_values()).to_string(),
+///     "INSERT INTO Person DEFAULT VALUES RETURNING id",
+/// );
+/// assert_eq!(with_id(delete_from("Person")).to_string(), "DELETE FROM Person RETURNING id");
+/// ```
+pub trait HasReturning: Sized {
+    /// Add one or more `RETURNING` expressions
+    fn returning(self, expressions: impl IntoIteratorOfSameType<OutputExpression>) -> Self;
+
+    /// Get expressions of the `RETURNING` clause added so far
+    fn returning_expressions(&self) -> &[OutputExpression];
+}
+
+/// Statements with a `WITH` clause: `SELECT`, `INSERT INTO`, `UPDATE` and `DELETE FROM`
+///
+/// In addition to starting a statement with [`with`][crate::postgres::with], this allows adding
+/// tables to the `WITH` clause of a statement that is already built.
+///
+/// ```
+/// use scooby::postgres::{select, update, with, HasWith};
+///
+/// fn with_tenants<T: HasWith>(statement: T) -> T {
+///     statement.with_query("tenants", select("id").from("Tenant").where_("active"))
+/// }
+///
+/// assert_eq!(
+///     with_tenants(select("*").from("tenants")).to_string(),
+///     "WITH tenants AS (SELECT id FROM Tenant WHERE active) SELECT * FROM tenants",
+/// );
+/// assert_eq!(
+///     with_tenants(with("x").as_(select("1")).update("Person").set("a", "1")).to_string(),
+///     "WITH x AS (SELECT 1), tenants AS (SELECT id FROM Tenant WHERE active) UPDATE Person SET a = 1",
+/// );
+/// ```
+pub trait HasWith: Sized {
+    /// Add a table defined by the given statement to the `WITH` clause, creating the clause if needed
+    fn with_query(self, name: impl Into<TableName>, query: impl UsableInWithQuery) -> Self;
+
+    /// Get the `WITH` clause, if there is one
+    fn with_clause(&self) -> Option<&WithClause>;
+}
+
+/* Implementations */
+
+impl HasWhere for Select {
+    fn where_(self, conditions: impl IntoIteratorOfSameType<Condition>) -> Self {
+        Select::where_(self, conditions)
+    }
+
+    fn where_eq(self, column: impl Into<Expression>, params: &mut Parameters) -> Self {
+        Select::where_eq(self, column, params)
+    }
+
+    fn where_conditions(&self) -> &[Condition] {
+        Select::where_conditions(self)
+    }
+}
+
+impl HasWhere for Update {
+    fn where_(self, conditions: impl IntoIteratorOfSameType<Condition>) -> Self {
+        Update::where_(self, conditions)
+    }
+
+    fn where_eq(self, column: impl Into<Expression>, params: &mut Parameters) -> Self {
+        Update::where_eq(self, column, params)
+    }
+
+    fn where_conditions(&self) -> &[Condition] {
+        Update::where_conditions(self)
+    }
+}
+
+impl HasWhere for DeleteFrom {
+    fn where_(self, conditions: impl IntoIteratorOfSameType<Condition>) -> Self {
+        DeleteFrom::where_(self, conditions)
+    }
+
+    fn where_eq(self, column: impl Into<Expression>, params: &mut Parameters) -> Self {
+        DeleteFrom::where_eq(self, column, params)
+    }
+
+    fn where_conditions(&self) -> &[Condition] {
+        DeleteFrom::where_conditions(self)
+    }
+}
+
+impl<V: Values> HasReturning for InsertInto<V> {
+    fn returning(self, expressions: impl IntoIteratorOfSameType<OutputExpression>) -> Self {
+        InsertInto::<V>::returning(self, expressions)
+    }
+
+    fn returning_expressions(&self) -> &[OutputExpression] {
+        InsertInto::<V>::returning_expressions(self)
+    }
+}
+
+impl HasReturning for Update {
+    fn returning(self, expressions: impl IntoIteratorOfSameType<OutputExpression>) -> Self {
+        Update::returning(self, expressions)
+    }
+
+    fn returning_expressions(&self) -> &[OutputExpression] {
+        Update::returning_expressions(self)
+    }
+}
+
+impl HasReturning for DeleteFrom {
+    fn returning(self, expressions: impl IntoIteratorOfSameType<OutputExpression>) -> Self {
+        DeleteFrom::returning(self, expressions)
+    }
+
+    fn returning_expressions(&self) -> &[OutputExpression] {
+        DeleteFrom::returning_expressions(self)
+    }
+}
+
+impl HasWith for Select {
+    fn with_query(mut self, name: impl Into<TableName>, query: impl UsableInWithQuery) -> Self {
+        self.with
+            .get_or_insert_with(WithClause::default)
+            .push(name.into(), Vec::new(), query);
+        self
+    }
+
+    fn with_clause(&self) -> Option<&WithClause> {
+        self.with.as_ref()
+    }
+}
+
+impl<V: Values> HasWith for InsertInto<V> {
+    fn with_query(mut self, name: impl Into<TableName>, query: impl UsableInWithQuery) -> Self {
+        self.with
+            .get_or_insert_with(WithClause::default)
+            .push(name.into(), Vec::new(), query);
+        self
+    }
+
+    fn with_clause(&self) -> Option<&WithClause> {
+        self.with.as_ref()
+    }
+}
+
+impl HasWith for Update {
+    fn with_query(mut self, name: impl Into<TableName>, query: impl UsableInWithQuery) -> Self {
+        self.with
+            .get_or_insert_with(WithClause::default)
+            .push(name.into(), Vec::new(), query);
+        self
+    }
+
+    fn with_clause(&self) -> Option<&WithClause> {
+        self.with.as_ref()
+    }
+}
+
+impl HasWith for DeleteFrom {
+    fn with_query(mut self, name: impl Into<TableName>, query: impl UsableInWithQuery) -> Self {
+        self.with
+            .get_or_insert_with(WithClause::default)
+            .push(name.into(), Vec::new(), query);
+        self
+    }
+
+    fn with_clause(&self) -> Option<&WithClause> {
+        self.with.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HasReturning, HasWhere, HasWith};
+
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{delete_from, insert_into, select, Parameters};
+
+    fn scoped<T: HasWhere + HasReturning + HasWith>(statement: T, params: &mut Parameters) -> T {
+        statement
+            .with_query("scope", select("id").from("Dummy"))
+            .where_eq("tenant_id", params)
+            .returning("id")
+    }
+
+    #[test]
+    fn generic_delete() {
+        let mut params = Parameters::new();
+        let statement = scoped(delete_from("Dummy").where_("a > 1"), &mut params);
+
+        assert_eq!(statement.where_conditions(), ["a > 1", "tenant_id = $1"]);
+        assert_eq!(statement.returning_expressions(), ["id"]);
+        assert!(statement.with_clause().is_some());
+        assert_correct_postgresql(
+            &statement.to_string(),
+            "WITH scope AS (SELECT id FROM Dummy) DELETE FROM Dummy WHERE a > 1 AND tenant_id = $1 RETURNING id",
+        );
+    }
+
+    #[test]
+    fn insert_with_query() {
+        let statement = insert_into("Dummy")
+            .values([("(SELECT id FROM scope)",)])
+            .with_query("scope", select("1 AS id"));
+
+        assert_correct_postgresql(
+            &statement.to_string(),
+            "WITH scope AS (SELECT 1 AS id) INSERT INTO Dummy VALUES ((SELECT id FROM scope))",
+        );
+    }
+}
@@ -1,27 +1,49 @@
 mod column_constraints;
 mod column_definition;
+mod deferrability;
+mod on_commit;
+mod partitioning;
+mod referential_action;
 mod table_constraints;
+mod table_elements;
 
 use std::fmt::{self, Display, Formatter};
 
-use crate::postgres::general::Column;
+use crate::postgres::general::TableName;
+use crate::postgres::general::{
+    Column, Comment, Comments, Condition, Expression, StorageParameter,
+};
 use crate::tools::IntoIteratorOfSameType;
-use crate::{postgres::general::TableName, tools::joined};
+use crate::tools::{clause_break, joined, listed};
 
 pub use column_definition::{ColumnDefinition, ColumnDefinitionBuilder, ColumnDefinitionable};
+pub use on_commit::OnCommit;
+pub use partitioning::{PartitionFromBuilder, PartitionOfBuilder};
+pub use referential_action::ReferentialAction;
+pub use table_constraints::{
+    DeferrableConstraint, ExcludeElement, ForeignKeyBuilder, ForeignKeyConstraint,
+    ForeignKeyConstraintBuilder, TableConstraint,
+};
+pub use table_elements::{like, Like, LikeOption, TableElement};
 
-use self::table_constraints::TableConstraint;
+use self::partitioning::{PartitionBy, PartitionOf, PartitionStrategy};
+use self::table_elements::ColumnListItem;
 
 pub fn create_table(table_name: impl Into<TableName>) -> CreateTableBuilder {
     CreateTableBuilder {
         table_name: table_name.into(),
+        persistence: None,
         if_not_exists: false,
+        on_commit: None,
     }
 }
 
+#[derive(Debug)]
 pub struct CreateTableBuilder {
     table_name: TableName,
+    persistence: Option<Persistence>,
     if_not_exists: bool,
+    on_commit: Option<OnCommit>,
 }
 
 impl CreateTableBuilder {
@@ -30,54 +52,464 @@ impl CreateTableBuilder {
         self
     }
 
-    pub fn columns(self, columns: impl IntoIteratorOfSameType<ColumnDefinition>) -> CreateTable {
+    /// Make this a `TEMPORARY` table
+    ///
+    /// ```
+    /// use scooby::postgres::create_table;
+    ///
+    /// let sql = create_table("Staging").temporary().columns([("id", "integer")]).to_string();
+    ///
+    /// assert_eq!(sql, "CREATE TEMPORARY TABLE Staging (id integer)");
+    /// ```
+    pub fn temporary(mut self) -> CreateTableBuilder {
+        self.persistence = Some(Persistence::Temporary);
+        self
+    }
+
+    /// Make this an `UNLOGGED` table
+    ///
+    /// ```
+    /// use scooby::postgres::create_table;
+    ///
+    /// let sql = create_table("Staging").unlogged().columns([("id", "integer")]).to_string();
+    ///
+    /// assert_eq!(sql, "CREATE UNLOGGED TABLE Staging (id integer)");
+    /// ```
+    pub fn unlogged(mut self) -> CreateTableBuilder {
+        self.persistence = Some(Persistence::Unlogged);
+        self
+    }
+
+    /// Set an `ON COMMIT` option, controlling what happens to a temporary table at the end of a transaction
+    ///
+    /// PostgreSQL only accepts this option for temporary tables.
+    ///
+    /// ```
+    /// use scooby::postgres::{create_table, OnCommit};
+    ///
+    /// let sql = create_table("Staging")
+    ///     .temporary()
+    ///     .on_commit(OnCommit::Drop)
+    ///     .columns([("id", "integer")])
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "CREATE TEMPORARY TABLE Staging (id integer) ON COMMIT DROP");
+    /// ```
+    pub fn on_commit(mut self, on_commit: OnCommit) -> CreateTableBuilder {
+        self.on_commit = Some(on_commit);
+        self
+    }
+
+    pub fn columns(self, columns: impl IntoIteratorOfSameType<TableElement>) -> CreateTable {
+        self.build(columns.into_some_iter().collect(), None)
+    }
+
+    /// Start building the column list by adding a single column, or another column list element.
+    ///
+    /// More columns may be added with [`add_column`][CreateTable::add_column] on the returned [`CreateTable`] structure,
+    /// which is useful when the set of columns is only known at runtime.
+    ///
+    /// ```
+    /// use scooby::postgres::create_table;
+    ///
+    /// let mut statement = create_table("Dummy").add_column(("id", "integer"));
+    ///
+    /// for name in ["a", "b"] {
+    ///     statement = statement.add_column((name, "text"));
+    /// }
+    ///
+    /// assert_eq!(statement.to_string(), "CREATE TABLE Dummy (id integer, a text, b text)");
+    /// ```
+    pub fn add_column(self, column: impl Into<TableElement>) -> CreateTable {
+        self.build(vec![column.into()], None)
+    }
+
+    /// Create this table as a partition of another table
+    ///
+    /// Returns a [`PartitionOfBuilder`] structure which requires you to specify the partition bound.
+    ///
+    /// ```
+    /// use scooby::postgres::create_table;
+    ///
+    /// let sql = create_table("Measurement2021")
+    ///     .partition_of("Measurement")
+    ///     .for_values_from("'2021-01-01'")
+    ///     .to("'2022-01-01'")
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "CREATE TABLE Measurement2021 PARTITION OF Measurement FOR VALUES FROM ('2021-01-01') TO ('2022-01-01')");
+    /// ```
+    pub fn partition_of(self, parent: impl Into<TableName>) -> PartitionOfBuilder {
+        PartitionOfBuilder::new(self, parent.into())
+    }
+
+    pub(crate) fn build(
+        self,
+        columns: Vec<TableElement>,
+        partition_of: Option<PartitionOf>,
+    ) -> CreateTable {
         CreateTable {
             name: self.table_name,
+            persistence: self.persistence,
             if_not_exists: self.if_not_exists,
-            columns: columns.into_some_iter().collect(),
+            partition_of,
+            columns,
             constraints: Vec::new(),
+            partition_by: None,
+            storage_parameters: Vec::new(),
+            on_commit: self.on_commit,
+            access_method: None,
+            tablespace: None,
+            comments: Comments::default(),
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CreateTable {
     name: TableName,
+    persistence: Option<Persistence>,
     if_not_exists: bool,
-    columns: Vec<ColumnDefinition>,
+    partition_of: Option<PartitionOf>,
+    columns: Vec<TableElement>,
     constraints: Vec<TableConstraint>,
+    partition_by: Option<PartitionBy>,
+    access_method: Option<String>,
+    storage_parameters: Vec<StorageParameter>,
+    on_commit: Option<OnCommit>,
+    tablespace: Option<String>,
+    comments: Comments,
 }
 
 impl Display for CreateTable {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "CREATE TABLE")?;
+        write!(f, "CREATE")?;
+
+        if let Some(persistence) = &self.persistence {
+            write!(f, " {}", persistence)?;
+        }
+
+        write!(f, " TABLE")?;
 
         if self.if_not_exists {
             write!(f, " IF NOT EXISTS")?;
         }
 
-        write!(f, " {} ({}", self.name, joined(&self.columns, ", "))?;
+        write!(f, " {}", self.name)?;
 
-        if self.constraints.len() > 0 {
-            write!(f, ", {}", joined(&self.constraints, ", "))?;
+        if let Some(partition_of) = &self.partition_of {
+            write!(f, " {}", partition_of)?;
         }
 
-        write!(f, ")")
+        let in_partition = self.partition_of.is_some();
+
+        if !in_partition || !self.columns.is_empty() || !self.constraints.is_empty() {
+            let elements = self
+                .columns
+                .iter()
+                .map(move |element| ColumnListItem::Element(element, in_partition))
+                .chain(self.constraints.iter().map(ColumnListItem::Constraint));
+
+            if f.alternate() {
+                write!(f, " (")?;
+                listed(elements, ", ").fmt(f)?;
+                write!(f, "\n)")?;
+            } else {
+                write!(f, " ({})", joined(elements, ", "))?;
+            }
+        }
+
+        if let Some(partition_of) = &self.partition_of {
+            clause_break(f)?;
+            write!(f, "{}", partition_of.bound())?;
+        }
+
+        if let Some(partition_by) = &self.partition_by {
+            clause_break(f)?;
+            write!(f, "{}", partition_by)?;
+        }
+
+        if let Some(access_method) = &self.access_method {
+            clause_break(f)?;
+            write!(f, "USING {}", access_method)?;
+        }
+
+        if !self.storage_parameters.is_empty() {
+            clause_break(f)?;
+            write!(f, "WITH ({})", joined(&self.storage_parameters, ", "))?;
+        }
+
+        if let Some(on_commit) = &self.on_commit {
+            clause_break(f)?;
+            write!(f, "{}", on_commit)?;
+        }
+
+        if let Some(tablespace) = &self.tablespace {
+            clause_break(f)?;
+            write!(f, "TABLESPACE {}", tablespace)?;
+        }
+
+        self.comments.fmt(f)?;
+
+        Ok(())
     }
 }
 
 impl CreateTable {
-    pub fn unique(mut self, columns: impl IntoIteratorOfSameType<Column>) -> Self {
-        self.constraints
-            .push(TableConstraint::Unique(columns.into_some_iter().collect()));
+    /// Add another column, or another column list element, to this statement
+    ///
+    /// Table constraints may be added one by one as well, by calling their respective methods
+    /// such as [`unique`][CreateTable::unique] or [`check`][CreateTable::check] repeatedly,
+    /// or with [`add_constraint`][CreateTable::add_constraint].
+    ///
+    /// Partitions take column types from their parent table, so columns of a [`partition_of`][CreateTableBuilder::partition_of]
+    /// statement are rendered as `name WITH OPTIONS ...`, and their types are ignored.
+    ///
+    /// ```
+    /// use scooby::postgres::{create_table, ColumnDefinitionable};
+    ///
+    /// let sql = create_table("Dummy")
+    ///     .columns([("id", "integer")])
+    ///     .add_column(("name", "text").not_null())
+    ///     .unique("name")
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "CREATE TABLE Dummy (id integer, name text NOT NULL, UNIQUE (name))");
+    /// ```
+    pub fn add_column(mut self, column: impl Into<TableElement>) -> Self {
+        self.columns.push(column.into());
+        self
+    }
+
+    /// Add a table constraint made separately, see [`TableConstraint`] for details
+    ///
+    /// ```
+    /// use scooby::postgres::{create_table, TableConstraint};
+    ///
+    /// let sql = create_table("Dummy")
+    ///     .columns([("a", "integer")])
+    ///     .add_constraint(TableConstraint::unique("a"))
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "CREATE TABLE Dummy (a integer, UNIQUE (a))");
+    /// ```
+    pub fn add_constraint(mut self, constraint: impl Into<TableConstraint>) -> Self {
+        self.constraints.push(constraint.into());
+        self
+    }
+
+    pub fn unique(self, columns: impl IntoIteratorOfSameType<Column>) -> Self {
+        self.add_constraint(TableConstraint::unique(columns))
+    }
+
+    /// Add a `PRIMARY KEY` table constraint, possibly spanning multiple columns
+    ///
+    /// ```
+    /// use scooby::postgres::create_table;
+    ///
+    /// let sql = create_table("Membership")
+    ///     .columns([("person_id", "integer"), ("club_id", "integer")])
+    ///     .primary_key(("person_id", "club_id"))
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "CREATE TABLE Membership (person_id integer, club_id integer, PRIMARY KEY (person_id, club_id))");
+    /// ```
+    pub fn primary_key(self, columns: impl IntoIteratorOfSameType<Column>) -> Self {
+        self.add_constraint(TableConstraint::primary_key(columns))
+    }
+
+    /// Begin adding a `FOREIGN KEY` table constraint on given columns
+    ///
+    /// Returns a [`ForeignKeyBuilder`] structure which requires you to specify referenced table and columns.
+    ///
+    /// To set referential actions, match type or deferrability, make the constraint with
+    /// [`TableConstraint::foreign_key`] and add it with [`add_constraint`][CreateTable::add_constraint] instead.
+    ///
+    /// ```
+    /// use scooby::postgres::create_table;
+    ///
+    /// let sql = create_table("Membership")
+    ///     .columns([("person_id", "integer"), ("person_name", "text")])
+    ///     .foreign_key(("person_id", "person_name"))
+    ///     .references("Person", ("id", "name"))
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "CREATE TABLE Membership (person_id integer, person_name text, FOREIGN KEY (person_id, person_name) REFERENCES Person(id, name))");
+    /// ```
+    pub fn foreign_key(self, columns: impl IntoIteratorOfSameType<Column>) -> ForeignKeyBuilder {
+        ForeignKeyBuilder::new(self, columns.into_some_iter().collect())
+    }
+
+    /// Add a `CHECK` table constraint
+    ///
+    /// ```
+    /// use scooby::postgres::create_table;
+    ///
+    /// let sql = create_table("Rectangle")
+    ///     .columns([("width", "integer"), ("height", "integer")])
+    ///     .check("width > height")
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "CREATE TABLE Rectangle (width integer, height integer, CHECK (width > height))");
+    /// ```
+    pub fn check(self, condition: impl Into<Condition>) -> Self {
+        self.add_constraint(TableConstraint::check(condition))
+    }
+
+    /// Add an `EXCLUDE` table constraint using the given index method
+    ///
+    /// Elements are specified as `(expression, operator)` pairs.
+    ///
+    /// ```
+    /// use scooby::postgres::create_table;
+    ///
+    /// let sql = create_table("Booking")
+    ///     .columns([("room", "integer"), ("during", "tsrange")])
+    ///     .exclude_using("gist", [("room", "="), ("during", "&&")])
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "CREATE TABLE Booking (room integer, during tsrange, EXCLUDE USING gist (room WITH =, during WITH &&))");
+    /// ```
+    pub fn exclude_using(
+        self,
+        index_method: impl Into<String>,
+        elements: impl IntoIteratorOfSameType<ExcludeElement>,
+    ) -> Self {
+        self.add_constraint(TableConstraint::exclude_using(index_method, elements))
+    }
+
+    /// Make this a partitioned table with a `PARTITION BY RANGE (...)` clause
+    ///
+    /// ```
+    /// use scooby::postgres::create_table;
+    ///
+    /// let sql = create_table("Measurement")
+    ///     .columns([("logdate", "date"), ("peaktemp", "int")])
+    ///     .partition_by_range("logdate")
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "CREATE TABLE Measurement (logdate date, peaktemp int) PARTITION BY RANGE (logdate)");
+    /// ```
+    pub fn partition_by_range(self, key: impl IntoIteratorOfSameType<Expression>) -> Self {
+        self.with_partition_by(PartitionStrategy::Range, key)
+    }
+
+    /// Make this a partitioned table with a `PARTITION BY LIST (...)` clause
+    pub fn partition_by_list(self, key: impl IntoIteratorOfSameType<Expression>) -> Self {
+        self.with_partition_by(PartitionStrategy::List, key)
+    }
+
+    /// Make this a partitioned table with a `PARTITION BY HASH (...)` clause
+    pub fn partition_by_hash(self, key: impl IntoIteratorOfSameType<Expression>) -> Self {
+        self.with_partition_by(PartitionStrategy::Hash, key)
+    }
+
+    fn with_partition_by(
+        mut self,
+        strategy: PartitionStrategy,
+        key: impl IntoIteratorOfSameType<Expression>,
+    ) -> Self {
+        self.partition_by = Some(PartitionBy::new(strategy, key.into_some_iter().collect()));
+        self
+    }
+
+    /// Set a table access method with a `USING` clause, such as `heap`, or `columnar` provided by an extension
+    ///
+    /// ```
+    /// use scooby::postgres::create_table;
+    ///
+    /// let sql = create_table("Event")
+    ///     .columns([("id", "bigint"), ("payload", "jsonb")])
+    ///     .using("columnar")
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "CREATE TABLE Event (id bigint, payload jsonb) USING columnar");
+    /// ```
+    pub fn using(mut self, access_method: impl Into<String>) -> Self {
+        self.access_method = Some(access_method.into());
         self
     }
+
+    /// Add one or more storage parameters in a `WITH (...)` clause
+    ///
+    /// ```
+    /// use scooby::postgres::create_table;
+    ///
+    /// let sql = create_table("Film")
+    ///     .columns([("id", "integer")])
+    ///     .with_storage_parameters([("fillfactor", "70"), ("autovacuum_enabled", "false")])
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "CREATE TABLE Film (id integer) WITH (fillfactor = 70, autovacuum_enabled = false)");
+    /// ```
+    pub fn with_storage_parameters(
+        mut self,
+        parameters: impl IntoIteratorOfSameType<StorageParameter>,
+    ) -> Self {
+        self.storage_parameters.extend(parameters.into_some_iter());
+        self
+    }
+
+    /// Set a `TABLESPACE` in which the table will be created
+    ///
+    /// ```
+    /// use scooby::postgres::create_table;
+    ///
+    /// let sql = create_table("Film")
+    ///     .columns([("id", "integer")])
+    ///     .tablespace("fast_ssd")
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "CREATE TABLE Film (id integer) TABLESPACE fast_ssd");
+    /// ```
+    pub fn tablespace(mut self, tablespace: impl Into<String>) -> Self {
+        self.tablespace = Some(tablespace.into());
+        self
+    }
+
+    /// Annotate this statement with a `key:value` pair, e.g. to identify its origin in `pg_stat_activity`.
+    ///
+    /// All annotations are rendered together in a single trailing `/* ... */` comment.
+    /// Anything that would end the comment early is broken up, so arbitrary values are safe to use.
+    ///
+    /// ```
+    /// use scooby::postgres::create_table;
+    ///
+    /// let sql = create_table("Dummy")
+    ///     .columns([("id", "integer")])
+    ///     .comment("migration", "0042")
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "CREATE TABLE Dummy (id integer) /* migration:0042 */");
+    /// ```
+    pub fn comment(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.comments.push(Comment::new(key, value));
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Persistence {
+    Temporary,
+    Unlogged,
+}
+
+impl Display for Persistence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Persistence::Temporary => write!(f, "TEMPORARY"),
+            Persistence::Unlogged => write!(f, "UNLOGGED"),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::postgres::tools::tests::assert_correct_postgresql;
-    use crate::postgres::{create_table, ColumnDefinitionable};
+    use crate::postgres::{
+        create_table, like, ColumnDefinitionable, LikeOption, OnCommit, ReferentialAction,
+        TableConstraint,
+    };
 
     #[test]
     fn regular_table() {
@@ -107,4 +539,341 @@ mod tests {
 
         assert_correct_postgresql(&sql, "CREATE TABLE IF NOT EXISTS Dummy (a integer)");
     }
+
+    #[test]
+    fn table_constraints() {
+        let sql = create_table("Booking")
+            .columns((
+                ("id", "integer"),
+                ("room_id", "integer"),
+                ("during", "tsrange"),
+            ))
+            .primary_key(("id", "room_id"))
+            .foreign_key("room_id")
+            .references("Room", "id")
+            .check("NOT isempty(during)")
+            .exclude_using("gist", [("room_id", "="), ("during", "&&")])
+            .to_string();
+
+        assert_correct_postgresql(&sql, "CREATE TABLE Booking (id integer, room_id integer, during tsrange, PRIMARY KEY (id, room_id), FOREIGN KEY (room_id) REFERENCES Room(id), CHECK (NOT isempty(during)), EXCLUDE USING gist (room_id WITH =, during WITH &&))");
+    }
+
+    #[test]
+    fn deferrable_constraints() {
+        let sql = create_table("Film")
+            .columns((
+                ("code", "char(5)"),
+                ("director_id", "integer")
+                    .references("Person", "id")
+                    .deferrable(),
+                ("producer_id", "integer")
+                    .references("Person", "id")
+                    .initially_immediate(),
+            ))
+            .add_constraint(TableConstraint::unique("code").not_deferrable())
+            .add_constraint(
+                TableConstraint::foreign_key("code")
+                    .references("Code", "code")
+                    .initially_deferred(),
+            )
+            .to_string();
+
+        assert_correct_postgresql(&sql, "CREATE TABLE Film (code char(5), director_id integer REFERENCES Person(id) DEFERRABLE, producer_id integer REFERENCES Person(id) DEFERRABLE INITIALLY IMMEDIATE, UNIQUE (code) NOT DEFERRABLE, FOREIGN KEY (code) REFERENCES Code(code) DEFERRABLE INITIALLY DEFERRED)");
+    }
+
+    #[test]
+    fn identity_columns() {
+        let sql = create_table("Dummy")
+            .columns((
+                ("a", "integer").generated_always_as_identity(),
+                ("b", "bigint")
+                    .not_null()
+                    .generated_by_default_as_identity()
+                    .start_with(10)
+                    .increment_by(-1)
+                    .min_value(-100)
+                    .max_value(100)
+                    .cache(5)
+                    .cycle(),
+            ))
+            .to_string();
+
+        assert_correct_postgresql(&sql, "CREATE TABLE Dummy (a integer GENERATED ALWAYS AS IDENTITY, b bigint NOT NULL GENERATED BY DEFAULT AS IDENTITY (START WITH 10 INCREMENT BY -1 MINVALUE -100 MAXVALUE 100 CACHE 5 CYCLE))");
+    }
+
+    #[test]
+    fn generated_columns() {
+        let sql = create_table("Document")
+            .columns((
+                ("body", "text").not_null(),
+                ("search", "tsvector").generated_as("to_tsvector('english', body)"),
+            ))
+            .to_string();
+
+        assert_correct_postgresql(&sql, "CREATE TABLE Document (body text NOT NULL, search tsvector GENERATED ALWAYS AS (to_tsvector('english', body)) STORED)");
+    }
+
+    #[test]
+    fn collations() {
+        let sql = create_table("Person")
+            .columns((
+                ("name", "text").collate("und-x-icu"),
+                ("code", "text").unique().collate("C"),
+            ))
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            r#"CREATE TABLE Person (name text COLLATE "und-x-icu", code text COLLATE "C" UNIQUE)"#,
+        );
+    }
+
+    #[test]
+    fn partitioned_tables() {
+        let sql = create_table("Cities")
+            .columns([("name", "text"), ("population", "bigint")])
+            .partition_by_list("left(lower(name), 1)")
+            .to_string();
+
+        assert_correct_postgresql(&sql, "CREATE TABLE Cities (name text, population bigint) PARTITION BY LIST (left(lower(name), 1))");
+
+        let sql = create_table("Orders")
+            .columns([("id", "bigint"), ("created_at", "date")])
+            .partition_by_hash(("id", "created_at"))
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "CREATE TABLE Orders (id bigint, created_at date) PARTITION BY HASH (id, created_at)",
+        );
+    }
+
+    #[test]
+    fn partitions() {
+        let sql = create_table("Measurement2021")
+            .partition_of("Measurement")
+            .for_values_from(("MINVALUE", "0"))
+            .to(("'2022-01-01'", "100"))
+            .to_string();
+
+        assert_correct_postgresql(&sql, "CREATE TABLE Measurement2021 PARTITION OF Measurement FOR VALUES FROM (MINVALUE, 0) TO ('2022-01-01', 100)");
+
+        let sql = create_table("CitiesAB")
+            .if_not_exists()
+            .partition_of("Cities")
+            .for_values_in(("'a'", "'b'"))
+            .primary_key("name")
+            .partition_by_range("population")
+            .to_string();
+
+        assert_correct_postgresql(&sql, "CREATE TABLE IF NOT EXISTS CitiesAB PARTITION OF Cities (PRIMARY KEY (name)) FOR VALUES IN ('a', 'b') PARTITION BY RANGE (population)");
+
+        let sql = create_table("Orders1")
+            .partition_of("Orders")
+            .for_values_with(4, 1)
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "CREATE TABLE Orders1 PARTITION OF Orders FOR VALUES WITH (MODULUS 4, REMAINDER 1)",
+        );
+
+        let sql = create_table("CitiesOther")
+            .partition_of("Cities")
+            .default()
+            .to_string();
+
+        assert_correct_postgresql(&sql, "CREATE TABLE CitiesOther PARTITION OF Cities DEFAULT");
+    }
+
+    #[test]
+    fn partition_columns() {
+        let sql = create_table("CitiesAB")
+            .partition_of("Cities")
+            .for_values_in(("'a'", "'b'"))
+            .add_column(("name", "text").not_null())
+            .add_column(
+                ("population", "bigint")
+                    .default("0")
+                    .check("population >= 0"),
+            )
+            .add_constraint(TableConstraint::unique("name"))
+            .to_string();
+
+        assert_correct_postgresql(&sql, "CREATE TABLE CitiesAB PARTITION OF Cities (name WITH OPTIONS NOT NULL, population WITH OPTIONS DEFAULT 0 CHECK (population >= 0), UNIQUE (name)) FOR VALUES IN ('a', 'b')");
+    }
+
+    #[test]
+    fn like_source_table() {
+        let sql = create_table("Shadow")
+            .columns((
+                ("id", "integer"),
+                like("Film")
+                    .including(LikeOption::All)
+                    .excluding(LikeOption::Indexes),
+                ("note", "text"),
+            ))
+            .to_string();
+
+        assert_correct_postgresql(&sql, "CREATE TABLE Shadow (id integer, LIKE Film INCLUDING ALL EXCLUDING INDEXES, note text)");
+    }
+
+    #[test]
+    fn storage_parameters_and_tablespace() {
+        let sql = create_table("Staging")
+            .temporary()
+            .on_commit(OnCommit::PreserveRows)
+            .columns([("id", "integer")])
+            .with_storage_parameters([("fillfactor", "70")])
+            .with_storage_parameters([("toast_tuple_target", "128")])
+            .tablespace("pg_default")
+            .to_string();
+
+        assert_correct_postgresql(&sql, "CREATE TEMPORARY TABLE Staging (id integer) WITH (fillfactor = 70, toast_tuple_target = 128) ON COMMIT PRESERVE ROWS TABLESPACE pg_default");
+    }
+
+    #[test]
+    fn access_method() {
+        let sql = create_table("Dummy")
+            .columns([("id", "integer")])
+            .using("heap")
+            .with_storage_parameters([("fillfactor", "70")])
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "CREATE TABLE Dummy (id integer) USING heap WITH (fillfactor = 70)",
+        );
+    }
+
+    #[test]
+    fn referential_actions() {
+        let sql = create_table("Film")
+            .columns((
+                ("director_id", "integer")
+                    .references("Person", "id")
+                    .match_full()
+                    .on_delete(ReferentialAction::Cascade)
+                    .on_update(ReferentialAction::NoAction)
+                    .deferrable(),
+                ("producer_id", "integer")
+                    .references("Person", "id")
+                    .match_simple()
+                    .on_update(ReferentialAction::Restrict),
+                ("studio_id", "integer")
+                    .references("Studio", "id")
+                    .on_delete(ReferentialAction::SetDefault),
+            ))
+            .to_string();
+
+        assert_correct_postgresql(&sql, "CREATE TABLE Film (director_id integer REFERENCES Person(id) MATCH FULL ON DELETE CASCADE ON UPDATE NO ACTION DEFERRABLE, producer_id integer REFERENCES Person(id) MATCH SIMPLE ON UPDATE RESTRICT, studio_id integer REFERENCES Studio(id) ON DELETE SET DEFAULT)");
+    }
+
+    #[test]
+    fn foreign_key_referential_actions() {
+        let sql = create_table("Film")
+            .columns([("director_id", "integer"), ("studio_id", "integer")])
+            .add_constraint(
+                TableConstraint::foreign_key("director_id")
+                    .references("Person", "id")
+                    .match_simple()
+                    .on_delete(ReferentialAction::SetNull)
+                    .initially_immediate(),
+            )
+            .add_constraint(
+                TableConstraint::foreign_key("studio_id")
+                    .references("Studio", "id")
+                    .on_update(ReferentialAction::Cascade),
+            )
+            .to_string();
+
+        assert_correct_postgresql(&sql, "CREATE TABLE Film (director_id integer, studio_id integer, FOREIGN KEY (director_id) REFERENCES Person(id) MATCH SIMPLE ON DELETE SET NULL DEFERRABLE INITIALLY IMMEDIATE, FOREIGN KEY (studio_id) REFERENCES Studio(id) ON UPDATE CASCADE)");
+    }
+
+    #[test]
+    fn incremental_columns() {
+        let columns = vec![("a", "integer"), ("b", "text"), ("c", "date")];
+
+        let mut statement = create_table("Dummy").columns(());
+        for column in columns {
+            statement = statement
+                .add_column(column)
+                .check(format!("{} IS NOT NULL", column.0));
+        }
+
+        assert_correct_postgresql(&statement.to_string(), "CREATE TABLE Dummy (a integer, b text, c date, CHECK (a IS NOT NULL), CHECK (b IS NOT NULL), CHECK (c IS NOT NULL))");
+    }
+
+    #[test]
+    fn temporary() {
+        let sql = create_table("Dummy")
+            .temporary()
+            .columns((("a", "integer"),))
+            .to_string();
+
+        assert_correct_postgresql(&sql, "CREATE TEMPORARY TABLE Dummy (a integer)");
+    }
+
+    #[test]
+    fn unlogged_if_not_exists() {
+        let sql = create_table("Dummy")
+            .unlogged()
+            .if_not_exists()
+            .columns((("a", "integer"),))
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "CREATE UNLOGGED TABLE IF NOT EXISTS Dummy (a integer)",
+        );
+    }
+
+    #[test]
+    fn temporary_on_commit() {
+        let sql = create_table("Dummy")
+            .temporary()
+            .on_commit(OnCommit::DeleteRows)
+            .columns((("a", "integer"),))
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "CREATE TEMPORARY TABLE Dummy (a integer) ON COMMIT DELETE ROWS",
+        );
+    }
+
+    #[test]
+    fn pretty() {
+        let sql = create_table("Film")
+            .columns((("code", "char(5)").primary_key(), ("title", "text")))
+            .unique("title")
+            .partition_by_hash("code")
+            .tablespace("pg_default");
+
+        assert_eq!(
+            format!("{:#}", sql),
+            "\
+CREATE TABLE Film (
+    code char(5) PRIMARY KEY,
+    title text,
+    UNIQUE (title)
+)
+PARTITION BY HASH (code)
+TABLESPACE pg_default"
+        );
+    }
+
+    #[test]
+    fn comment() {
+        let sql = create_table("Dummy")
+            .columns([("id", "integer")])
+            .tablespace("pg_default")
+            .comment("migration", "0042")
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "CREATE TABLE Dummy (id integer) TABLESPACE pg_default /* migration:0042 */",
+        );
+    }
 }
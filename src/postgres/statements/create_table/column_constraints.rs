@@ -1,15 +1,21 @@
 use std::fmt::{self, Display, Formatter};
 
 use crate::postgres::general::{Column, Condition, Expression, TableName};
+use crate::tools::joined;
 
-#[derive(Debug, Clone)]
+use super::deferrability::Deferrability;
+use super::referential_action::{MatchType, ReferentialAction};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ColumnConstraint {
     Null,
     NotNull,
     PrimaryKey,
     Unique,
     Default(Expression),
-    References(TableName, Column),
+    Identity(Identity),
+    GeneratedAs(Expression),
+    References(References),
     Check(Condition),
 }
 
@@ -25,9 +31,9 @@ impl Display for ColumnConstraint {
             Self::PrimaryKey => write!(f, "PRIMARY KEY"),
             Self::Unique => write!(f, "UNIQUE"),
             Self::Default(expr) => write!(f, "DEFAULT {}", expr),
-            Self::References(table_name, column) => {
-                write!(f, "REFERENCES {}({})", table_name, column)
-            }
+            Self::Identity(identity) => write!(f, "{}", identity),
+            Self::GeneratedAs(expr) => write!(f, "GENERATED ALWAYS AS ({}) STORED", expr),
+            Self::References(references) => write!(f, "{}", references),
             Self::Check(cond) => write!(f, "CHECK ({})", cond),
         }
     }
@@ -44,9 +50,6 @@ impl IntoColumnConstraint for NoConstraint {
     }
 }
 
-#[derive(Debug)]
-pub struct ImpossibleConstraint;
-
 /* Null and not null */
 
 pub trait NullabilityConstraint: IntoColumnConstraint {}
@@ -121,19 +124,176 @@ impl IntoColumnConstraint for HasDefault {
     }
 }
 
+/* Identity and generated columns, which occupy the same spot as default because they're all mutually exclusive */
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Identity {
+    generated: IdentityGenerated,
+    sequence_options: Vec<SequenceOption>,
+}
+
+impl Identity {
+    pub(crate) fn always() -> Self {
+        Identity {
+            generated: IdentityGenerated::Always,
+            sequence_options: Vec::new(),
+        }
+    }
+
+    pub(crate) fn by_default() -> Self {
+        Identity {
+            generated: IdentityGenerated::ByDefault,
+            sequence_options: Vec::new(),
+        }
+    }
+
+    pub(crate) fn add_sequence_option(&mut self, option: SequenceOption) {
+        self.sequence_options.push(option);
+    }
+}
+
+impl Display for Identity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "GENERATED {} AS IDENTITY", self.generated)?;
+
+        if !self.sequence_options.is_empty() {
+            write!(f, " ({})", joined(&self.sequence_options, " "))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl DefaultConstraint for Identity {}
+
+impl IntoColumnConstraint for Identity {
+    fn into_column_constraint(self) -> Option<ColumnConstraint> {
+        Some(ColumnConstraint::Identity(self))
+    }
+}
+
+#[derive(Debug)]
+pub struct GeneratedAs(pub(crate) Expression);
+
+impl DefaultConstraint for GeneratedAs {}
+
+impl IntoColumnConstraint for GeneratedAs {
+    fn into_column_constraint(self) -> Option<ColumnConstraint> {
+        Some(ColumnConstraint::GeneratedAs(self.0))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum IdentityGenerated {
+    Always,
+    ByDefault,
+}
+
+impl Display for IdentityGenerated {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Always => write!(f, "ALWAYS"),
+            Self::ByDefault => write!(f, "BY DEFAULT"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SequenceOption {
+    StartWith(i64),
+    IncrementBy(i64),
+    MinValue(i64),
+    MaxValue(i64),
+    Cache(i64),
+    Cycle,
+}
+
+impl Display for SequenceOption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StartWith(value) => write!(f, "START WITH {}", value),
+            Self::IncrementBy(value) => write!(f, "INCREMENT BY {}", value),
+            Self::MinValue(value) => write!(f, "MINVALUE {}", value),
+            Self::MaxValue(value) => write!(f, "MAXVALUE {}", value),
+            Self::Cache(value) => write!(f, "CACHE {}", value),
+            Self::Cycle => write!(f, "CYCLE"),
+        }
+    }
+}
+
 /* References */
 
 pub trait ReferencesConstraint: IntoColumnConstraint {}
 
-#[derive(Debug)]
-pub struct References(pub(crate) TableName, pub(crate) Column);
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct References {
+    table_name: TableName,
+    column: Column,
+    match_type: Option<MatchType>,
+    on_delete: Option<ReferentialAction>,
+    on_update: Option<ReferentialAction>,
+    deferrability: Option<Deferrability>,
+}
+
+impl References {
+    pub(crate) fn new(table_name: TableName, column: Column) -> Self {
+        References {
+            table_name,
+            column,
+            match_type: None,
+            on_delete: None,
+            on_update: None,
+            deferrability: None,
+        }
+    }
+
+    pub(crate) fn set_match_type(&mut self, match_type: MatchType) {
+        self.match_type = Some(match_type);
+    }
+
+    pub(crate) fn set_on_delete(&mut self, action: ReferentialAction) {
+        self.on_delete = Some(action);
+    }
+
+    pub(crate) fn set_on_update(&mut self, action: ReferentialAction) {
+        self.on_update = Some(action);
+    }
+
+    pub(crate) fn set_deferrability(&mut self, deferrability: Deferrability) {
+        self.deferrability = Some(deferrability);
+    }
+}
+
+impl Display for References {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "REFERENCES {}({})", self.table_name, self.column)?;
+
+        if let Some(match_type) = &self.match_type {
+            write!(f, " {}", match_type)?;
+        }
+
+        if let Some(action) = &self.on_delete {
+            write!(f, " ON DELETE {}", action)?;
+        }
+
+        if let Some(action) = &self.on_update {
+            write!(f, " ON UPDATE {}", action)?;
+        }
+
+        if let Some(deferrability) = &self.deferrability {
+            write!(f, " {}", deferrability)?;
+        }
+
+        Ok(())
+    }
+}
 
 impl ReferencesConstraint for References {}
 impl ReferencesConstraint for NoConstraint {}
 
 impl IntoColumnConstraint for References {
     fn into_column_constraint(self) -> Option<ColumnConstraint> {
-        Some(ColumnConstraint::References(self.0, self.1))
+        Some(ColumnConstraint::References(self))
     }
 }
 
@@ -1,20 +1,31 @@
 use std::fmt::{self, Display, Formatter};
 
-use crate::postgres::general::{Column, Condition, Expression, TableName};
+use crate::postgres::general::{Collation, Column, Condition, Expression, TableName};
 use crate::tools::joined;
 
 use super::column_constraints::*;
+use super::deferrability::Deferrability;
+use super::referential_action::{MatchType, ReferentialAction};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ColumnDefinition {
     name: String,
     type_: String,
+    collation: Option<Collation>,
     constraints: Vec<ColumnConstraint>,
 }
 
-impl Display for ColumnDefinition {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{} {}", self.name, self.type_)?;
+impl ColumnDefinition {
+    /// Render this column without its type, i.e. `name WITH OPTIONS ...`
+    pub(crate) fn fmt_options(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} WITH OPTIONS", self.name)?;
+        self.fmt_qualifiers(f)
+    }
+
+    fn fmt_qualifiers(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(collation) = &self.collation {
+            write!(f, " COLLATE {}", collation)?;
+        }
 
         if !self.constraints.is_empty() {
             write!(f, " {}", joined(&self.constraints, " "))?
@@ -24,6 +35,13 @@ impl Display for ColumnDefinition {
     }
 }
 
+impl Display for ColumnDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.name, self.type_)?;
+        self.fmt_qualifiers(f)
+    }
+}
+
 impl<N, P, U, D, R, C> From<ColumnDefinitionBuilder<N, P, U, D, R, C>> for ColumnDefinition
 where
     N: NullabilityConstraint,
@@ -63,6 +81,7 @@ where
         ColumnDefinition {
             name: builder.name,
             type_: builder.type_,
+            collation: builder.collation,
             constraints,
         }
     }
@@ -77,6 +96,7 @@ where
         ColumnDefinition {
             name: name.into(),
             type_: type_.into(),
+            collation: None,
             constraints: Vec::new(),
         }
     }
@@ -102,6 +122,7 @@ pub struct ColumnDefinitionBuilder<
 {
     name: String,
     type_: String,
+    collation: Option<Collation>,
     nullability: N,
     primary_key: P,
     unique: U,
@@ -115,6 +136,7 @@ impl ColumnDefinitionBuilder {
         ColumnDefinitionBuilder {
             name,
             type_,
+            collation: None,
             nullability: NoConstraint,
             primary_key: NoConstraint,
             unique: NoConstraint,
@@ -125,6 +147,32 @@ impl ColumnDefinitionBuilder {
     }
 }
 
+impl<N, P, U, D, R, C> ColumnDefinitionBuilder<N, P, U, D, R, C>
+where
+    N: NullabilityConstraint,
+    P: PrimaryKeyConstraint,
+    U: UniqueConstraint,
+    D: DefaultConstraint,
+    R: ReferencesConstraint,
+    C: CheckConstraint,
+{
+    /// Set a `COLLATE` option for this column
+    ///
+    /// ```
+    /// use scooby::postgres::{create_table, ColumnDefinitionable};
+    ///
+    /// let sql = create_table("Person")
+    ///     .columns([("name", "text").collate("und-x-icu").not_null()])
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, r#"CREATE TABLE Person (name text COLLATE "und-x-icu" NOT NULL)"#);
+    /// ```
+    pub fn collate(mut self, collation: impl Into<Collation>) -> Self {
+        self.collation = Some(collation.into());
+        self
+    }
+}
+
 impl<P, U, D, R, C> ColumnDefinitionBuilder<NoConstraint, P, U, D, R, C>
 where
     P: PrimaryKeyConstraint,
@@ -137,6 +185,7 @@ where
         ColumnDefinitionBuilder {
             name: self.name,
             type_: self.type_,
+            collation: self.collation,
             nullability: IsNull,
             primary_key: self.primary_key,
             unique: self.unique,
@@ -150,6 +199,7 @@ where
         ColumnDefinitionBuilder {
             name: self.name,
             type_: self.type_,
+            collation: self.collation,
             nullability: IsNotNull,
             primary_key: self.primary_key,
             unique: self.unique,
@@ -172,6 +222,7 @@ where
         ColumnDefinitionBuilder {
             name: self.name,
             type_: self.type_,
+            collation: self.collation,
             nullability: self.nullability,
             primary_key: IsPrimaryKey,
             unique: self.unique,
@@ -194,6 +245,7 @@ where
         ColumnDefinitionBuilder {
             name: self.name,
             type_: self.type_,
+            collation: self.collation,
             nullability: self.nullability,
             primary_key: self.primary_key,
             unique: IsUnique,
@@ -219,6 +271,7 @@ where
         ColumnDefinitionBuilder {
             name: self.name,
             type_: self.type_,
+            collation: self.collation,
             nullability: self.nullability,
             primary_key: self.primary_key,
             unique: self.unique,
@@ -229,6 +282,139 @@ where
     }
 }
 
+impl<N, P, U, R, C> ColumnDefinitionBuilder<N, P, U, NoConstraint, R, C>
+where
+    N: NullabilityConstraint,
+    P: PrimaryKeyConstraint,
+    U: UniqueConstraint,
+    R: ReferencesConstraint,
+    C: CheckConstraint,
+{
+    /// Make this a `GENERATED ALWAYS AS IDENTITY` column
+    ///
+    /// ```
+    /// use scooby::postgres::{create_table, ColumnDefinitionable};
+    ///
+    /// let sql = create_table("Film")
+    ///     .columns([("id", "integer").generated_always_as_identity().primary_key()])
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "CREATE TABLE Film (id integer PRIMARY KEY GENERATED ALWAYS AS IDENTITY)");
+    /// ```
+    pub fn generated_always_as_identity(self) -> ColumnDefinitionBuilder<N, P, U, Identity, R, C> {
+        self.with_identity(Identity::always())
+    }
+
+    /// Make this a `GENERATED BY DEFAULT AS IDENTITY` column
+    pub fn generated_by_default_as_identity(
+        self,
+    ) -> ColumnDefinitionBuilder<N, P, U, Identity, R, C> {
+        self.with_identity(Identity::by_default())
+    }
+
+    /// Make this a generated column, computed from other columns, i.e. `GENERATED ALWAYS AS (...) STORED`
+    ///
+    /// ```
+    /// use scooby::postgres::{create_table, ColumnDefinitionable};
+    ///
+    /// let sql = create_table("Person")
+    ///     .columns((
+    ///         ("email", "text"),
+    ///         ("normalized_email", "text").generated_as("lower(email)"),
+    ///     ))
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "CREATE TABLE Person (email text, normalized_email text GENERATED ALWAYS AS (lower(email)) STORED)");
+    /// ```
+    pub fn generated_as(
+        self,
+        expr: impl Into<Expression>,
+    ) -> ColumnDefinitionBuilder<N, P, U, GeneratedAs, R, C> {
+        ColumnDefinitionBuilder {
+            name: self.name,
+            type_: self.type_,
+            collation: self.collation,
+            nullability: self.nullability,
+            primary_key: self.primary_key,
+            unique: self.unique,
+            default: GeneratedAs(expr.into()),
+            references: self.references,
+            check: self.check,
+        }
+    }
+
+    fn with_identity(self, identity: Identity) -> ColumnDefinitionBuilder<N, P, U, Identity, R, C> {
+        ColumnDefinitionBuilder {
+            name: self.name,
+            type_: self.type_,
+            collation: self.collation,
+            nullability: self.nullability,
+            primary_key: self.primary_key,
+            unique: self.unique,
+            default: identity,
+            references: self.references,
+            check: self.check,
+        }
+    }
+}
+
+impl<N, P, U, R, C> ColumnDefinitionBuilder<N, P, U, Identity, R, C>
+where
+    N: NullabilityConstraint,
+    P: PrimaryKeyConstraint,
+    U: UniqueConstraint,
+    R: ReferencesConstraint,
+    C: CheckConstraint,
+{
+    /// Set `START WITH` option of the identity column's sequence
+    ///
+    /// ```
+    /// use scooby::postgres::{create_table, ColumnDefinitionable};
+    ///
+    /// let sql = create_table("Film")
+    ///     .columns([("id", "bigint")
+    ///         .generated_by_default_as_identity()
+    ///         .start_with(1000)
+    ///         .increment_by(10)])
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "CREATE TABLE Film (id bigint GENERATED BY DEFAULT AS IDENTITY (START WITH 1000 INCREMENT BY 10))");
+    /// ```
+    pub fn start_with(self, value: i64) -> Self {
+        self.with_sequence_option(SequenceOption::StartWith(value))
+    }
+
+    /// Set `INCREMENT BY` option of the identity column's sequence
+    pub fn increment_by(self, value: i64) -> Self {
+        self.with_sequence_option(SequenceOption::IncrementBy(value))
+    }
+
+    /// Set `MINVALUE` option of the identity column's sequence
+    pub fn min_value(self, value: i64) -> Self {
+        self.with_sequence_option(SequenceOption::MinValue(value))
+    }
+
+    /// Set `MAXVALUE` option of the identity column's sequence
+    pub fn max_value(self, value: i64) -> Self {
+        self.with_sequence_option(SequenceOption::MaxValue(value))
+    }
+
+    /// Set `CACHE` option of the identity column's sequence
+    pub fn cache(self, value: i64) -> Self {
+        self.with_sequence_option(SequenceOption::Cache(value))
+    }
+
+    /// Set `CYCLE` option of the identity column's sequence
+    pub fn cycle(self) -> Self {
+        self.with_sequence_option(SequenceOption::Cycle)
+    }
+
+    fn with_sequence_option(mut self, option: SequenceOption) -> Self {
+        self.default.add_sequence_option(option);
+        self
+    }
+}
+
 impl<N, P, U, D, C> ColumnDefinitionBuilder<N, P, U, D, NoConstraint, C>
 where
     N: NullabilityConstraint,
@@ -245,16 +431,101 @@ where
         ColumnDefinitionBuilder {
             name: self.name,
             type_: self.type_,
+            collation: self.collation,
             nullability: self.nullability,
             primary_key: self.primary_key,
             unique: self.unique,
             default: self.default,
-            references: References(table_name.into(), column.into()),
+            references: References::new(table_name.into(), column.into()),
             check: self.check,
         }
     }
 }
 
+impl<N, P, U, D, C> ColumnDefinitionBuilder<N, P, U, D, References, C>
+where
+    N: NullabilityConstraint,
+    P: PrimaryKeyConstraint,
+    U: UniqueConstraint,
+    D: DefaultConstraint,
+    C: CheckConstraint,
+{
+    /// Set an `ON DELETE` action of the `REFERENCES` constraint
+    ///
+    /// ```
+    /// use scooby::postgres::{create_table, ColumnDefinitionable, ReferentialAction};
+    ///
+    /// let sql = create_table("Film")
+    ///     .columns([("director_id", "integer")
+    ///         .references("Person", "id")
+    ///         .on_delete(ReferentialAction::Cascade)
+    ///         .on_update(ReferentialAction::SetNull)])
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "CREATE TABLE Film (director_id integer REFERENCES Person(id) ON DELETE CASCADE ON UPDATE SET NULL)");
+    /// ```
+    pub fn on_delete(mut self, action: ReferentialAction) -> Self {
+        self.references.set_on_delete(action);
+        self
+    }
+
+    /// Set an `ON UPDATE` action of the `REFERENCES` constraint
+    pub fn on_update(mut self, action: ReferentialAction) -> Self {
+        self.references.set_on_update(action);
+        self
+    }
+
+    /// Set `MATCH FULL` match type of the `REFERENCES` constraint
+    pub fn match_full(self) -> Self {
+        self.with_references_match_type(MatchType::Full)
+    }
+
+    /// Set `MATCH SIMPLE` match type of the `REFERENCES` constraint
+    pub fn match_simple(self) -> Self {
+        self.with_references_match_type(MatchType::Simple)
+    }
+
+    fn with_references_match_type(mut self, match_type: MatchType) -> Self {
+        self.references.set_match_type(match_type);
+        self
+    }
+
+    /// Make the `REFERENCES` constraint `DEFERRABLE`
+    pub fn deferrable(self) -> Self {
+        self.with_references_deferrability(Deferrability::Deferrable)
+    }
+
+    /// Make the `REFERENCES` constraint explicitly `NOT DEFERRABLE`
+    pub fn not_deferrable(self) -> Self {
+        self.with_references_deferrability(Deferrability::NotDeferrable)
+    }
+
+    /// Make the `REFERENCES` constraint `DEFERRABLE INITIALLY DEFERRED`
+    ///
+    /// ```
+    /// use scooby::postgres::{create_table, ColumnDefinitionable};
+    ///
+    /// let sql = create_table("Film")
+    ///     .columns([("director_id", "integer").references("Person", "id").initially_deferred()])
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "CREATE TABLE Film (director_id integer REFERENCES Person(id) DEFERRABLE INITIALLY DEFERRED)");
+    /// ```
+    pub fn initially_deferred(self) -> Self {
+        self.with_references_deferrability(Deferrability::InitiallyDeferred)
+    }
+
+    /// Make the `REFERENCES` constraint `DEFERRABLE INITIALLY IMMEDIATE`
+    pub fn initially_immediate(self) -> Self {
+        self.with_references_deferrability(Deferrability::InitiallyImmediate)
+    }
+
+    fn with_references_deferrability(mut self, deferrability: Deferrability) -> Self {
+        self.references.set_deferrability(deferrability);
+        self
+    }
+}
+
 impl<N, P, U, D, R> ColumnDefinitionBuilder<N, P, U, D, R, NoConstraint>
 where
     N: NullabilityConstraint,
@@ -270,6 +541,7 @@ where
         ColumnDefinitionBuilder {
             name: self.name,
             type_: self.type_,
+            collation: self.collation,
             nullability: self.nullability,
             primary_key: self.primary_key,
             unique: self.unique,
@@ -291,6 +563,7 @@ where
 }
 
 pub trait ColumnDefinitionable: Into<ColumnDefinitionBuilder> {
+    fn collate(self, collation: impl Into<Collation>) -> ColumnDefinitionBuilder;
     fn null(self) -> ColumnDefinitionBuilder<IsNull>;
     fn not_null(self) -> ColumnDefinitionBuilder<IsNotNull>;
     fn primary_key(self) -> ColumnDefinitionBuilder<NoConstraint, IsPrimaryKey>;
@@ -299,6 +572,16 @@ pub trait ColumnDefinitionable: Into<ColumnDefinitionBuilder> {
         self,
         expr: impl Into<Expression>,
     ) -> ColumnDefinitionBuilder<NoConstraint, NoConstraint, NoConstraint, HasDefault>;
+    fn generated_always_as_identity(
+        self,
+    ) -> ColumnDefinitionBuilder<NoConstraint, NoConstraint, NoConstraint, Identity>;
+    fn generated_by_default_as_identity(
+        self,
+    ) -> ColumnDefinitionBuilder<NoConstraint, NoConstraint, NoConstraint, Identity>;
+    fn generated_as(
+        self,
+        expr: impl Into<Expression>,
+    ) -> ColumnDefinitionBuilder<NoConstraint, NoConstraint, NoConstraint, GeneratedAs>;
     fn references(
         self,
         table_name: impl Into<TableName>,
@@ -322,6 +605,10 @@ where
     T: Into<String>,
     U: Into<String>,
 {
+    fn collate(self, collation: impl Into<Collation>) -> ColumnDefinitionBuilder {
+        ColumnDefinitionBuilder::from(self).collate(collation)
+    }
+
     fn null(self) -> ColumnDefinitionBuilder<IsNull> {
         ColumnDefinitionBuilder::from(self).null()
     }
@@ -345,6 +632,25 @@ where
         ColumnDefinitionBuilder::from(self).default(expr)
     }
 
+    fn generated_always_as_identity(
+        self,
+    ) -> ColumnDefinitionBuilder<NoConstraint, NoConstraint, NoConstraint, Identity> {
+        ColumnDefinitionBuilder::from(self).generated_always_as_identity()
+    }
+
+    fn generated_by_default_as_identity(
+        self,
+    ) -> ColumnDefinitionBuilder<NoConstraint, NoConstraint, NoConstraint, Identity> {
+        ColumnDefinitionBuilder::from(self).generated_by_default_as_identity()
+    }
+
+    fn generated_as(
+        self,
+        expr: impl Into<Expression>,
+    ) -> ColumnDefinitionBuilder<NoConstraint, NoConstraint, NoConstraint, GeneratedAs> {
+        ColumnDefinitionBuilder::from(self).generated_as(expr)
+    }
+
     fn references(
         self,
         table_name: impl Into<TableName>,
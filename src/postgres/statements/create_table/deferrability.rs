@@ -0,0 +1,21 @@
+use std::fmt::{self, Display, Formatter};
+
+/// `DEFERRABLE`-related options of constraints that support them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Deferrability {
+    NotDeferrable,
+    Deferrable,
+    InitiallyDeferred,
+    InitiallyImmediate,
+}
+
+impl Display for Deferrability {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotDeferrable => write!(f, "NOT DEFERRABLE"),
+            Self::Deferrable => write!(f, "DEFERRABLE"),
+            Self::InitiallyDeferred => write!(f, "DEFERRABLE INITIALLY DEFERRED"),
+            Self::InitiallyImmediate => write!(f, "DEFERRABLE INITIALLY IMMEDIATE"),
+        }
+    }
+}
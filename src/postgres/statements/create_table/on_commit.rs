@@ -0,0 +1,24 @@
+use std::fmt::{self, Display, Formatter};
+
+/// Behavior of a temporary table at the end of a transaction, i.e. an `ON COMMIT` option
+///
+/// Pass it to [`CreateTableBuilder::on_commit`][crate::postgres::statements::CreateTableBuilder::on_commit].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum OnCommit {
+    /// `ON COMMIT PRESERVE ROWS`, which is what PostgreSQL does by default
+    PreserveRows,
+    /// `ON COMMIT DELETE ROWS`
+    DeleteRows,
+    /// `ON COMMIT DROP`
+    Drop,
+}
+
+impl Display for OnCommit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            OnCommit::PreserveRows => write!(f, "ON COMMIT PRESERVE ROWS"),
+            OnCommit::DeleteRows => write!(f, "ON COMMIT DELETE ROWS"),
+            OnCommit::Drop => write!(f, "ON COMMIT DROP"),
+        }
+    }
+}
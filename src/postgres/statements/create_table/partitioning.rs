@@ -0,0 +1,217 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::postgres::general::{Expression, TableName};
+use crate::tools::{joined, IntoIteratorOfSameType};
+
+use super::{CreateTable, CreateTableBuilder};
+
+/// `PARTITION BY` clause of a partitioned table
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PartitionBy {
+    strategy: PartitionStrategy,
+    key: Vec<Expression>,
+}
+
+impl PartitionBy {
+    pub(crate) fn new(strategy: PartitionStrategy, key: Vec<Expression>) -> Self {
+        PartitionBy { strategy, key }
+    }
+}
+
+impl Display for PartitionBy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "PARTITION BY {} ({})",
+            self.strategy,
+            joined(&self.key, ", ")
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PartitionStrategy {
+    Range,
+    List,
+    Hash,
+}
+
+impl Display for PartitionStrategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Range => write!(f, "RANGE"),
+            Self::List => write!(f, "LIST"),
+            Self::Hash => write!(f, "HASH"),
+        }
+    }
+}
+
+/// `PARTITION OF parent ...` part of a table that is created as a partition of another table
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PartitionOf {
+    parent: TableName,
+    bound: PartitionBound,
+}
+
+impl Display for PartitionOf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "PARTITION OF {}", self.parent)
+    }
+}
+
+impl PartitionOf {
+    pub(crate) fn bound(&self) -> &PartitionBound {
+        &self.bound
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PartitionBound {
+    FromTo(Vec<Expression>, Vec<Expression>),
+    In(Vec<Expression>),
+    With(u32, u32),
+    Default,
+}
+
+impl Display for PartitionBound {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FromTo(from, to) => write!(
+                f,
+                "FOR VALUES FROM ({}) TO ({})",
+                joined(from, ", "),
+                joined(to, ", ")
+            ),
+            Self::In(values) => write!(f, "FOR VALUES IN ({})", joined(values, ", ")),
+            Self::With(modulus, remainder) => write!(
+                f,
+                "FOR VALUES WITH (MODULUS {}, REMAINDER {})",
+                modulus, remainder
+            ),
+            Self::Default => write!(f, "DEFAULT"),
+        }
+    }
+}
+
+/// Intermediate structure to ensure that a partition bound is specified for a `CREATE TABLE ... PARTITION OF` statement
+///
+/// Use one of the following methods to specify it:
+///
+/// - [`for_values_from`][PartitionOfBuilder::for_values_from] for `FOR VALUES FROM (...) TO (...)`
+/// - [`for_values_in`][PartitionOfBuilder::for_values_in] for `FOR VALUES IN (...)`
+/// - [`for_values_with`][PartitionOfBuilder::for_values_with] for `FOR VALUES WITH (MODULUS ..., REMAINDER ...)`
+/// - [`default`][PartitionOfBuilder::default] for `DEFAULT`
+#[must_use = "Making a partition without specifying its bounds is pointless"]
+#[derive(Debug)]
+pub struct PartitionOfBuilder {
+    builder: CreateTableBuilder,
+    parent: TableName,
+}
+
+impl PartitionOfBuilder {
+    pub(crate) fn new(builder: CreateTableBuilder, parent: TableName) -> Self {
+        PartitionOfBuilder { builder, parent }
+    }
+
+    /// Begin specifying a `FOR VALUES FROM (...) TO (...)` range partition bound
+    ///
+    /// Returns a [`PartitionFromBuilder`] structure which requires you to specify the upper bound.
+    ///
+    /// ```
+    /// use scooby::postgres::create_table;
+    ///
+    /// let sql = create_table("Measurement2021")
+    ///     .partition_of("Measurement")
+    ///     .for_values_from("'2021-01-01'")
+    ///     .to("'2022-01-01'")
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "CREATE TABLE Measurement2021 PARTITION OF Measurement FOR VALUES FROM ('2021-01-01') TO ('2022-01-01')");
+    /// ```
+    pub fn for_values_from(
+        self,
+        from: impl IntoIteratorOfSameType<Expression>,
+    ) -> PartitionFromBuilder {
+        PartitionFromBuilder {
+            builder: self,
+            from: from.into_some_iter().collect(),
+        }
+    }
+
+    /// Specify a `FOR VALUES IN (...)` list partition bound
+    ///
+    /// ```
+    /// use scooby::postgres::create_table;
+    ///
+    /// let sql = create_table("CitiesAB")
+    ///     .partition_of("Cities")
+    ///     .for_values_in(("'a'", "'b'"))
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "CREATE TABLE CitiesAB PARTITION OF Cities FOR VALUES IN ('a', 'b')");
+    /// ```
+    pub fn for_values_in(self, values: impl IntoIteratorOfSameType<Expression>) -> CreateTable {
+        self.with_bound(PartitionBound::In(values.into_some_iter().collect()))
+    }
+
+    /// Specify a `FOR VALUES WITH (MODULUS ..., REMAINDER ...)` hash partition bound
+    ///
+    /// ```
+    /// use scooby::postgres::create_table;
+    ///
+    /// let sql = create_table("Orders0")
+    ///     .partition_of("Orders")
+    ///     .for_values_with(4, 0)
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "CREATE TABLE Orders0 PARTITION OF Orders FOR VALUES WITH (MODULUS 4, REMAINDER 0)");
+    /// ```
+    pub fn for_values_with(self, modulus: u32, remainder: u32) -> CreateTable {
+        self.with_bound(PartitionBound::With(modulus, remainder))
+    }
+
+    /// Make this the `DEFAULT` partition
+    ///
+    /// ```
+    /// use scooby::postgres::create_table;
+    ///
+    /// let sql = create_table("CitiesOther")
+    ///     .partition_of("Cities")
+    ///     .default()
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "CREATE TABLE CitiesOther PARTITION OF Cities DEFAULT");
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn default(self) -> CreateTable {
+        self.with_bound(PartitionBound::Default)
+    }
+
+    fn with_bound(self, bound: PartitionBound) -> CreateTable {
+        self.builder.build(
+            Vec::new(),
+            Some(PartitionOf {
+                parent: self.parent,
+                bound,
+            }),
+        )
+    }
+}
+
+/// Intermediate structure to ensure that the upper bound of a range partition is specified
+///
+/// Use the only provided [`to`][PartitionFromBuilder::to] method to specify it.
+#[must_use = "Making a range partition without an upper bound is pointless"]
+#[derive(Debug)]
+pub struct PartitionFromBuilder {
+    builder: PartitionOfBuilder,
+    from: Vec<Expression>,
+}
+
+impl PartitionFromBuilder {
+    /// Specify the upper bound of the range partition, turning this into a [`CreateTable`] statement.
+    pub fn to(self, to: impl IntoIteratorOfSameType<Expression>) -> CreateTable {
+        let bound = PartitionBound::FromTo(self.from, to.into_some_iter().collect());
+        self.builder.with_bound(bound)
+    }
+}
@@ -0,0 +1,38 @@
+use std::fmt::{self, Display, Formatter};
+
+/// Action to take when a referenced row is deleted or updated, i.e. `ON DELETE ...` and `ON UPDATE ...`
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ReferentialAction {
+    NoAction,
+    Restrict,
+    Cascade,
+    SetNull,
+    SetDefault,
+}
+
+impl Display for ReferentialAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoAction => write!(f, "NO ACTION"),
+            Self::Restrict => write!(f, "RESTRICT"),
+            Self::Cascade => write!(f, "CASCADE"),
+            Self::SetNull => write!(f, "SET NULL"),
+            Self::SetDefault => write!(f, "SET DEFAULT"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MatchType {
+    Full,
+    Simple,
+}
+
+impl Display for MatchType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Full => write!(f, "MATCH FULL"),
+            Self::Simple => write!(f, "MATCH SIMPLE"),
+        }
+    }
+}
@@ -1,16 +1,426 @@
 use std::fmt::{self, Display, Formatter};
 
-use crate::{postgres::general::Column, tools::joined};
+use crate::postgres::general::{Column, Condition, Expression, TableName};
+use crate::tools::{joined, IntoIteratorOfSameType};
 
-#[derive(Debug, Clone)]
-pub enum TableConstraint {
-    Unique(Vec<Column>),
+use super::deferrability::Deferrability;
+use super::referential_action::{MatchType, ReferentialAction};
+use super::CreateTable;
+
+/// Table constraint of a `CREATE TABLE` statement, to be added with [`add_constraint`][CreateTable::add_constraint]
+///
+/// Useful when the set of constraints is only known at runtime.
+///
+/// ```
+/// use scooby::postgres::{create_table, TableConstraint};
+///
+/// let constraints: Vec<TableConstraint> = vec![
+///     TableConstraint::primary_key("id").into(),
+///     TableConstraint::check("price > 0"),
+///     TableConstraint::foreign_key("vendor_id")
+///         .references("Vendor", "id")
+///         .initially_deferred()
+///         .into(),
+/// ];
+///
+/// let mut statement = create_table("Product")
+///     .columns([("id", "integer"), ("vendor_id", "integer"), ("price", "numeric")]);
+///
+/// for constraint in constraints {
+///     statement = statement.add_constraint(constraint);
+/// }
+///
+/// assert_eq!(
+///     statement.to_string(),
+///     "CREATE TABLE Product (id integer, vendor_id integer, price numeric, PRIMARY KEY (id), CHECK (price > 0), FOREIGN KEY (vendor_id) REFERENCES Vendor(id) DEFERRABLE INITIALLY DEFERRED)",
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TableConstraint {
+    kind: TableConstraintKind,
+    deferrability: Option<Deferrability>,
+}
+
+impl TableConstraint {
+    pub(crate) fn new(kind: TableConstraintKind) -> Self {
+        TableConstraint {
+            kind,
+            deferrability: None,
+        }
+    }
+
+    /// Make a `UNIQUE (...)` table constraint
+    pub fn unique(columns: impl IntoIteratorOfSameType<Column>) -> DeferrableConstraint {
+        DeferrableConstraint(TableConstraint::new(TableConstraintKind::Unique(
+            columns.into_some_iter().collect(),
+        )))
+    }
+
+    /// Make a `PRIMARY KEY (...)` table constraint
+    pub fn primary_key(columns: impl IntoIteratorOfSameType<Column>) -> DeferrableConstraint {
+        DeferrableConstraint(TableConstraint::new(TableConstraintKind::PrimaryKey(
+            columns.into_some_iter().collect(),
+        )))
+    }
+
+    /// Begin making a `FOREIGN KEY (...)` table constraint
+    ///
+    /// Returns a [`ForeignKeyConstraintBuilder`] structure which requires you to specify referenced table and columns.
+    pub fn foreign_key(
+        columns: impl IntoIteratorOfSameType<Column>,
+    ) -> ForeignKeyConstraintBuilder {
+        ForeignKeyConstraintBuilder {
+            columns: columns.into_some_iter().collect(),
+        }
+    }
+
+    /// Make a `CHECK (...)` table constraint
+    ///
+    /// Unlike other table constraints, `CHECK` constraints can't be deferred.
+    pub fn check(condition: impl Into<Condition>) -> Self {
+        TableConstraint::new(TableConstraintKind::Check(condition.into()))
+    }
+
+    /// Make an `EXCLUDE USING ...` table constraint, with elements specified as `(expression, operator)` pairs
+    pub fn exclude_using(
+        index_method: impl Into<String>,
+        elements: impl IntoIteratorOfSameType<ExcludeElement>,
+    ) -> DeferrableConstraint {
+        DeferrableConstraint(TableConstraint::new(TableConstraintKind::Exclude(
+            index_method.into(),
+            elements.into_some_iter().collect(),
+        )))
+    }
+}
+
+/// Table constraint which may be deferred, i.e. any table constraint other than `CHECK`
+///
+/// Converts into a [`TableConstraint`], to be added with [`add_constraint`][CreateTable::add_constraint].
+///
+/// ```
+/// use scooby::postgres::{create_table, TableConstraint};
+///
+/// let sql = create_table("Dummy")
+///     .columns([("a", "integer")])
+///     .add_constraint(TableConstraint::unique("a").deferrable())
+///     .to_string();
+///
+/// assert_eq!(sql, "CREATE TABLE Dummy (a integer, UNIQUE (a) DEFERRABLE)");
+/// ```
+#[must_use = "Making a table constraint without adding it to a statement is pointless"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DeferrableConstraint(TableConstraint);
+
+impl DeferrableConstraint {
+    /// Make this constraint `DEFERRABLE`
+    pub fn deferrable(self) -> Self {
+        self.with_deferrability(Deferrability::Deferrable)
+    }
+
+    /// Make this constraint explicitly `NOT DEFERRABLE`
+    pub fn not_deferrable(self) -> Self {
+        self.with_deferrability(Deferrability::NotDeferrable)
+    }
+
+    /// Make this constraint `DEFERRABLE INITIALLY DEFERRED`
+    pub fn initially_deferred(self) -> Self {
+        self.with_deferrability(Deferrability::InitiallyDeferred)
+    }
+
+    /// Make this constraint `DEFERRABLE INITIALLY IMMEDIATE`
+    pub fn initially_immediate(self) -> Self {
+        self.with_deferrability(Deferrability::InitiallyImmediate)
+    }
+
+    fn with_deferrability(mut self, deferrability: Deferrability) -> Self {
+        self.0.deferrability = Some(deferrability);
+        self
+    }
+}
+
+impl Display for DeferrableConstraint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<DeferrableConstraint> for TableConstraint {
+    fn from(constraint: DeferrableConstraint) -> Self {
+        constraint.0
+    }
 }
 
 impl Display for TableConstraint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind)?;
+
+        if let Some(deferrability) = &self.deferrability {
+            write!(f, " {}", deferrability)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TableConstraintKind {
+    Unique(Vec<Column>),
+    PrimaryKey(Vec<Column>),
+    ForeignKey(ForeignKey),
+    Check(Condition),
+    Exclude(String, Vec<ExcludeElement>),
+}
+
+impl Display for TableConstraintKind {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             Self::Unique(columns) => write!(f, "UNIQUE ({})", joined(columns, ", ")),
+            Self::PrimaryKey(columns) => write!(f, "PRIMARY KEY ({})", joined(columns, ", ")),
+            Self::ForeignKey(foreign_key) => write!(f, "{}", foreign_key),
+            Self::Check(cond) => write!(f, "CHECK ({})", cond),
+            Self::Exclude(index_method, elements) => write!(
+                f,
+                "EXCLUDE USING {} ({})",
+                index_method,
+                joined(elements, ", ")
+            ),
+        }
+    }
+}
+
+/// Element of an `EXCLUDE` table constraint, i.e. `expression WITH operator`
+///
+/// Create it from an `(expression, operator)` tuple.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExcludeElement {
+    element: Expression,
+    operator: String,
+}
+
+impl Display for ExcludeElement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} WITH {}", self.element, self.operator)
+    }
+}
+
+impl<T: Into<Expression>, U: Into<String>> From<(T, U)> for ExcludeElement {
+    fn from((element, operator): (T, U)) -> Self {
+        ExcludeElement {
+            element: element.into(),
+            operator: operator.into(),
+        }
+    }
+}
+
+/// Intermediate structure to ensure that a standalone `FOREIGN KEY` table constraint actually references something
+///
+/// Use the only provided [`references`][ForeignKeyConstraintBuilder::references] method to finish the constraint.
+#[must_use = "Making a FOREIGN KEY constraint without specifying what it references is pointless"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ForeignKeyConstraintBuilder {
+    columns: Vec<Column>,
+}
+
+impl ForeignKeyConstraintBuilder {
+    /// Specify the referenced table and its columns, finishing the constraint.
+    ///
+    /// Referential actions, match type and deferrability may then be set on the returned [`ForeignKeyConstraint`].
+    pub fn references(
+        self,
+        table_name: impl Into<TableName>,
+        columns: impl IntoIteratorOfSameType<Column>,
+    ) -> ForeignKeyConstraint {
+        ForeignKeyConstraint {
+            foreign_key: ForeignKey {
+                columns: self.columns,
+                table_name: table_name.into(),
+                ref_columns: columns.into_some_iter().collect(),
+                match_type: None,
+                on_delete: None,
+                on_update: None,
+            },
+            deferrability: None,
+        }
+    }
+}
+
+/// Standalone `FOREIGN KEY` table constraint, created with [`TableConstraint::foreign_key`]
+///
+/// Converts into a [`TableConstraint`], to be added with [`add_constraint`][CreateTable::add_constraint].
+///
+/// ```
+/// use scooby::postgres::{create_table, ReferentialAction, TableConstraint};
+///
+/// let sql = create_table("Membership")
+///     .columns([("person_id", "integer"), ("club_id", "integer")])
+///     .add_constraint(
+///         TableConstraint::foreign_key(("person_id", "club_id"))
+///             .references("Person", ("id", "club_id"))
+///             .match_full()
+///             .on_delete(ReferentialAction::Cascade)
+///             .on_update(ReferentialAction::Restrict)
+///             .deferrable(),
+///     )
+///     .to_string();
+///
+/// assert_eq!(sql, "CREATE TABLE Membership (person_id integer, club_id integer, FOREIGN KEY (person_id, club_id) REFERENCES Person(id, club_id) MATCH FULL ON DELETE CASCADE ON UPDATE RESTRICT DEFERRABLE)");
+/// ```
+#[must_use = "Making a table constraint without adding it to a statement is pointless"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ForeignKeyConstraint {
+    foreign_key: ForeignKey,
+    deferrability: Option<Deferrability>,
+}
+
+impl ForeignKeyConstraint {
+    /// Set an `ON DELETE` action
+    pub fn on_delete(mut self, action: ReferentialAction) -> Self {
+        self.foreign_key.on_delete = Some(action);
+        self
+    }
+
+    /// Set an `ON UPDATE` action
+    pub fn on_update(mut self, action: ReferentialAction) -> Self {
+        self.foreign_key.on_update = Some(action);
+        self
+    }
+
+    /// Set `MATCH FULL` match type
+    pub fn match_full(self) -> Self {
+        self.with_match_type(MatchType::Full)
+    }
+
+    /// Set `MATCH SIMPLE` match type
+    pub fn match_simple(self) -> Self {
+        self.with_match_type(MatchType::Simple)
+    }
+
+    fn with_match_type(mut self, match_type: MatchType) -> Self {
+        self.foreign_key.match_type = Some(match_type);
+        self
+    }
+
+    /// Make this constraint `DEFERRABLE`
+    pub fn deferrable(self) -> Self {
+        self.with_deferrability(Deferrability::Deferrable)
+    }
+
+    /// Make this constraint explicitly `NOT DEFERRABLE`
+    pub fn not_deferrable(self) -> Self {
+        self.with_deferrability(Deferrability::NotDeferrable)
+    }
+
+    /// Make this constraint `DEFERRABLE INITIALLY DEFERRED`
+    pub fn initially_deferred(self) -> Self {
+        self.with_deferrability(Deferrability::InitiallyDeferred)
+    }
+
+    /// Make this constraint `DEFERRABLE INITIALLY IMMEDIATE`
+    pub fn initially_immediate(self) -> Self {
+        self.with_deferrability(Deferrability::InitiallyImmediate)
+    }
+
+    fn with_deferrability(mut self, deferrability: Deferrability) -> Self {
+        self.deferrability = Some(deferrability);
+        self
+    }
+}
+
+impl Display for ForeignKeyConstraint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.foreign_key)?;
+
+        if let Some(deferrability) = &self.deferrability {
+            write!(f, " {}", deferrability)?;
         }
+
+        Ok(())
+    }
+}
+
+impl From<ForeignKeyConstraint> for TableConstraint {
+    fn from(constraint: ForeignKeyConstraint) -> Self {
+        TableConstraint {
+            kind: TableConstraintKind::ForeignKey(constraint.foreign_key),
+            deferrability: constraint.deferrability,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ForeignKey {
+    columns: Vec<Column>,
+    table_name: TableName,
+    ref_columns: Vec<Column>,
+    match_type: Option<MatchType>,
+    on_delete: Option<ReferentialAction>,
+    on_update: Option<ReferentialAction>,
+}
+
+impl Display for ForeignKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "FOREIGN KEY ({}) REFERENCES {}({})",
+            joined(&self.columns, ", "),
+            self.table_name,
+            joined(&self.ref_columns, ", ")
+        )?;
+
+        if let Some(match_type) = &self.match_type {
+            write!(f, " {}", match_type)?;
+        }
+
+        if let Some(action) = &self.on_delete {
+            write!(f, " ON DELETE {}", action)?;
+        }
+
+        if let Some(action) = &self.on_update {
+            write!(f, " ON UPDATE {}", action)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Intermediate structure to ensure that a `FOREIGN KEY` table constraint actually references something
+///
+/// Use the only provided [`references`][ForeignKeyBuilder::references] method to finish the constraint.
+#[must_use = "Making a FOREIGN KEY constraint without specifying what it references is pointless"]
+#[derive(Debug)]
+pub struct ForeignKeyBuilder {
+    statement: CreateTable,
+    columns: Vec<Column>,
+}
+
+impl ForeignKeyBuilder {
+    pub(crate) fn new(statement: CreateTable, columns: Vec<Column>) -> Self {
+        ForeignKeyBuilder { statement, columns }
+    }
+
+    /// Specify the referenced table and its columns, returning back to the [`CreateTable`] statement.
+    ///
+    /// ```
+    /// use scooby::postgres::create_table;
+    ///
+    /// let sql = create_table("Film")
+    ///     .columns([("director_id", "integer")])
+    ///     .foreign_key("director_id")
+    ///     .references("Person", "id")
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "CREATE TABLE Film (director_id integer, FOREIGN KEY (director_id) REFERENCES Person(id))");
+    /// ```
+    pub fn references(
+        self,
+        table_name: impl Into<TableName>,
+        columns: impl IntoIteratorOfSameType<Column>,
+    ) -> CreateTable {
+        self.statement.add_constraint(
+            ForeignKeyConstraintBuilder {
+                columns: self.columns,
+            }
+            .references(table_name, columns),
+        )
     }
 }
@@ -0,0 +1,168 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::postgres::general::TableName;
+
+use super::column_constraints::*;
+use super::column_definition::{ColumnDefinition, ColumnDefinitionBuilder};
+use super::table_constraints::TableConstraint;
+
+/// Element of a `CREATE TABLE` statement's column list: either a column definition, or a `LIKE` clause
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TableElement {
+    Column(ColumnDefinition),
+    Like(Like),
+}
+
+impl Display for TableElement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Column(column) => write!(f, "{}", column),
+            Self::Like(like) => write!(f, "{}", like),
+        }
+    }
+}
+
+/// Item of a rendered column list, with a flag to render columns of partitions without types
+///
+/// Partitions take column types from their parent, so only `name WITH OPTIONS ...` may be specified.
+#[derive(Clone, Copy)]
+pub(crate) enum ColumnListItem<'a> {
+    Element(&'a TableElement, bool),
+    Constraint(&'a TableConstraint),
+}
+
+impl Display for ColumnListItem<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Element(TableElement::Column(column), true) => column.fmt_options(f),
+            Self::Element(element, _) => write!(f, "{}", element),
+            Self::Constraint(constraint) => write!(f, "{}", constraint),
+        }
+    }
+}
+
+impl From<ColumnDefinition> for TableElement {
+    fn from(column: ColumnDefinition) -> Self {
+        TableElement::Column(column)
+    }
+}
+
+impl<N, P, U, D, R, C> From<ColumnDefinitionBuilder<N, P, U, D, R, C>> for TableElement
+where
+    N: NullabilityConstraint,
+    P: PrimaryKeyConstraint,
+    U: UniqueConstraint,
+    D: DefaultConstraint,
+    R: ReferencesConstraint,
+    C: CheckConstraint,
+{
+    fn from(builder: ColumnDefinitionBuilder<N, P, U, D, R, C>) -> Self {
+        TableElement::Column(builder.into())
+    }
+}
+
+impl<T, U> From<(T, U)> for TableElement
+where
+    T: Into<String>,
+    U: Into<String>,
+{
+    fn from(pair: (T, U)) -> Self {
+        TableElement::Column(pair.into())
+    }
+}
+
+impl From<Like> for TableElement {
+    fn from(like: Like) -> Self {
+        TableElement::Like(like)
+    }
+}
+
+/// Create a `LIKE source_table` element for a `CREATE TABLE` statement's column list
+///
+/// Add `INCLUDING` and `EXCLUDING` options with [`including`][Like::including] and [`excluding`][Like::excluding].
+///
+/// ```
+/// use scooby::postgres::{create_table, like, LikeOption};
+///
+/// let sql = create_table("Shadow")
+///     .columns([like("Film")
+///         .including(LikeOption::Defaults)
+///         .including(LikeOption::Indexes)])
+///     .to_string();
+///
+/// assert_eq!(sql, "CREATE TABLE Shadow (LIKE Film INCLUDING DEFAULTS INCLUDING INDEXES)");
+/// ```
+pub fn like(source_table: impl Into<TableName>) -> Like {
+    Like {
+        source_table: source_table.into(),
+        options: Vec::new(),
+    }
+}
+
+/// `LIKE source_table [ { INCLUDING | EXCLUDING } ... ]` element of a `CREATE TABLE` statement
+///
+/// See [`like`] for details.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Like {
+    source_table: TableName,
+    options: Vec<(bool, LikeOption)>,
+}
+
+impl Like {
+    /// Add an `INCLUDING ...` option
+    pub fn including(mut self, option: LikeOption) -> Self {
+        self.options.push((true, option));
+        self
+    }
+
+    /// Add an `EXCLUDING ...` option
+    pub fn excluding(mut self, option: LikeOption) -> Self {
+        self.options.push((false, option));
+        self
+    }
+}
+
+impl Display for Like {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "LIKE {}", self.source_table)?;
+
+        for (including, option) in &self.options {
+            let keyword = if *including { "INCLUDING" } else { "EXCLUDING" };
+            write!(f, " {} {}", keyword, option)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Things that may be copied from the source table by a `LIKE` element
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum LikeOption {
+    Comments,
+    Compression,
+    Constraints,
+    Defaults,
+    Generated,
+    Identity,
+    Indexes,
+    Statistics,
+    Storage,
+    All,
+}
+
+impl Display for LikeOption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Comments => write!(f, "COMMENTS"),
+            Self::Compression => write!(f, "COMPRESSION"),
+            Self::Constraints => write!(f, "CONSTRAINTS"),
+            Self::Defaults => write!(f, "DEFAULTS"),
+            Self::Generated => write!(f, "GENERATED"),
+            Self::Identity => write!(f, "IDENTITY"),
+            Self::Indexes => write!(f, "INDEXES"),
+            Self::Statistics => write!(f, "STATISTICS"),
+            Self::Storage => write!(f, "STORAGE"),
+            Self::All => write!(f, "ALL"),
+        }
+    }
+}
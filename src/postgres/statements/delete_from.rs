@@ -1,7 +1,12 @@
 use std::fmt::{self, Display, Formatter};
 
-use crate::postgres::general::{Condition, OutputExpression, TableName, WithClause};
-use crate::tools::{joined, IntoIteratorOfSameType};
+use crate::postgres::general::{
+    Comment, Comments, Condition, Expression, OutputExpression, TableName, WithClause,
+};
+use crate::postgres::tools::Parameters;
+use crate::tools::{clause_break, write_clause, ClauseVec, IntoIteratorOfSameType};
+
+use super::BuildError;
 
 /// Create a new `DELETE FROM` statement with the given table name.
 ///
@@ -53,12 +58,14 @@ pub(crate) fn delete_from_with(table_name: TableName, with: WithClause) -> Delet
 ///
 /// See [`delete_from`] docs for more details and examples.
 #[must_use = "Making a DELETE FROM without using it is pointless"]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DeleteFrom {
     table_name: TableName,
-    with: Option<WithClause>,
-    where_: Vec<Condition>,
-    returning: Vec<OutputExpression>,
+    pub(super) with: Option<WithClause>,
+    where_: ClauseVec<Condition>,
+    returning: ClauseVec<OutputExpression>,
+    comments: Comments,
+    allow_full_table: bool,
 }
 
 impl DeleteFrom {
@@ -66,8 +73,10 @@ impl DeleteFrom {
         DeleteFrom {
             table_name,
             with,
-            where_: Vec::new(),
-            returning: Vec::new(),
+            where_: ClauseVec::new(),
+            returning: ClauseVec::new(),
+            comments: Comments::default(),
+            allow_full_table: false,
         }
     }
 
@@ -88,6 +97,23 @@ impl DeleteFrom {
         self
     }
 
+    /// Add a `column = $n` condition with the next placeholder from `params`, `AND`'ed with existing conditions.
+    ///
+    /// ```
+    /// use scooby::postgres::{delete_from, Parameters};
+    ///
+    /// let mut params = Parameters::new();
+    ///
+    /// let sql = delete_from("Dummy").where_eq("id", &mut params).to_string();
+    ///
+    /// assert_eq!(sql, "DELETE FROM Dummy WHERE id = $1");
+    /// ```
+    pub fn where_eq(mut self, column: impl Into<Expression>, params: &mut Parameters) -> Self {
+        self.where_
+            .push(format!("{} = {}", column.into(), params.next()));
+        self
+    }
+
     /// Add one or more `RETURNING` expressions.
     ///
     /// ```
@@ -104,24 +130,133 @@ impl DeleteFrom {
         self.returning.extend(expressions.into_some_iter());
         self
     }
+
+    /// Remove all `WHERE` conditions added so far
+    ///
+    /// ```
+    /// use scooby::postgres::delete_from;
+    ///
+    /// let sql = delete_from("Dummy").where_("x > 1").clear_where().to_string();
+    ///
+    /// assert_eq!(sql, "DELETE FROM Dummy");
+    /// ```
+    pub fn clear_where(mut self) -> Self {
+        self.where_.clear();
+        self
+    }
+
+    /// Remove all `RETURNING` expressions added so far
+    pub fn clear_returning(mut self) -> Self {
+        self.returning.clear();
+        self
+    }
+
+    /// Annotate this statement with a `key:value` pair, e.g. to identify its origin in `pg_stat_activity`.
+    ///
+    /// All annotations are rendered together in a single trailing `/* ... */` comment.
+    /// Anything that would end the comment early is broken up, so arbitrary values are safe to use.
+    ///
+    /// ```
+    /// use scooby::postgres::delete_from;
+    ///
+    /// let sql = delete_from("Dummy")
+    ///     .where_("x > 0")
+    ///     .comment("application", "billing")
+    ///     .comment("route", "/invoices")
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "DELETE FROM Dummy WHERE x > 0 /* application:billing,route:/invoices */");
+    /// ```
+    pub fn comment(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.comments.push(Comment::new(key, value));
+        self
+    }
+
+    /// Mark this statement as meant to affect every row of the table when it has no `WHERE` conditions
+    ///
+    /// Without this, [`verify`][DeleteFrom::verify] and [`try_build`][crate::postgres::Statement::try_build]
+    /// refuse statements without conditions, which are usually a forgotten `where_` call.
+    ///
+    /// ```
+    /// use scooby::postgres::{delete_from, Statement};
+    /// use scooby::postgres::statements::BuildError;
+    ///
+    /// assert_eq!(delete_from("Person").try_build(), Err(BuildError::MissingWhere));
+    /// assert_eq!(delete_from("Person").allow_full_table().try_build().unwrap(), "DELETE FROM Person");
+    /// ```
+    pub fn allow_full_table(mut self) -> Self {
+        self.allow_full_table = true;
+        self
+    }
+
+    /// Get the name of the table this statement operates on
+    ///
+    /// ```
+    /// use scooby::postgres::delete_from;
+    ///
+    /// let sql = delete_from("Dummy");
+    ///
+    /// assert_eq!(sql.table_name(), "Dummy");
+    /// ```
+    pub fn table_name(&self) -> &str {
+        &self.table_name
+    }
+
+    /// Get conditions of the `WHERE` clause added so far
+    pub fn where_conditions(&self) -> &[Condition] {
+        &self.where_
+    }
+
+    /// Check whether this statement has a `WHERE` clause
+    ///
+    /// ```
+    /// use scooby::postgres::delete_from;
+    ///
+    /// let sql = delete_from("Dummy");
+    ///
+    /// assert!(!sql.has_where());
+    /// assert!(sql.where_("x > 0").has_where());
+    /// ```
+    pub fn has_where(&self) -> bool {
+        !self.where_.is_empty()
+    }
+
+    /// Get expressions of the `RETURNING` clause added so far
+    pub fn returning_expressions(&self) -> &[OutputExpression] {
+        &self.returning
+    }
+
+    /// Check this statement for mistakes that would make it affect more rows than intended
+    ///
+    /// Currently checks that there are `WHERE` conditions, unless [`allow_full_table`][DeleteFrom::allow_full_table] is called.
+    pub fn verify(&self) -> Result<(), BuildError> {
+        if self.where_.is_empty() && !self.allow_full_table {
+            return Err(BuildError::MissingWhere);
+        }
+
+        Ok(())
+    }
 }
 
 impl Display for DeleteFrom {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         if let Some(with_clause) = &self.with {
-            write!(f, "{} ", with_clause)?;
+            with_clause.fmt(f)?;
+            clause_break(f)?;
         }
 
-        write!(f, "DELETE FROM {}", self.table_name,)?;
+        write!(f, "DELETE FROM {}", self.table_name)?;
 
         if !self.where_.is_empty() {
-            write!(f, " WHERE {}", joined(&self.where_, " AND "))?;
+            write_clause(f, "WHERE", &self.where_, " AND ")?;
         }
 
         if !self.returning.is_empty() {
-            write!(f, " RETURNING {}", joined(&self.returning, ", "))?;
+            write_clause(f, "RETURNING", &self.returning, ", ")?;
         }
 
+        self.comments.fmt(f)?;
+
         Ok(())
     }
 }
@@ -180,4 +315,39 @@ mod tests {
 
         assert_correct_postgresql(&sql, "WITH thing AS (SELECT 1 + 1) DELETE FROM Dummy");
     }
+
+    #[test]
+    fn pretty() {
+        let sql = with("thing")
+            .as_(select("1 + 1"))
+            .delete_from("Dummy")
+            .where_("x > 0")
+            .returning(("id", "place"));
+
+        assert_eq!(
+            format!("{:#}", sql),
+            "\
+WITH
+    thing AS (SELECT 1 + 1)
+DELETE FROM Dummy
+WHERE
+    x > 0
+RETURNING
+    id,
+    place"
+        );
+    }
+
+    #[test]
+    fn comment() {
+        let sql = delete_from("Dummy")
+            .returning("id")
+            .comment("application", "billing")
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "DELETE FROM Dummy RETURNING id /* application:billing */",
+        );
+    }
 }
@@ -0,0 +1,142 @@
+use std::fmt::{self, Display, Formatter};
+
+/// Create a new `DO` statement executing an anonymous code block in the given procedural language
+///
+/// The body is dollar-quoted as is. The quote tag is chosen so that it never collides with the body,
+/// so bodies containing `$$` or other dollar-quoted strings need no escaping.
+///
+/// ```
+/// use scooby::postgres::do_block;
+///
+/// let sql = do_block("plpgsql", "BEGIN PERFORM 1; END").to_string();
+/// assert_eq!(sql, "DO LANGUAGE plpgsql $$BEGIN PERFORM 1; END$$");
+///
+/// let sql = do_block("plpgsql", "BEGIN EXECUTE $$SELECT 1$$; END").to_string();
+/// assert_eq!(sql, "DO LANGUAGE plpgsql $do$BEGIN EXECUTE $$SELECT 1$$; END$do$");
+/// ```
+pub fn do_block(language: impl Into<String>, body: impl Into<String>) -> DoBlock {
+    let body = body.into();
+
+    let tag = (0..)
+        .map(|n| match n {
+            0 => "$$".to_owned(),
+            1 => "$do$".to_owned(),
+            n => format!("$do{}$", n - 1),
+        })
+        .find(|tag| !collides(&body, tag))
+        .unwrap();
+
+    DoBlock {
+        language: language.into(),
+        body,
+        tag,
+    }
+}
+
+/// `DO` statement, created with [`do_block`]
+#[must_use = "Making a DO statement without using it is pointless"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DoBlock {
+    language: String,
+    body: String,
+    tag: String,
+}
+
+impl DoBlock {
+    /// Get the procedural language of this code block
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// Get the body of this code block, unquoted
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    /// Get the dollar-quote tag the body will be quoted with, such as `$$` or `$do$`
+    ///
+    /// ```
+    /// use scooby::postgres::do_block;
+    ///
+    /// assert_eq!(do_block("plpgsql", "BEGIN END").tag(), "$$");
+    /// assert_eq!(do_block("plpgsql", "SELECT $$a$$, $do$b$do$").tag(), "$do1$");
+    /// ```
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+}
+
+/// Whether the closing `tag` would be found before the end of `body`, including
+/// partially overlapping with the end of it, e.g. a body ending in `$` before `$$`
+fn collides(body: &str, tag: &str) -> bool {
+    body.contains(tag)
+        || (1..tag.len()).any(|n| body.ends_with(&tag[..n]) && tag.starts_with(&tag[n..]))
+}
+
+impl Display for DoBlock {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "DO LANGUAGE {} {}{}{}",
+            self.language, self.tag, self.body, self.tag
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::do_block;
+
+    use crate::postgres::statements::StatementKind;
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::Statement;
+
+    #[test]
+    fn plain() {
+        let statement = do_block(
+            "plpgsql",
+            "BEGIN IF NOT EXISTS (SELECT 1 FROM pg_roles WHERE rolname = 'app') THEN CREATE ROLE app; END IF; END",
+        );
+
+        assert_eq!(statement.kind(), StatementKind::Do);
+        assert_correct_postgresql(
+            &statement.to_string(),
+            "DO LANGUAGE plpgsql $$BEGIN IF NOT EXISTS (SELECT 1 FROM pg_roles WHERE rolname = 'app') THEN CREATE ROLE app; END IF; END$$",
+        );
+    }
+
+    #[test]
+    fn nested_dollar_quotes() {
+        let sql = do_block(
+            "plpgsql",
+            "BEGIN EXECUTE $$SELECT $do$x$do$$$; EXECUTE $do1$SELECT 1$do1$; END",
+        )
+        .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "DO LANGUAGE plpgsql $do2$BEGIN EXECUTE $$SELECT $do$x$do$$$; EXECUTE $do1$SELECT 1$do1$; END$do2$",
+        );
+    }
+
+    #[test]
+    fn trailing_dollar() {
+        let sql = do_block("plpgsql", "BEGIN PERFORM 'a$'; END; --$").to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "DO LANGUAGE plpgsql $do$BEGIN PERFORM 'a$'; END; --$$do$",
+        );
+    }
+
+    #[test]
+    fn overlapping_tag() {
+        let statement = do_block("plpgsql", "BEGIN EXECUTE $$SELECT 1$$; END; --$do");
+
+        assert_eq!(statement.tag(), "$do1$");
+        assert_correct_postgresql(
+            &statement.to_string(),
+            "DO LANGUAGE plpgsql $do1$BEGIN EXECUTE $$SELECT 1$$; END; --$do$do1$",
+        );
+    }
+}
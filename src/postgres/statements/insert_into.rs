@@ -1,13 +1,25 @@
+mod batches;
 mod on_conflict;
 mod values;
 
 use std::fmt::{self, Display, Formatter};
 
-use crate::postgres::general::{Column, Expression, OutputExpression, TableName, WithClause};
-use crate::tools::{joined, IntoIteratorOfSameType, IntoNonZeroArray};
+use crate::postgres::general::{
+    Column, Comment, Comments, Expression, OutputExpression, TableName, WithClause,
+};
+use crate::tools::{
+    clause_break, write_clause, ClauseVec, IntoIteratorOfSameType, IntoNonZeroArray,
+};
 
-pub use on_conflict::{OnConflictClause, OnConflictClauseBuilder};
-pub use values::{DefaultValues, Values, WithColumns, WithoutColumns};
+use super::BuildError;
+
+pub use batches::InsertBatches;
+pub use on_conflict::{
+    OnConflictClause, OnConflictClauseBuilder, WithConflictTarget, WithoutConflictTarget,
+};
+pub use values::{
+    DefaultValues, DynamicColumns, DynamicValuesError, Values, WithColumns, WithoutColumns,
+};
 
 /// Start building a new `INSERT INTO` statement with the given table name.
 ///
@@ -17,20 +29,27 @@ pub use values::{DefaultValues, Values, WithColumns, WithoutColumns};
 /// 1. For `DEFAULT VALUES`, call [`default_values`][BareInsertInto::default_values]
 /// 2. For `VALUES (...)` with unspecified columns, call [`values`][BareInsertInto::values]
 /// 3. For `(...) VALUES (...)`, call [`columns`][BareInsertInto::columns]
+/// 4. For `(...) VALUES (...)` with columns only known at runtime, call [`columns_dyn`][BareInsertInto::columns_dyn]
+/// 5. For a single row of `(...) VALUES (...)` from column + value pairs, call [`set_map`][BareInsertInto::set_map]
 ///
 /// First two options will give you an [`InsertInto`] structure directly
 ///
 /// Option 3 will expect you to specify at least one set of values through [`values`][InsertIntoColumnsBuilder::values] method
 ///
+/// Option 4 will expect you to specify at least one set of values through [`values_dyn`][InsertIntoDynamicColumnsBuilder::values_dyn] method,
+/// which checks their number at runtime
+///
+/// Option 5 will give you an [`InsertInto`] structure directly, unless there are no pairs
+///
 /// Call `to_string` on the final `InsertInto` structure to finalize and get an SQL string.
 ///
 /// # Supported clauses
 ///
-/// | Clause        | Method                                   |
-/// |---------------|------------------------------------------|
-/// | `VALUES`      | [`values`][InsertInto::values]           |
-/// | `ON CONFLICT` | [`on_conflict`][InsertInto::on_conflict] |
-/// | `RETURNING`   | [`returning`][InsertInto::returning]     |
+/// | Clause        | Method                                                                                             |
+/// |---------------|----------------------------------------------------------------------------------------------------|
+/// | `VALUES`      | [`values`][InsertInto::values]                                                                     |
+/// | `ON CONFLICT` | [`on_conflict`][InsertInto::on_conflict], [`on_conflict_columns`][InsertInto::on_conflict_columns] |
+/// | `RETURNING`   | [`returning`][InsertInto::returning]                                                               |
 ///
 /// # Specifying a `WITH` clause
 ///
@@ -124,7 +143,8 @@ impl BareInsertInto {
 
     /// Begin building a `(...) VALUES (...)` clause for this statement.
     ///
-    /// Expects a non-zero list of columns: an array, a tuple, or a single value.
+    /// Expects a non-zero list of columns: an array of up to 32 items, a tuple of up to 24 items, or a single value.
+    /// Use [`columns_dyn`][BareInsertInto::columns_dyn] for longer lists.
     ///
     /// Returns an [`InsertIntoColumnsBuilder`] structure which requires you to specify at least one set of values.
     ///
@@ -137,6 +157,17 @@ impl BareInsertInto {
     ///     .to_string();
     ///
     /// assert_eq!(sql, "INSERT INTO Dummy (col1, col2) VALUES ($1, $2), ($3, $4)");
+    /// ```
+    ///
+    /// An empty list of columns is rejected at compile time:
+    ///
+    /// ```compile_fail
+    /// use scooby::postgres::general::Column;
+    /// use scooby::postgres::insert_into;
+    ///
+    /// let columns: [Column; 0] = [];
+    /// insert_into("Dummy").columns(columns);
+    /// ```
     pub fn columns<const N: usize>(
         self,
         columns: impl IntoNonZeroArray<Column, N>,
@@ -147,6 +178,68 @@ impl BareInsertInto {
             columns: columns.into_non_zero_array(),
         }
     }
+
+    /// Begin building a `(...) VALUES (...)` clause for this statement, with columns only known at runtime.
+    ///
+    /// Returns an [`InsertIntoDynamicColumnsBuilder`] structure which requires you to specify at least one set of values,
+    /// checking that each of them has as many values as there are columns.
+    ///
+    /// ```
+    /// use scooby::postgres::insert_into;
+    ///
+    /// let fields = vec![("name", "$1"), ("age", "$2")];
+    ///
+    /// let sql = insert_into("Person")
+    ///     .columns_dyn(fields.iter().map(|(column, _)| *column).collect::<Vec<_>>())
+    ///     .values_dyn([fields.iter().map(|(_, value)| *value).collect::<Vec<_>>()])
+    ///     .unwrap()
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "INSERT INTO Person (name, age) VALUES ($1, $2)");
+    /// ```
+    pub fn columns_dyn(
+        self,
+        columns: impl IntoIteratorOfSameType<Column>,
+    ) -> InsertIntoDynamicColumnsBuilder {
+        InsertIntoDynamicColumnsBuilder {
+            table_name: self.table_name,
+            with: self.with,
+            columns: columns.into_some_iter().collect(),
+        }
+    }
+
+    /// Add a single row of values from column + value pairs, such as a map of them
+    ///
+    /// Columns come in the order of pairs, so use an ordered map, e.g. a `BTreeMap`, for the same SQL every time.
+    /// Returns [`DynamicValuesError::NoColumns`] if there are no pairs.
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use scooby::postgres::insert_into;
+    ///
+    /// let mut fields = BTreeMap::new();
+    /// fields.insert("name", "$1");
+    /// fields.insert("age", "$2");
+    ///
+    /// let sql = insert_into("Person").set_map(fields).unwrap().to_string();
+    ///
+    /// assert_eq!(sql, "INSERT INTO Person (age, name) VALUES ($2, $1)");
+    /// ```
+    pub fn set_map<C, E>(
+        self,
+        pairs: impl IntoIterator<Item = (C, E)>,
+    ) -> Result<InsertInto<DynamicColumns>, DynamicValuesError>
+    where
+        C: Into<Column>,
+        E: Into<Expression>,
+    {
+        let (columns, values): (Vec<Column>, Vec<Expression>) = pairs
+            .into_iter()
+            .map(|(column, value)| (column.into(), value.into()))
+            .unzip();
+
+        self.columns_dyn(columns).values_dyn([values])
+    }
 }
 
 /// Intermediate structure to ensure one cannot build an `INSERT INTO` statement with columns, but without values
@@ -190,6 +283,90 @@ impl<const N: usize> InsertIntoColumnsBuilder<N> {
             self.with,
         )
     }
+
+    /// Split rows into as many statements as needed to keep each under `max_parameters` placeholders
+    ///
+    /// Every statement has a `$1, $2, ...` placeholder for each value of its rows, numbered from `$1`,
+    /// and is yielded along with its rows, so that their values may be bound in the same order.
+    /// Use [`Parameters::MAX`][crate::postgres::Parameters::MAX] for the limit of PostgreSQL itself. There's at least one row in each statement,
+    /// even if it has more values than the limit.
+    ///
+    /// ```
+    /// use scooby::postgres::insert_into;
+    ///
+    /// let people = [("Alice", 30), ("Bob", 25), ("Carol", 41)];
+    ///
+    /// let batches: Vec<_> = insert_into("Person")
+    ///     .columns(("name", "age"))
+    ///     .batches(people, 4)
+    ///     .map(|(statement, rows)| (statement.to_string(), rows))
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     batches,
+    ///     [
+    ///         ("INSERT INTO Person (name, age) VALUES ($1, $2), ($3, $4)".to_string(), vec![("Alice", 30), ("Bob", 25)]),
+    ///         ("INSERT INTO Person (name, age) VALUES ($1, $2)".to_string(), vec![("Carol", 41)]),
+    ///     ],
+    /// );
+    /// ```
+    pub fn batches<I: IntoIterator>(
+        self,
+        rows: I,
+        max_parameters: usize,
+    ) -> InsertBatches<I::IntoIter, N> {
+        InsertBatches::new(
+            self.table_name,
+            self.with,
+            self.columns,
+            rows.into_iter(),
+            max_parameters,
+        )
+    }
+}
+
+/// Intermediate structure to ensure one cannot build an `INSERT INTO` statement with runtime columns, but without values
+///
+/// Use the only provided [`values_dyn`][InsertIntoDynamicColumnsBuilder::values_dyn] method to add at least one set of values.
+#[must_use = "Making a bare INSERT INTO statement with columns is pointless"]
+#[derive(Debug)]
+pub struct InsertIntoDynamicColumnsBuilder {
+    table_name: TableName,
+    with: Option<WithClause>,
+    columns: Vec<Column>,
+}
+
+impl InsertIntoDynamicColumnsBuilder {
+    /// Add first one or more sets of values, each of which must have as many values as there are columns.
+    ///
+    /// Further values and additional clauses may be added by calling appropriate methods
+    /// on the returned [`InsertInto`] structure.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no columns, no sets of values, or if any of them has a wrong number of values.
+    ///
+    /// ```
+    /// use scooby::postgres::insert_into;
+    /// use scooby::postgres::statements::DynamicValuesError;
+    ///
+    /// let result = insert_into("Dummy")
+    ///     .columns_dyn(vec!["col1", "col2"])
+    ///     .values_dyn(vec![vec!["$1", "$2"], vec!["$3"]]);
+    ///
+    /// assert_eq!(
+    ///     result.unwrap_err(),
+    ///     DynamicValuesError::WrongWidth { row: 1, expected: 2, found: 1 },
+    /// );
+    /// ```
+    pub fn values_dyn<T: IntoIteratorOfSameType<Expression>>(
+        self,
+        values: impl IntoIterator<Item = T>,
+    ) -> Result<InsertInto<DynamicColumns>, DynamicValuesError> {
+        let mut dynamic = DynamicColumns::new(self.columns);
+        dynamic.add(values)?;
+        Ok(InsertInto::new(self.table_name, dynamic, self.with))
+    }
 }
 
 /// `INSERT INTO` statement with a `VALUES` clause, and possibly additional clauses.
@@ -198,13 +375,14 @@ impl<const N: usize> InsertIntoColumnsBuilder<N> {
 ///
 /// See [`insert_into`] docs for more details and examples.
 #[must_use = "Making an INSERT INTO statement without using it is pointless"]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct InsertInto<V: Values> {
     table_name: TableName,
-    with: Option<WithClause>,
+    pub(super) with: Option<WithClause>,
     values: V,
-    returning: Vec<OutputExpression>,
+    returning: ClauseVec<OutputExpression>,
     on_conflict: Option<OnConflictClause>,
+    comments: Comments,
 }
 
 impl<V: Values> InsertInto<V> {
@@ -214,7 +392,8 @@ impl<V: Values> InsertInto<V> {
             with,
             values,
             on_conflict: None,
-            returning: Vec::new(),
+            returning: ClauseVec::new(),
+            comments: Comments::default(),
         }
     }
 
@@ -255,6 +434,138 @@ impl<V: Values> InsertInto<V> {
     pub fn on_conflict(self) -> OnConflictClauseBuilder<V> {
         OnConflictClauseBuilder::new(self)
     }
+
+    /// Add an `ON CONFLICT DO NOTHING` clause to this statement, i.e. insert only rows that don't exist yet.
+    ///
+    /// Shortcut for `.on_conflict().do_nothing()`.
+    ///
+    /// ```
+    /// use scooby::postgres::insert_into;
+    ///
+    /// let sql = insert_into("Tag")
+    ///     .columns("name")
+    ///     .values([("$1",), ("$2",)])
+    ///     .on_conflict_do_nothing()
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "INSERT INTO Tag (name) VALUES ($1), ($2) ON CONFLICT DO NOTHING");
+    /// ```
+    pub fn on_conflict_do_nothing(self) -> Self {
+        self.on_conflict().do_nothing()
+    }
+
+    /// Add an `ON CONFLICT (...)` clause with the given conflict target columns to this statement.
+    ///
+    /// Returns a [`OnConflictClauseBuilder`] structure which requires you to specify
+    /// an action to do when a conflict happens using follow up methods.
+    ///
+    /// ```
+    /// use scooby::postgres::insert_into;
+    ///
+    /// let sql = insert_into("Person")
+    ///     .columns(("id", "name"))
+    ///     .values([("$1", "$2")])
+    ///     .on_conflict_columns("id")
+    ///     .do_update_set([("name", "EXCLUDED.name")])
+    ///     .returning("id")
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "INSERT INTO Person (id, name) VALUES ($1, $2) ON CONFLICT (id) DO UPDATE SET name = EXCLUDED.name RETURNING id");
+    /// ```
+    pub fn on_conflict_columns(
+        self,
+        columns: impl IntoIteratorOfSameType<Column>,
+    ) -> OnConflictClauseBuilder<V, WithConflictTarget> {
+        OnConflictClauseBuilder::with_target(self, columns.into_some_iter().collect())
+    }
+
+    fn on_conflict_columns_within(
+        self,
+        inserted: &[Column],
+        columns: impl IntoIteratorOfSameType<Column>,
+    ) -> Result<OnConflictClauseBuilder<V, WithConflictTarget>, BuildError> {
+        let target: Vec<Column> = columns.into_some_iter().collect();
+
+        if let Some(column) = target.iter().find(|column| !inserted.contains(column)) {
+            return Err(BuildError::UnknownConflictColumn {
+                column: column.to_string(),
+            });
+        }
+
+        Ok(OnConflictClauseBuilder::with_target(self, target))
+    }
+
+    /// Remove all `RETURNING` expressions added so far
+    pub fn clear_returning(mut self) -> Self {
+        self.returning.clear();
+        self
+    }
+
+    /// Annotate this statement with a `key:value` pair, e.g. to identify its origin in `pg_stat_activity`.
+    ///
+    /// All annotations are rendered together in a single trailing `/* ... */` comment.
+    /// Anything that would end the comment early is broken up, so arbitrary values are safe to use.
+    ///
+    /// ```
+    /// use scooby::postgres::insert_into;
+    ///
+    /// let sql = insert_into("Dummy")
+    ///     .default_values()
+    ///     .comment("application", "billing")
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "INSERT INTO Dummy DEFAULT VALUES /* application:billing */");
+    /// ```
+    pub fn comment(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.comments.push(Comment::new(key, value));
+        self
+    }
+
+    /// Get the name of the table this statement operates on
+    ///
+    /// ```
+    /// use scooby::postgres::insert_into;
+    ///
+    /// let sql = insert_into("Dummy").default_values();
+    ///
+    /// assert_eq!(sql.table_name(), "Dummy");
+    /// ```
+    pub fn table_name(&self) -> &str {
+        &self.table_name
+    }
+
+    /// Get expressions of the `RETURNING` clause added so far
+    pub fn returning_expressions(&self) -> &[OutputExpression] {
+        &self.returning
+    }
+
+    /// Check this statement for mistakes that PostgreSQL would reject it for
+    ///
+    /// Checks that there is at least one row of values, and that `ON CONFLICT DO UPDATE` has a conflict target.
+    ///
+    /// ```
+    /// use scooby::postgres::insert_into;
+    /// use scooby::postgres::statements::BuildError;
+    ///
+    /// let statement = insert_into("Dummy").columns("a").values(Vec::<&str>::new());
+    /// assert_eq!(statement.verify(), Err(BuildError::NoRows));
+    ///
+    /// let statement = insert_into("Dummy").values(["$1"]).on_conflict().do_update_set([("a", "$1")]);
+    /// assert_eq!(statement.verify(), Err(BuildError::MissingConflictTarget));
+    /// ```
+    pub fn verify(&self) -> Result<(), BuildError> {
+        if self.values.is_empty() {
+            return Err(BuildError::NoRows);
+        }
+
+        if let Some(on_conflict) = &self.on_conflict {
+            if on_conflict.is_missing_target() {
+                return Err(BuildError::MissingConflictTarget);
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl<const N: usize> InsertInto<WithColumns<N>> {
@@ -277,6 +588,35 @@ impl<const N: usize> InsertInto<WithColumns<N>> {
         self.values.add(new_values);
         self
     }
+
+    /// Add an `ON CONFLICT (...)` clause with a conflict target made of some of the inserted columns
+    ///
+    /// To use all of the inserted columns as the target, declare them once as a [`ColumnList`][crate::postgres::schema::ColumnList]
+    /// and pass it to both [`columns`][BareInsertInto::columns] and [`on_conflict_columns`][InsertInto::on_conflict_columns].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuildError::UnknownConflictColumn`] if any of the target columns is not inserted.
+    ///
+    /// ```
+    /// use scooby::postgres::insert_into;
+    /// use scooby::postgres::statements::BuildError;
+    ///
+    /// let statement = || insert_into("Person").columns(("email", "name")).values([("$1", "$2")]);
+    ///
+    /// let sql = statement().on_conflict_columns_subset("email").unwrap().do_update_set_excluded().to_string();
+    /// assert_eq!(sql, "INSERT INTO Person (email, name) VALUES ($1, $2) ON CONFLICT (email) DO UPDATE SET name = EXCLUDED.name");
+    ///
+    /// let result = statement().on_conflict_columns_subset("mail");
+    /// assert_eq!(result.err(), Some(BuildError::UnknownConflictColumn { column: "mail".to_owned() }));
+    /// ```
+    pub fn on_conflict_columns_subset(
+        self,
+        columns: impl IntoIteratorOfSameType<Column>,
+    ) -> Result<OnConflictClauseBuilder<WithColumns<N>, WithConflictTarget>, BuildError> {
+        let inserted = self.values.columns().to_vec();
+        self.on_conflict_columns_within(&inserted, columns)
+    }
 }
 
 impl<const N: usize> InsertInto<WithoutColumns<N>> {
@@ -300,22 +640,70 @@ impl<const N: usize> InsertInto<WithoutColumns<N>> {
     }
 }
 
+impl InsertInto<DynamicColumns> {
+    /// Add one or more sets of values, each of which must have as many values as there are columns.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no sets of values, or if any of them has a wrong number of values.
+    /// In that case, none of the values are added.
+    ///
+    /// ```
+    /// use scooby::postgres::insert_into;
+    ///
+    /// let sql = insert_into("Dummy")
+    ///     .columns_dyn(vec!["col1", "col2"])
+    ///     .values_dyn([vec!["$1", "$2"]])
+    ///     .and_then(|statement| statement.values_dyn([vec!["$3", "$4"]]))
+    ///     .unwrap()
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "INSERT INTO Dummy (col1, col2) VALUES ($1, $2), ($3, $4)");
+    /// ```
+    pub fn values_dyn<T: IntoIteratorOfSameType<Expression>>(
+        mut self,
+        values: impl IntoIterator<Item = T>,
+    ) -> Result<Self, DynamicValuesError> {
+        self.values.add(values)?;
+        Ok(self)
+    }
+
+    /// Add an `ON CONFLICT (...)` clause with a conflict target made of some of the inserted columns
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuildError::UnknownConflictColumn`] if any of the target columns is not inserted.
+    pub fn on_conflict_columns_subset(
+        self,
+        columns: impl IntoIteratorOfSameType<Column>,
+    ) -> Result<OnConflictClauseBuilder<DynamicColumns, WithConflictTarget>, BuildError> {
+        let inserted = self.values.columns().to_vec();
+        self.on_conflict_columns_within(&inserted, columns)
+    }
+}
+
 impl<V: Values> Display for InsertInto<V> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         if let Some(with_clause) = &self.with {
-            write!(f, "{} ", with_clause)?;
+            with_clause.fmt(f)?;
+            clause_break(f)?;
         }
 
-        write!(f, "INSERT INTO {} {}", self.table_name, self.values)?;
+        write!(f, "INSERT INTO {}", self.table_name)?;
 
-        if !self.returning.is_empty() {
-            write!(f, " RETURNING {}", joined(&self.returning, ", "))?;
-        }
+        self.values.fmt(f)?;
 
         if let Some(on_conflict_clause) = &self.on_conflict {
-            write!(f, " {}", on_conflict_clause)?;
+            clause_break(f)?;
+            write!(f, "{}", on_conflict_clause)?;
         }
 
+        if !self.returning.is_empty() {
+            write_clause(f, "RETURNING", &self.returning, ", ")?;
+        }
+
+        self.comments.fmt(f)?;
+
         Ok(())
     }
 }
@@ -325,6 +713,8 @@ mod tests {
     use crate::postgres::tools::tests::assert_correct_postgresql;
     use crate::postgres::{insert_into, select, with, Parameters};
 
+    use super::{BuildError, DynamicValuesError};
+
     #[test]
     fn default_values() {
         let sql = insert_into("Dummy").default_values().to_string();
@@ -347,16 +737,6 @@ mod tests {
         assert_correct_postgresql(&sql, "INSERT INTO Dummy VALUES (a, b), (c, d), (e, f)");
     }
 
-    // FIXME: This currently compiles and panics at runtime, but ideally should not even compile
-    #[test]
-    #[should_panic]
-    fn zero_length_columns() {
-        let values: [[String; 0]; 1] = [[]];
-        let sql = insert_into("Dummy").columns([]).values(values).to_string();
-
-        assert_correct_postgresql(&sql, "INSERT INTO Dummy () VALUES ");
-    }
-
     #[test]
     fn single_column() {
         let sql = insert_into("Dummy")
@@ -477,4 +857,232 @@ mod tests {
             "INSERT INTO Dummy VALUES (a) ON CONFLICT DO UPDATE SET col = 1",
         );
     }
+
+    #[test]
+    fn pretty() {
+        let sql = insert_into("Dummy")
+            .columns(("col1", "col2"))
+            .values([("a", "b"), ("c", "d")])
+            .returning("id")
+            .on_conflict()
+            .do_nothing();
+
+        assert_eq!(
+            format!("{:#}", sql),
+            "\
+INSERT INTO Dummy (col1, col2)
+VALUES
+    (a, b),
+    (c, d)
+ON CONFLICT DO NOTHING
+RETURNING
+    id"
+        );
+
+        let sql = insert_into("Dummy").default_values();
+
+        assert_eq!(format!("{:#}", sql), "INSERT INTO Dummy\nDEFAULT VALUES");
+    }
+
+    #[test]
+    fn comment() {
+        let sql = insert_into("Dummy")
+            .values(["a"])
+            .on_conflict()
+            .do_nothing()
+            .comment("application", "billing")
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "INSERT INTO Dummy VALUES (a) ON CONFLICT DO NOTHING /* application:billing */",
+        );
+    }
+
+    #[test]
+    fn wide_tuples() {
+        let sql = insert_into("Wide")
+            .columns((
+                "c1", "c2", "c3", "c4", "c5", "c6", "c7", "c8", "c9", "c10", "c11", "c12", "c13",
+                "c14", "c15", "c16",
+            ))
+            .values([(
+                "$1", "$2", "$3", "$4", "$5", "$6", "$7", "$8", "$9", "$10", "$11", "$12", "$13",
+                "$14", "$15", "$16",
+            )])
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "INSERT INTO Wide (c1, c2, c3, c4, c5, c6, c7, c8, c9, c10, c11, c12, c13, c14, c15, c16) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)",
+        );
+    }
+
+    #[test]
+    fn dynamic_columns() {
+        let columns: Vec<String> = vec!["col1".to_owned(), "col2".to_owned()];
+        let sql = insert_into("Dummy")
+            .columns_dyn(columns)
+            .values_dyn(vec![vec!["a", "b"], vec!["c", "d"]])
+            .unwrap()
+            .returning("id")
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "INSERT INTO Dummy (col1, col2) VALUES (a, b), (c, d) RETURNING id",
+        );
+    }
+
+    #[test]
+    fn dynamic_columns_errors() {
+        let no_columns: Vec<&str> = vec![];
+        let result = insert_into("Dummy")
+            .columns_dyn(no_columns)
+            .values_dyn([vec!["a"]]);
+        assert_eq!(result.unwrap_err(), DynamicValuesError::NoColumns);
+
+        let no_rows: Vec<Vec<&str>> = vec![];
+        let result = insert_into("Dummy").columns_dyn("col1").values_dyn(no_rows);
+        assert_eq!(result.unwrap_err(), DynamicValuesError::NoRows);
+
+        let result = insert_into("Dummy")
+            .columns_dyn("col1")
+            .values_dyn([vec!["a", "b"]]);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "row 0 has 2 values, but 1 columns were specified"
+        );
+    }
+
+    #[test]
+    fn set_map() {
+        let mut params = Parameters::new();
+        let fields = vec![("name", params.next()), ("age", params.next())];
+
+        let sql = insert_into("Dummy")
+            .set_map(fields)
+            .unwrap()
+            .on_conflict()
+            .do_nothing()
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "INSERT INTO Dummy (name, age) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+        );
+
+        let result = insert_into("Dummy").set_map(Vec::<(&str, &str)>::new());
+        assert_eq!(result.unwrap_err(), DynamicValuesError::NoColumns);
+    }
+
+    #[test]
+    fn upsert() {
+        let sql = insert_into("Dummy")
+            .columns(("id", "col1", "col2"))
+            .values([("$1", "$2", "$3")])
+            .on_conflict_columns("id")
+            .do_update_set_excluded()
+            .returning("id")
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "INSERT INTO Dummy (id, col1, col2) VALUES ($1, $2, $3) ON CONFLICT (id) DO UPDATE SET col1 = EXCLUDED.col1, col2 = EXCLUDED.col2 RETURNING id",
+        );
+    }
+
+    #[test]
+    fn upsert_only_conflict_columns() {
+        let sql = insert_into("Dummy")
+            .columns_dyn(vec!["a", "b"])
+            .values_dyn([vec!["$1", "$2"]])
+            .unwrap()
+            .on_conflict_columns(("a", "b"))
+            .do_update_set_excluded()
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "INSERT INTO Dummy (a, b) VALUES ($1, $2) ON CONFLICT (a, b) DO UPDATE SET a = EXCLUDED.a, b = EXCLUDED.b",
+        );
+    }
+
+    #[test]
+    fn on_conflict_before_returning() {
+        let sql = insert_into("Dummy")
+            .values(["a"])
+            .returning("id")
+            .on_conflict()
+            .do_nothing()
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "INSERT INTO Dummy VALUES (a) ON CONFLICT DO NOTHING RETURNING id",
+        );
+    }
+
+    #[test]
+    fn on_conflict_do_nothing_shortcut() {
+        let sql = insert_into("Dummy")
+            .default_values()
+            .on_conflict_do_nothing()
+            .returning("id")
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "INSERT INTO Dummy DEFAULT VALUES ON CONFLICT DO NOTHING RETURNING id",
+        );
+    }
+
+    #[test]
+    fn on_conflict_columns_subset() {
+        let sql = insert_into("Dummy")
+            .columns_dyn(vec!["a", "b", "c"])
+            .values_dyn([vec!["$1", "$2", "$3"]])
+            .unwrap()
+            .on_conflict_columns_subset(("a", "b"))
+            .unwrap()
+            .do_nothing()
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "INSERT INTO Dummy (a, b, c) VALUES ($1, $2, $3) ON CONFLICT (a, b) DO NOTHING",
+        );
+
+        let result = insert_into("Dummy")
+            .columns(("a", "b"))
+            .values([("$1", "$2")])
+            .on_conflict_columns_subset(("a", "c"));
+        assert_eq!(
+            result.err(),
+            Some(BuildError::UnknownConflictColumn {
+                column: "c".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn cloning() {
+        let with_columns = insert_into("Dummy")
+            .columns(("a", "b"))
+            .values([("$1", "$2")]);
+        let without_columns = insert_into("Dummy").values([("$1", "$2")]);
+        let default_values = insert_into("Dummy").default_values();
+        let dynamic_columns = insert_into("Dummy")
+            .columns_dyn(vec!["a", "b"])
+            .values_dyn([vec!["$1", "$2"]])
+            .unwrap();
+
+        assert_eq!(with_columns.clone(), with_columns);
+        assert_eq!(without_columns.clone(), without_columns);
+        assert_eq!(default_values.clone(), default_values);
+        assert_eq!(
+            dynamic_columns.clone().returning("id"),
+            dynamic_columns.returning("id")
+        );
+    }
 }
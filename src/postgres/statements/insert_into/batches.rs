@@ -0,0 +1,125 @@
+use crate::postgres::general::{Column, Expression, TableName, WithClause};
+use crate::postgres::tools::Parameters;
+use crate::tools::transform_array;
+
+use super::{InsertInto, WithColumns};
+
+/// Iterator over `INSERT INTO` statements for batches of rows, created with
+/// [`InsertIntoColumnsBuilder::batches`][super::InsertIntoColumnsBuilder::batches]
+///
+/// Yields each statement along with the rows it has placeholders for, in order.
+#[must_use = "Iterators are lazy, making batches without iterating over them is pointless"]
+#[derive(Debug)]
+pub struct InsertBatches<I: Iterator, const N: usize> {
+    table_name: TableName,
+    with: Option<WithClause>,
+    columns: [Column; N],
+    rows: I,
+    rows_per_batch: usize,
+}
+
+impl<I: Iterator, const N: usize> InsertBatches<I, N> {
+    pub(super) fn new(
+        table_name: TableName,
+        with: Option<WithClause>,
+        columns: [Column; N],
+        rows: I,
+        max_parameters: usize,
+    ) -> Self {
+        InsertBatches {
+            table_name,
+            with,
+            columns,
+            rows,
+            rows_per_batch: (max_parameters / N).max(1),
+        }
+    }
+}
+
+impl<I: Iterator, const N: usize> Iterator for InsertBatches<I, N> {
+    type Item = (InsertInto<WithColumns<N>>, Vec<I::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rows: Vec<I::Item> = self.rows.by_ref().take(self.rows_per_batch).collect();
+
+        if rows.is_empty() {
+            return None;
+        }
+
+        // Every statement is bound separately, so numbering starts over for each of them
+        let mut params = Parameters::new();
+        let values = rows
+            .iter()
+            .map(|_| transform_array(params.next_array::<N>(), Expression::from))
+            .collect();
+
+        let statement = InsertInto::new(
+            self.table_name.clone(),
+            WithColumns::new(self.columns.clone(), values),
+            self.with.clone(),
+        );
+
+        Some((statement, rows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{insert_into, Parameters};
+
+    #[test]
+    fn batches() {
+        let people = vec![
+            ("Alice", 30),
+            ("Bob", 25),
+            ("Carol", 41),
+            ("Dave", 19),
+            ("Eve", 33),
+        ];
+
+        let batches: Vec<_> = insert_into("Person")
+            .columns(("name", "age"))
+            .batches(people, 4)
+            .map(|(statement, rows)| (statement.returning("id").to_string(), rows))
+            .collect();
+
+        assert_eq!(batches.len(), 3);
+
+        assert_correct_postgresql(
+            &batches[0].0,
+            "INSERT INTO Person (name, age) VALUES ($1, $2), ($3, $4) RETURNING id",
+        );
+        assert_eq!(batches[0].1, [("Alice", 30), ("Bob", 25)]);
+
+        assert_correct_postgresql(
+            &batches[2].0,
+            "INSERT INTO Person (name, age) VALUES ($1, $2) RETURNING id",
+        );
+        assert_eq!(batches[2].1, [("Eve", 33)]);
+    }
+
+    #[test]
+    fn batches_at_limit() {
+        let rows_per_batch: Vec<usize> = insert_into("Reading")
+            .columns(("sensor_id", "taken_at", "value"))
+            .batches(0..50_000, Parameters::MAX)
+            .map(|(_, rows)| rows.len())
+            .collect();
+
+        assert_eq!(rows_per_batch, [21845, 21845, 6310]);
+    }
+
+    #[test]
+    fn no_rows() {
+        let rows: Vec<i32> = Vec::new();
+
+        assert_eq!(
+            insert_into("Tag")
+                .columns("name")
+                .batches(rows, 100)
+                .count(),
+            0
+        );
+    }
+}
@@ -1,22 +1,37 @@
 use std::fmt::{self, Display, Formatter};
+use std::marker::PhantomData;
 
-use crate::postgres::general::ColumnValuePair;
+use crate::postgres::general::{Column, ColumnValuePair};
 use crate::tools::{joined, IntoIteratorOfSameType};
 
-use super::{InsertInto, Values};
+use super::{DynamicColumns, InsertInto, Values, WithColumns};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct OnConflictClause {
+    target: Vec<Column>,
     action: ConflictAction,
 }
 
+impl OnConflictClause {
+    /// Check whether this clause updates rows without specifying which conflicts, which PostgreSQL rejects
+    pub(crate) fn is_missing_target(&self) -> bool {
+        self.target.is_empty() && matches!(self.action, ConflictAction::DoUpdateSet(_))
+    }
+}
+
 impl Display for OnConflictClause {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "ON CONFLICT {}", self.action)
+        write!(f, "ON CONFLICT")?;
+
+        if !self.target.is_empty() {
+            write!(f, " ({})", joined(&self.target, ", "))?;
+        }
+
+        write!(f, " {}", self.action)
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ConflictAction {
     DoNothing,
     DoUpdateSet(Vec<ColumnValuePair>),
@@ -35,15 +50,43 @@ impl Display for ConflictAction {
 ///
 /// - [`do_nothing`][OnConflictClauseBuilder::do_nothing] to add a `DO NOTHING` action
 /// - [`do_update_set`][OnConflictClauseBuilder::do_update_set] to add `DO UPDATE SET ...` action
-pub struct OnConflictClauseBuilder<V: Values> {
+/// - [`do_update_set_excluded`][OnConflictClauseBuilder::do_update_set_excluded] to update inserted columns with `EXCLUDED` values,
+///   only once there is a conflict target
+pub struct OnConflictClauseBuilder<V: Values, T = WithoutConflictTarget> {
     statement: InsertInto<V>,
+    target: Vec<Column>,
+    target_kind: PhantomData<T>,
 }
 
-impl<V: Values> OnConflictClauseBuilder<V> {
+/// Marker for an `ON CONFLICT` clause without a conflict target, created with [`InsertInto::on_conflict`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WithoutConflictTarget;
+
+/// Marker for an `ON CONFLICT (...)` clause with a conflict target, created with [`InsertInto::on_conflict_columns`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WithConflictTarget;
+
+impl<V: Values> OnConflictClauseBuilder<V, WithoutConflictTarget> {
     pub(crate) fn new(statement: InsertInto<V>) -> Self {
-        Self { statement }
+        Self {
+            statement,
+            target: Vec::new(),
+            target_kind: PhantomData,
+        }
     }
+}
+
+impl<V: Values> OnConflictClauseBuilder<V, WithConflictTarget> {
+    pub(crate) fn with_target(statement: InsertInto<V>, target: Vec<Column>) -> Self {
+        Self {
+            statement,
+            target,
+            target_kind: PhantomData,
+        }
+    }
+}
 
+impl<V: Values, T> OnConflictClauseBuilder<V, T> {
     /// Add a `DO NOTHING` action to this `ON CONFLICT` clause.
     ///
     /// Returns back to the [`InsertInto`] statement.
@@ -60,13 +103,7 @@ impl<V: Values> OnConflictClauseBuilder<V> {
     /// assert_eq!(sql, "INSERT INTO Dummy VALUES (a) ON CONFLICT DO NOTHING");
     /// ```
     pub fn do_nothing(self) -> InsertInto<V> {
-        let mut statement = self.statement;
-
-        statement.on_conflict = Some(OnConflictClause {
-            action: ConflictAction::DoNothing,
-        });
-
-        statement
+        self.with_action(ConflictAction::DoNothing)
     }
 
     /// Add a `DO UPDATE SET` action to this `ON CONFLICT` clause
@@ -88,14 +125,86 @@ impl<V: Values> OnConflictClauseBuilder<V> {
         self,
         pairs: impl IntoIteratorOfSameType<ColumnValuePair>,
     ) -> InsertInto<V> {
-        let mut statement = self.statement;
-
         let cols = pairs.into_some_iter().collect();
+        self.with_action(ConflictAction::DoUpdateSet(cols))
+    }
+
+    fn with_action(self, action: ConflictAction) -> InsertInto<V> {
+        let mut statement = self.statement;
 
         statement.on_conflict = Some(OnConflictClause {
-            action: ConflictAction::DoUpdateSet(cols),
+            target: self.target,
+            action,
         });
 
         statement
     }
+
+    fn with_excluded(self, columns: &[Column]) -> InsertInto<V> {
+        let mut updated: Vec<&Column> = columns
+            .iter()
+            .filter(|column| !self.target.contains(column))
+            .collect();
+
+        if updated.is_empty() {
+            updated = columns.iter().collect();
+        }
+
+        let pairs = updated
+            .into_iter()
+            .map(|column| ColumnValuePair::from((column.clone(), format!("EXCLUDED.{}", column))))
+            .collect();
+
+        self.with_action(ConflictAction::DoUpdateSet(pairs))
+    }
+}
+
+impl<const N: usize> OnConflictClauseBuilder<WithColumns<N>, WithConflictTarget> {
+    /// Add a `DO UPDATE SET` action setting all inserted columns, except for the conflict target, to their `EXCLUDED` values
+    ///
+    /// If all inserted columns are in the conflict target, all of them are set anyway, so that `RETURNING` still returns conflicting rows.
+    ///
+    /// PostgreSQL needs a conflict target to update rows, so this is only available after
+    /// [`on_conflict_columns`][InsertInto::on_conflict_columns], not after [`on_conflict`][InsertInto::on_conflict]:
+    ///
+    /// ```compile_fail
+    /// use scooby::postgres::insert_into;
+    ///
+    /// insert_into("Person")
+    ///     .columns(("id", "name"))
+    ///     .values([("$1", "$2")])
+    ///     .on_conflict()
+    ///     .do_update_set_excluded();
+    /// ```
+    ///
+    /// Returns back to the [`InsertInto`] statement.
+    ///
+    /// ```
+    /// use scooby::postgres::insert_into;
+    ///
+    /// let sql = insert_into("Person")
+    ///     .columns(("id", "name", "age"))
+    ///     .values([("$1", "$2", "$3")])
+    ///     .on_conflict_columns("id")
+    ///     .do_update_set_excluded()
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "INSERT INTO Person (id, name, age) VALUES ($1, $2, $3) ON CONFLICT (id) DO UPDATE SET name = EXCLUDED.name, age = EXCLUDED.age");
+    /// ```
+    pub fn do_update_set_excluded(self) -> InsertInto<WithColumns<N>> {
+        let columns = self.statement.values.columns().to_vec();
+        self.with_excluded(&columns)
+    }
+}
+
+impl OnConflictClauseBuilder<DynamicColumns, WithConflictTarget> {
+    /// Add a `DO UPDATE SET` action setting all inserted columns, except for the conflict target, to their `EXCLUDED` values
+    ///
+    /// If all inserted columns are in the conflict target, all of them are set anyway, so that `RETURNING` still returns conflicting rows.
+    ///
+    /// Returns back to the [`InsertInto`] statement.
+    pub fn do_update_set_excluded(self) -> InsertInto<DynamicColumns> {
+        let columns = self.statement.values.columns().to_vec();
+        self.with_excluded(&columns)
+    }
 }
@@ -1,7 +1,10 @@
+use std::error::Error;
 use std::fmt::{self, Display, Formatter};
 
 use crate::postgres::general::{Column, Expression};
-use crate::tools::{joined, IntoNonZeroArray};
+use crate::tools::{
+    clause_break, joined, parenthesized, write_clause, IntoIteratorOfSameType, IntoNonZeroArray,
+};
 
 /// Marker trait for implemenations of different kinds of `VALUES`
 /// clauses for `INSERT INTO` statements
@@ -9,18 +12,24 @@ use crate::tools::{joined, IntoNonZeroArray};
 /// You may not construct any of the implementations directly.
 ///
 /// Please use the appropriate methods on [`BareInsertInto`][crate::postgres::statements::BareInsertInto]
-pub trait Values: Display {}
+pub trait Values: Display {
+    /// Check whether there are no rows of values, which PostgreSQL would reject
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
 
 /// Default values, i.e. `INSERT INTO x DEFAULT VALUES`
 ///
 /// Constructing this directly is useless, please use [`BareInsertInto::default_values`][crate::postgres::statements::BareInsertInto::default_values]
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DefaultValues;
 
 impl Values for DefaultValues {}
 
 impl Display for DefaultValues {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        clause_break(f)?;
         write!(f, "DEFAULT VALUES")
     }
 }
@@ -28,7 +37,7 @@ impl Display for DefaultValues {
 /// Values without columns, i.e. `INSERT INTO x VALUES (1, 2)`
 ///
 /// You may not construct this directly, please use [`BareInsertInto::values`][crate::postgres::statements::BareInsertInto::values]
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct WithoutColumns<const N: usize> {
     values: Vec<[Expression; N]>,
 }
@@ -47,19 +56,21 @@ impl<const N: usize> WithoutColumns<N> {
     }
 }
 
-impl<const N: usize> Values for WithoutColumns<N> {}
+impl<const N: usize> Values for WithoutColumns<N> {
+    fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
 
 impl<const N: usize> Display for WithoutColumns<N> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(
+        write_clause(
             f,
-            "VALUES {}",
-            joined(
-                self.values
-                    .iter()
-                    .map(|cols| format!("({})", joined(cols, ", "))),
-                ", "
-            )
+            "VALUES",
+            self.values
+                .iter()
+                .map(|cols| parenthesized(joined(cols, ", "))),
+            ", ",
         )
     }
 }
@@ -67,7 +78,7 @@ impl<const N: usize> Display for WithoutColumns<N> {
 /// Values with specified columns, i.e. `INSERT INTO x (col1, col2) VALUES (1, 2)`
 ///
 /// You may not construct this directly, please use [`BareInsertInto::columns`][crate::postgres::statements::BareInsertInto::columns]
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct WithColumns<const N: usize> {
     columns: [Column; N],
     values: Vec<[Expression; N]>,
@@ -78,6 +89,10 @@ impl<const N: usize> WithColumns<N> {
         WithColumns { columns, values }
     }
 
+    pub(crate) fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
     pub(crate) fn add<T: IntoNonZeroArray<Expression, N>>(
         &mut self,
         iter: impl IntoIterator<Item = T>,
@@ -87,20 +102,131 @@ impl<const N: usize> WithColumns<N> {
     }
 }
 
-impl<const N: usize> Values for WithColumns<N> {}
+impl<const N: usize> Values for WithColumns<N> {
+    fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
 
 impl<const N: usize> Display for WithColumns<N> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(
+        write!(f, " ({})", joined(&self.columns, ", "))?;
+        write_clause(
+            f,
+            "VALUES",
+            self.values
+                .iter()
+                .map(|cols| parenthesized(joined(cols, ", "))),
+            ", ",
+        )
+    }
+}
+
+/// Values with columns only known at runtime, i.e. `INSERT INTO x (col1, col2) VALUES (1, 2)`
+///
+/// You may not construct this directly, please use [`BareInsertInto::columns_dyn`][crate::postgres::statements::BareInsertInto::columns_dyn]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DynamicColumns {
+    columns: Vec<Column>,
+    values: Vec<Vec<Expression>>,
+}
+
+impl DynamicColumns {
+    pub(crate) fn new(columns: Vec<Column>) -> Self {
+        DynamicColumns {
+            columns,
+            values: Vec::new(),
+        }
+    }
+
+    pub(crate) fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
+    /// Add rows of values, only if all of them have as many values as there are columns
+    pub(crate) fn add<T: IntoIteratorOfSameType<Expression>>(
+        &mut self,
+        iter: impl IntoIterator<Item = T>,
+    ) -> Result<(), DynamicValuesError> {
+        if self.columns.is_empty() {
+            return Err(DynamicValuesError::NoColumns);
+        }
+
+        let rows: Vec<Vec<Expression>> = iter
+            .into_iter()
+            .map(|row| row.into_some_iter().collect())
+            .collect();
+
+        if rows.is_empty() {
+            return Err(DynamicValuesError::NoRows);
+        }
+
+        for (row, values) in rows.iter().enumerate() {
+            if values.len() != self.columns.len() {
+                return Err(DynamicValuesError::WrongWidth {
+                    row,
+                    expected: self.columns.len(),
+                    found: values.len(),
+                });
+            }
+        }
+
+        self.values.extend(rows);
+        Ok(())
+    }
+}
+
+impl Values for DynamicColumns {
+    fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl Display for DynamicColumns {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, " ({})", joined(&self.columns, ", "))?;
+        write_clause(
             f,
-            "({}) VALUES {}",
-            joined(&self.columns, ", "),
-            joined(
-                self.values
-                    .iter()
-                    .map(|cols| format!("({})", joined(cols, ", "))),
-                ", "
-            )
+            "VALUES",
+            self.values
+                .iter()
+                .map(|cols| parenthesized(joined(cols, ", "))),
+            ", ",
         )
     }
 }
+
+/// Error of adding values with runtime-checked width to an `INSERT INTO` statement
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DynamicValuesError {
+    /// No columns were specified
+    NoColumns,
+    /// No rows of values were given
+    NoRows,
+    /// A row, counted from zero among the given rows, has a different number of values than there are columns
+    WrongWidth {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl Display for DynamicValuesError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DynamicValuesError::NoColumns => write!(f, "no columns specified for INSERT INTO"),
+            DynamicValuesError::NoRows => write!(f, "no rows of values given for INSERT INTO"),
+            DynamicValuesError::WrongWidth {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {} has {} values, but {} columns were specified",
+                row, found, expected
+            ),
+        }
+    }
+}
+
+impl Error for DynamicValuesError {}
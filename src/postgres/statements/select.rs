@@ -4,19 +4,30 @@ mod join;
 mod limit;
 mod offset;
 mod order_by;
+mod seek;
+mod sort_spec;
 
 use std::default::Default;
 use std::fmt::{self, Display, Formatter};
+use std::iter::{once, Once};
 
-use crate::postgres::general::{Condition, Expression, WithClause};
-use crate::tools::{joined, IntoIteratorOfSameType};
+use crate::postgres::general::{
+    count_all, exists, sanitize_comment, Aliasable, Comment, Comments, Condition, Expression,
+    Qualifiable, TableName, WithClause,
+};
+use crate::postgres::tools::Parameters;
+use crate::tools::{clause_break, joined, listed, write_clause, ClauseVec, IntoIteratorOfSameType};
+
+use super::BuildError;
 
 pub use distinct::Distinct;
 pub use from_item::FromItem;
 pub use join::Joinable;
 pub use limit::Limit;
 pub use offset::Offset;
-pub use order_by::{OrderBy, Orderable};
+pub use order_by::{Direction, OrderBy, Orderable};
+pub use seek::SeekKey;
+pub use sort_spec::{SortSpec, UnknownSortField};
 
 /// Create a new `SELECT` statement with given expressions.
 ///
@@ -82,6 +93,21 @@ pub fn select(expressions: impl IntoIteratorOfSameType<Expression>) -> Select {
     }
 }
 
+/// Create a new `SELECT` statement with all columns of the given table, `SELECT t.*`
+///
+/// ```
+/// use scooby::postgres::{select_all_of, Aliasable, Joinable};
+///
+/// let sql = select_all_of("p")
+///     .from("Person".as_("p").inner_join("Pet").on("Pet.owner_id = p.id"))
+///     .to_string();
+///
+/// assert_eq!(sql, "SELECT p.* FROM Person AS p INNER JOIN Pet ON Pet.owner_id = p.id");
+/// ```
+pub fn select_all_of(table_name: impl Into<TableName>) -> Select {
+    select(table_name.star())
+}
+
 pub(crate) fn select_with(expressions: Vec<Expression>, with_clause: WithClause) -> Select {
     Select {
         expressions,
@@ -115,19 +141,44 @@ pub fn from(from: impl IntoIteratorOfSameType<FromItem>) -> FromSelectBuilder {
 /// Finalize and turn into `String` by calling `to_string`.
 ///
 /// See [`select`] docs for more details and examples.
+///
+/// Use alternate formatting (`{:#}`) to render it on multiple lines, one clause per line, for debugging.
+///
+/// ```
+/// use scooby::postgres::{select, Joinable};
+///
+/// let sql = select(("p.name", "c.name"))
+///     .from("Person p".inner_join("City c").on("c.id = p.city_id"))
+///     .where_(("p.age > 18", "c.population > 1000000"))
+///     .limit(10);
+///
+/// assert_eq!(format!("{:#}", sql), "\
+/// SELECT
+///     p.name,
+///     c.name
+/// FROM
+///     Person p
+///     INNER JOIN City c ON c.id = p.city_id
+/// WHERE
+///     p.age > 18 AND
+///     c.population > 1000000
+/// LIMIT 10");
+/// ```
 #[must_use = "Making a SELECT statement without using it is pointless"]
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Select {
-    with: Option<WithClause>,
+    pub(super) with: Option<WithClause>,
     expressions: Vec<Expression>,
-    from: Vec<FromItem>,
-    where_: Vec<Condition>,
-    group_by: Vec<Expression>,
-    having: Vec<Condition>,
-    order_by: Vec<OrderBy>,
+    from: ClauseVec<FromItem>,
+    where_: ClauseVec<Condition>,
+    group_by: ClauseVec<Expression>,
+    having: ClauseVec<Condition>,
+    order_by: ClauseVec<OrderBy>,
     limit: Option<Limit>,
     offset: Option<Offset>,
     distinct: Option<Distinct>,
+    comments: Comments,
+    hints: Vec<String>,
 }
 
 impl Select {
@@ -245,6 +296,27 @@ impl Select {
         self
     }
 
+    /// Add a `column = $n` condition with the next placeholder from `params`, `AND`'ed with existing conditions.
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Parameters};
+    ///
+    /// let mut params = Parameters::new();
+    ///
+    /// let sql = select("*")
+    ///     .from("Person")
+    ///     .where_eq("name", &mut params)
+    ///     .where_eq("age", &mut params)
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Person WHERE name = $1 AND age = $2");
+    /// ```
+    pub fn where_eq(mut self, column: impl Into<Expression>, params: &mut Parameters) -> Self {
+        self.where_
+            .push(format!("{} = {}", column.into(), params.next()));
+        self
+    }
+
     /// Add one or more expressions in a `GROUP BY` clause
     ///
     /// ```
@@ -328,12 +400,410 @@ impl Select {
         self.offset = Some(offset.into());
         self
     }
+
+    /// Set a `LIMIT` clause if there is a limit, or leave the statement as it is otherwise
+    ///
+    /// ```
+    /// use scooby::postgres::select;
+    ///
+    /// let sql = select("*").from("City").limit_opt(Some(10)).to_string();
+    /// assert_eq!(sql, "SELECT * FROM City LIMIT 10");
+    ///
+    /// let sql = select("*").from("City").limit_opt(None).to_string();
+    /// assert_eq!(sql, "SELECT * FROM City");
+    /// ```
+    pub fn limit_opt(self, limit: Option<usize>) -> Self {
+        match limit {
+            Some(limit) => self.limit(limit),
+            None => self,
+        }
+    }
+
+    /// Set an `OFFSET` clause if there is an offset, or leave the statement as it is otherwise
+    ///
+    /// ```
+    /// use scooby::postgres::select;
+    ///
+    /// let sql = select("*").from("City").offset_opt(Some(10)).to_string();
+    /// assert_eq!(sql, "SELECT * FROM City OFFSET 10");
+    ///
+    /// let sql = select("*").from("City").offset_opt(None).to_string();
+    /// assert_eq!(sql, "SELECT * FROM City");
+    /// ```
+    pub fn offset_opt(self, offset: Option<usize>) -> Self {
+        match offset {
+            Some(offset) => self.offset(offset),
+            None => self,
+        }
+    }
+
+    /// Set `LIMIT` and `OFFSET` clauses to get a page of results, counting pages from 1
+    ///
+    /// Page 0 is treated as the first page, and values that don't fit into PostgreSQL's
+    /// `bigint` are capped instead of overflowing.
+    ///
+    /// ```
+    /// use scooby::postgres::select;
+    /// let sql = select("*").from("City").paginate(3, 20).to_string();
+    /// assert_eq!(sql, "SELECT * FROM City LIMIT 20 OFFSET 40");
+    /// ```
+    pub fn paginate(self, page: usize, per_page: usize) -> Self {
+        let max = i64::MAX as usize;
+        let offset = page.saturating_sub(1).saturating_mul(per_page);
+
+        self.limit(per_page.min(max)).offset(offset.min(max))
+    }
+
+    /// Remove all `WHERE` conditions added so far
+    ///
+    /// Handy to derive a variant of a base query, together with other `clear_*` and `without_*` methods.
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Orderable};
+    ///
+    /// let base = select("*").from("City").where_("population > $1").order_by("name".asc()).limit(10);
+    ///
+    /// let sql = base.clone().clear_where().clear_order_by().without_limit().to_string();
+    /// assert_eq!(sql, "SELECT * FROM City");
+    /// ```
+    pub fn clear_where(mut self) -> Self {
+        self.where_.clear();
+        self
+    }
+
+    /// Remove all `GROUP BY` expressions added so far
+    pub fn clear_group_by(mut self) -> Self {
+        self.group_by.clear();
+        self
+    }
+
+    /// Remove all `HAVING` conditions added so far
+    pub fn clear_having(mut self) -> Self {
+        self.having.clear();
+        self
+    }
+
+    /// Remove all `ORDER BY` expressions added so far
+    pub fn clear_order_by(mut self) -> Self {
+        self.order_by.clear();
+        self
+    }
+
+    /// Remove the `LIMIT` clause
+    pub fn without_limit(mut self) -> Self {
+        self.limit = None;
+        self
+    }
+
+    /// Remove the `OFFSET` clause
+    pub fn without_offset(mut self) -> Self {
+        self.offset = None;
+        self
+    }
+
+    /// Annotate this statement with a `key:value` pair, e.g. to identify its origin in `pg_stat_activity`.
+    ///
+    /// All annotations are rendered together in a single trailing `/* ... */` comment.
+    /// Anything that would end the comment early is broken up, so arbitrary values are safe to use.
+    ///
+    /// ```
+    /// use scooby::postgres::select;
+    ///
+    /// let sql = select("*")
+    ///     .from("Invoice")
+    ///     .comment("application", "billing")
+    ///     .comment("trace_id", "5b8aa5a2")
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Invoice /* application:billing,trace_id:5b8aa5a2 */");
+    /// ```
+    pub fn comment(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.comments.push(Comment::new(key, value));
+        self
+    }
+
+    /// Add one or more planner hints, rendered in a `/*+ ... */` block at the very start of the statement,
+    /// where the [pg_hint_plan](https://github.com/ossc-db/pg_hint_plan) extension expects them.
+    ///
+    /// ```
+    /// use scooby::postgres::{select, with};
+    ///
+    /// let sql = select("*")
+    ///     .from("Invoice")
+    ///     .where_("customer_id = $1")
+    ///     .hint("IndexScan(Invoice invoice_customer_id_idx)")
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "/*+ IndexScan(Invoice invoice_customer_id_idx) */ SELECT * FROM Invoice WHERE customer_id = $1");
+    ///
+    /// let sql = with("recent")
+    ///     .as_(select("*").from("Invoice").where_("created_at > now() - interval '1 day'"))
+    ///     .select("*")
+    ///     .from("recent")
+    ///     .hint(("SeqScan(Invoice)", "Parallel(Invoice 4)"))
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "/*+ SeqScan(Invoice) Parallel(Invoice 4) */ WITH recent AS (SELECT * FROM Invoice WHERE created_at > now() - interval '1 day') SELECT * FROM recent");
+    /// ```
+    pub fn hint(mut self, hints: impl IntoIteratorOfSameType<String>) -> Self {
+        self.hints.extend(hints.into_some_iter());
+        self
+    }
+
+    /// Get expressions of the `SELECT` clause added so far
+    ///
+    /// ```
+    /// use scooby::postgres::select;
+    ///
+    /// let query = select(("id", "name")).from("Person");
+    ///
+    /// let expressions: Vec<String> = query.selected_expressions().iter().map(|e| e.to_string()).collect();
+    /// assert_eq!(expressions, ["id", "name"]);
+    /// ```
+    pub fn selected_expressions(&self) -> &[Expression] {
+        &self.expressions
+    }
+
+    /// Get items of the `FROM` clause added so far
+    ///
+    /// ```
+    /// use scooby::postgres::select;
+    ///
+    /// let query = select("*").from(("Person", "City"));
+    ///
+    /// assert_eq!(query.from_items().len(), 2);
+    /// assert_eq!(query.from_items()[1].to_string(), "City");
+    /// ```
+    pub fn from_items(&self) -> &[FromItem] {
+        &self.from
+    }
+
+    /// Get conditions of the `WHERE` clause added so far
+    ///
+    /// ```
+    /// use scooby::postgres::select;
+    ///
+    /// let query = select("*").from("Person").where_(("age > 18", "name IS NOT NULL"));
+    ///
+    /// assert_eq!(query.where_conditions(), ["age > 18", "name IS NOT NULL"]);
+    /// ```
+    pub fn where_conditions(&self) -> &[Condition] {
+        &self.where_
+    }
+
+    /// Check whether this statement has a `WHERE` clause
+    ///
+    /// ```
+    /// use scooby::postgres::select;
+    ///
+    /// let query = select("*").from("Person");
+    /// assert!(!query.has_where());
+    ///
+    /// let query = query.where_("age > 18");
+    /// assert!(query.has_where());
+    /// ```
+    pub fn has_where(&self) -> bool {
+        !self.where_.is_empty()
+    }
+
+    /// Get items of the `ORDER BY` clause added so far
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Orderable};
+    ///
+    /// let query = select("*").from("Person").order_by(("age".desc(), "name"));
+    ///
+    /// assert_eq!(query.order_by_items()[0].to_string(), "age DESC");
+    /// ```
+    pub fn order_by_items(&self) -> &[OrderBy] {
+        &self.order_by
+    }
+
+    /// Get the `LIMIT` of this statement, if it was set
+    ///
+    /// ```
+    /// use scooby::postgres::select;
+    ///
+    /// let query = select("*").from("Person").limit(10);
+    ///
+    /// assert_eq!(query.current_limit().map(|l| l.to_string()), Some("10".to_owned()));
+    /// ```
+    pub fn current_limit(&self) -> Option<&Limit> {
+        self.limit.as_ref()
+    }
+
+    /// Get the `OFFSET` of this statement, if it was set
+    ///
+    /// ```
+    /// use scooby::postgres::select;
+    ///
+    /// let query = select("*").from("Person");
+    ///
+    /// assert!(query.current_offset().is_none());
+    /// ```
+    pub fn current_offset(&self) -> Option<&Offset> {
+        self.offset.as_ref()
+    }
+
+    /// Get rows following the last seen one, for keyset (cursor-based) pagination
+    ///
+    /// Adds an `ORDER BY` clause sorting by all keys, and a `WHERE` condition selecting rows that
+    /// come after the given values in that order. Keys should be `NOT NULL`, and together unique.
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Direction};
+    ///
+    /// let sql = select("*")
+    ///     .from("Post")
+    ///     .seek([("created_at", Direction::Desc, "$1"), ("id", Direction::Desc, "$2")])
+    ///     .limit(20)
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Post WHERE (created_at, id) < ($1, $2) ORDER BY created_at DESC, id DESC LIMIT 20");
+    /// ```
+    ///
+    /// Keys sorted in different directions are supported as well:
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Direction};
+    ///
+    /// let sql = select("*")
+    ///     .from("Post")
+    ///     .seek([("likes", Direction::Desc, "$1"), ("id", Direction::Asc, "$2")])
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Post WHERE (likes < $1 OR (likes = $1 AND id > $2)) ORDER BY likes DESC, id ASC");
+    /// ```
+    pub fn seek(self, keys: impl IntoIteratorOfSameType<SeekKey>) -> Self {
+        self.seek_in_direction(keys, false)
+    }
+
+    /// Get rows preceding the first seen one, for keyset (cursor-based) pagination backwards
+    ///
+    /// Same as [`seek`][Select::seek], but sorts in the opposite order, so rows closest to the
+    /// cursor come first. Reverse fetched rows to get them in the order of keys.
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Direction};
+    ///
+    /// let sql = select("*")
+    ///     .from("Post")
+    ///     .seek_before([("created_at", Direction::Desc, "$1"), ("id", Direction::Desc, "$2")])
+    ///     .limit(20)
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "SELECT * FROM Post WHERE (created_at, id) > ($1, $2) ORDER BY created_at ASC, id ASC LIMIT 20");
+    /// ```
+    pub fn seek_before(self, keys: impl IntoIteratorOfSameType<SeekKey>) -> Self {
+        self.seek_in_direction(keys, true)
+    }
+
+    fn seek_in_direction(
+        mut self,
+        keys: impl IntoIteratorOfSameType<SeekKey>,
+        backward: bool,
+    ) -> Self {
+        let keys: Vec<SeekKey> = keys.into_some_iter().collect();
+
+        self.where_.extend(seek::seek_condition(&keys, backward));
+        self.order_by.extend(seek::seek_order_by(&keys, backward));
+        self
+    }
+
+    /// Make a statement counting all rows this one would return, ignoring `ORDER BY`, `LIMIT` and `OFFSET`
+    ///
+    /// Handy to get the total number of rows alongside a [paginated][Select::paginate] query.
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Orderable};
+    ///
+    /// let query = select("*").from("City").where_("population > $1").order_by("name".asc()).paginate(2, 10);
+    ///
+    /// assert_eq!(
+    ///     query.to_count().to_string(),
+    ///     "SELECT count(*) FROM (SELECT * FROM City WHERE population > $1) AS sub",
+    /// );
+    /// ```
+    pub fn to_count(&self) -> Select {
+        let query = self
+            .clone()
+            .clear_order_by()
+            .without_limit()
+            .without_offset();
+
+        select(count_all()).from(query.as_("sub"))
+    }
+
+    /// Make a statement checking whether this one would return any rows, `SELECT EXISTS (...)` style
+    ///
+    /// `ORDER BY` is dropped, as it never affects the result. `LIMIT` and `OFFSET` are kept,
+    /// as they do: `OFFSET 10` of a query returning 5 rows returns nothing.
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Orderable};
+    ///
+    /// let query = select("*").from("City").where_("population > $1").order_by("name".asc());
+    ///
+    /// assert_eq!(
+    ///     query.to_exists().to_string(),
+    ///     "SELECT EXISTS (SELECT * FROM City WHERE population > $1)",
+    /// );
+    /// ```
+    pub fn to_exists(&self) -> Select {
+        select(exists(self.clone().clear_order_by()))
+    }
+
+    /// Check this statement for mistakes that PostgreSQL would reject it for
+    ///
+    /// Currently checks that leading `ORDER BY` expressions match `DISTINCT ON` expressions, in any order and possibly
+    /// repeated, until all of them have appeared. Expressions are compared as they are written, so `"id"` and `"City.id"`
+    /// are considered different.
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Orderable};
+    /// use scooby::postgres::statements::BuildError;
+    ///
+    /// let query = select("*").distinct_on("city").from("Person").order_by(("city", "age".desc()));
+    /// assert_eq!(query.verify(), Ok(()));
+    ///
+    /// let query = select("*").distinct_on("city").from("Person").order_by("age".desc());
+    /// assert_eq!(query.verify(), Err(BuildError::DistinctOnMismatch { order_by: "age".to_owned() }));
+    /// ```
+    pub fn verify(&self) -> Result<(), BuildError> {
+        if let Some(Distinct::DistinctOn(expressions)) = &self.distinct {
+            let distinct_on: Vec<String> = expressions.iter().map(|e| e.to_string()).collect();
+            let mut remaining = distinct_on.clone();
+
+            for order_by in &self.order_by {
+                if remaining.is_empty() {
+                    break;
+                }
+
+                if !distinct_on.iter().any(|e| e == order_by.expression()) {
+                    return Err(BuildError::DistinctOnMismatch {
+                        order_by: order_by.expression().to_owned(),
+                    });
+                }
+
+                remaining.retain(|e| e != order_by.expression());
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl Display for Select {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if !self.hints.is_empty() {
+            let hints = self.hints.iter().map(|hint| sanitize_comment(hint));
+            write!(f, "/*+ {} */", joined(hints, " "))?;
+            clause_break(f)?;
+        }
+
         if let Some(with_clause) = &self.with {
-            write!(f, "{} ", with_clause)?;
+            with_clause.fmt(f)?;
+            clause_break(f)?;
         }
 
         write!(f, "SELECT")?;
@@ -343,37 +813,41 @@ impl Display for Select {
         }
 
         if !self.expressions.is_empty() {
-            write!(f, " {}", joined(&self.expressions, ", "))?;
+            listed(&self.expressions, ", ").fmt(f)?;
         }
 
         if !self.from.is_empty() {
-            write!(f, " FROM {}", joined(&self.from, ", "))?;
+            write_clause(f, "FROM", &self.from, ", ")?;
         }
 
         if !self.where_.is_empty() {
-            write!(f, " WHERE {}", joined(&self.where_, " AND "))?;
+            write_clause(f, "WHERE", &self.where_, " AND ")?;
         }
 
         if !self.group_by.is_empty() {
-            write!(f, " GROUP BY {}", joined(&self.group_by, ", "))?;
+            write_clause(f, "GROUP BY", &self.group_by, ", ")?;
         }
 
         if !self.having.is_empty() {
-            write!(f, " HAVING {}", joined(&self.having, " AND "))?;
+            write_clause(f, "HAVING", &self.having, " AND ")?;
         }
 
         if !self.order_by.is_empty() {
-            write!(f, " ORDER BY {}", joined(&self.order_by, ", "))?;
+            write_clause(f, "ORDER BY", &self.order_by, ", ")?;
         }
 
         if let Some(ref limit) = self.limit {
-            write!(f, " LIMIT {}", limit)?;
+            clause_break(f)?;
+            write!(f, "LIMIT {}", limit)?;
         }
 
         if let Some(ref offset) = self.offset {
-            write!(f, " OFFSET {}", offset)?;
+            clause_break(f)?;
+            write!(f, "OFFSET {}", offset)?;
         }
 
+        self.comments.fmt(f)?;
+
         Ok(())
     }
 }
@@ -384,7 +858,7 @@ impl Display for Select {
 #[must_use = "Making a FromSelectBuilder struct without using it is pointless"]
 #[derive(Debug)]
 pub struct FromSelectBuilder {
-    from: Vec<FromItem>,
+    from: ClauseVec<FromItem>,
 }
 
 impl FromSelectBuilder {
@@ -406,10 +880,47 @@ impl FromSelectBuilder {
     }
 }
 
+/* Conversions */
+
+/// Use as a parenthesized subquery wherever an expression is expected
+///
+/// ```
+/// use scooby::postgres::{select, Comparable};
+///
+/// let sql = select(("name", select("count(*)").from("Post").where_("author_id = Person.id")))
+///     .from("Person")
+///     .where_("region".in_(select("region").from("TopRegion")))
+///     .to_string();
+///
+/// assert_eq!(sql, "SELECT name, (SELECT count(*) FROM Post WHERE author_id = Person.id) FROM Person WHERE region IN (SELECT region FROM TopRegion)");
+/// ```
+impl From<Select> for Expression {
+    fn from(select: Select) -> Self {
+        Expression::from(format!("({})", select))
+    }
+}
+
+impl<T> IntoIteratorOfSameType<T> for Select
+where
+    T: From<Select>,
+{
+    type Iterator = Once<T>;
+
+    fn into_some_iter(self) -> Self::Iterator {
+        once(self.into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::postgres::general::Expression;
+    use crate::postgres::statements::BuildError;
+    use crate::postgres::tools::items;
     use crate::postgres::tools::tests::assert_correct_postgresql;
-    use crate::postgres::{from, select, with, Aliasable, Joinable, Orderable};
+    use crate::postgres::{
+        from, select, with, Aliasable, Collatable, Comparable, Direction, Joinable, Orderable,
+        Parameters,
+    };
 
     #[test]
     fn bare() {
@@ -442,11 +953,59 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::needless_borrows_for_generic_args)]
     fn slice_of_columns() {
         let sql = select(&["id", "name"]).from("Person").to_string();
         assert_correct_postgresql(&sql, "SELECT id, name FROM Person")
     }
 
+    #[test]
+    fn vec_of_columns() {
+        let columns: Vec<String> = vec!["id".to_owned(), "name".to_owned()];
+        let sql = select(columns).from("Person").to_string();
+        assert_correct_postgresql(&sql, "SELECT id, name FROM Person")
+    }
+
+    #[test]
+    fn collected_conditions() {
+        let filters = [("age", "$1"), ("name", "$2")];
+        let sql = select("*")
+            .from("Person")
+            .where_(
+                filters
+                    .iter()
+                    .map(|(column, value)| format!("{} = {}", column, value))
+                    .collect::<Vec<_>>(),
+            )
+            .to_string();
+        assert_correct_postgresql(&sql, "SELECT * FROM Person WHERE age = $1 AND name = $2")
+    }
+
+    #[test]
+    fn wide_tuple_of_columns() {
+        let sql = select((
+            "c1", "c2", "c3", "c4", "c5", "c6", "c7", "c8", "c9", "c10", "c11", "c12", "c13",
+            "c14", "c15", "c16", "c17", "c18", "c19", "c20", "c21", "c22", "c23", "c24",
+        ))
+        .from("Wide")
+        .to_string();
+        assert_correct_postgresql(&sql, "SELECT c1, c2, c3, c4, c5, c6, c7, c8, c9, c10, c11, c12, c13, c14, c15, c16, c17, c18, c19, c20, c21, c22, c23, c24 FROM Wide")
+    }
+
+    #[test]
+    fn mapped_conditions() {
+        let filters = [("age", "$1"), ("name", "$2")];
+        let sql = select("*")
+            .from("Person")
+            .where_(items(
+                filters
+                    .iter()
+                    .map(|(column, value)| format!("{} = {}", column, value)),
+            ))
+            .to_string();
+        assert_correct_postgresql(&sql, "SELECT * FROM Person WHERE age = $1 AND name = $2")
+    }
+
     #[test]
     fn array_of_columns() {
         let sql = select(["id", "name"]).from("Person").to_string();
@@ -492,6 +1051,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::needless_borrows_for_generic_args)]
     fn from_tuple_of_tables() {
         let sql = select(&["p.name", "c.name", "d.name"])
             .from(("Person p", "City c", "District d"))
@@ -649,6 +1209,19 @@ mod tests {
         assert_correct_postgresql(&sql, "SELECT * FROM City ORDER BY id DESC");
     }
 
+    #[test]
+    fn order_by_collated() {
+        let sql = select("*")
+            .from("City")
+            .order_by(("name".collate("C").desc().nulls_last(), "id".collate("C")))
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            r#"SELECT * FROM City ORDER BY name COLLATE "C" DESC NULLS LAST, id COLLATE "C""#,
+        );
+    }
+
     #[test]
     fn limit() {
         let sql = select("whatever").from("SomeTable").limit(5).to_string();
@@ -661,6 +1234,27 @@ mod tests {
         assert_correct_postgresql(&sql, "SELECT whatever FROM SomeTable OFFSET 5");
     }
 
+    #[test]
+    fn cleared_clauses() {
+        let sql = select(("country", "name"))
+            .from("City")
+            .where_("population > 1000")
+            .group_by(("country", "name"))
+            .having("count(*) > 1")
+            .order_by("country")
+            .limit(10)
+            .offset(5)
+            .clear_group_by()
+            .clear_having()
+            .without_offset()
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "SELECT country, name FROM City WHERE population > 1000 ORDER BY country LIMIT 10",
+        );
+    }
+
     #[test]
     fn limit_with_offset() {
         let sql = select("whatever")
@@ -672,6 +1266,17 @@ mod tests {
         assert_correct_postgresql(&sql, "SELECT whatever FROM SomeTable LIMIT 10 OFFSET 5");
     }
 
+    #[test]
+    fn optional_limit_and_offset() {
+        let sql = select("whatever")
+            .from("SomeTable")
+            .limit_opt(None)
+            .offset_opt(Some(5))
+            .to_string();
+
+        assert_correct_postgresql(&sql, "SELECT whatever FROM SomeTable OFFSET 5");
+    }
+
     #[test]
     fn with_select() {
         let sql = with("thing")
@@ -754,6 +1359,19 @@ mod tests {
         assert_correct_postgresql(&sql, "SELECT x, y FROM Points");
     }
 
+    #[test]
+    fn collated_expression() {
+        let sql = select("name")
+            .from("Person")
+            .where_(format!("{} < $1", "name".collate("C")))
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            r#"SELECT name FROM Person WHERE name COLLATE "C" < $1"#,
+        );
+    }
+
     #[test]
     fn limit_with_parameter() {
         let sql = select("1 + 1").limit("$1").to_string();
@@ -767,4 +1385,295 @@ mod tests {
 
         assert_correct_postgresql(&sql, "SELECT 1 + 1 OFFSET $1");
     }
+
+    #[test]
+    fn pretty() {
+        let sql = with("thing")
+            .as_(select("1 + 1"))
+            .select(("country.name".as_("name"), "COUNT(*)".as_("count")))
+            .distinct()
+            .from((
+                "Country"
+                    .as_("country")
+                    .inner_join("City".as_("city"))
+                    .on("city.country_id = country.id")
+                    .left_join("thing")
+                    .on("true"),
+                "Other",
+            ))
+            .where_(("city.population > 1000000", "country.id > 5"))
+            .group_by("country.name")
+            .having("COUNT(*) > 1")
+            .order_by(("count".desc(), "name"))
+            .limit(10)
+            .offset(5);
+
+        assert_eq!(
+            format!("{:#}", sql),
+            "\
+WITH
+    thing AS (SELECT 1 + 1)
+SELECT DISTINCT
+    country.name AS name,
+    COUNT(*) AS count
+FROM
+    Country AS country
+    INNER JOIN City AS city ON city.country_id = country.id
+    LEFT JOIN thing ON true,
+    Other
+WHERE
+    city.population > 1000000 AND
+    country.id > 5
+GROUP BY
+    country.name
+HAVING
+    COUNT(*) > 1
+ORDER BY
+    count DESC,
+    name
+LIMIT 10
+OFFSET 5"
+        );
+        assert_correct_postgresql(&format!("{:#}", sql), &format!("{:#}", sql));
+    }
+
+    #[test]
+    fn comment() {
+        let sql = select("*")
+            .from("City")
+            .limit(10)
+            .comment("application", "atlas")
+            .comment("route", "/cities/*/")
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "SELECT * FROM City LIMIT 10 /* application:atlas,route:/cities/ * / */",
+        );
+    }
+
+    #[test]
+    fn hint() {
+        let sql = select("*")
+            .from("City")
+            .hint("SeqScan(City)")
+            .hint(("Parallel(City 4)", "Set(enable_bitmapscan off)"))
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "/*+ SeqScan(City) Parallel(City 4) Set(enable_bitmapscan off) */ SELECT * FROM City",
+        );
+    }
+
+    #[test]
+    fn hint_sanitized() {
+        let sql = select("*")
+            .from("City")
+            .hint("SeqScan(City) */ DROP TABLE City; /*")
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "/*+ SeqScan(City) * / DROP TABLE City; / * */ SELECT * FROM City",
+        );
+    }
+
+    #[test]
+    fn hint_pretty() {
+        let sql = select("*").from("City").hint("SeqScan(City)");
+
+        assert_eq!(
+            format!("{:#}", sql),
+            "/*+ SeqScan(City) */\nSELECT\n    *\nFROM\n    City"
+        );
+    }
+
+    #[test]
+    fn where_eq() {
+        let mut params = Parameters::starting_from(3);
+        let sql = select("*")
+            .from("City")
+            .where_("population > 1000")
+            .where_eq("country_id", &mut params)
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "SELECT * FROM City WHERE population > 1000 AND country_id = $3",
+        );
+    }
+
+    #[test]
+    fn verify_distinct_on() {
+        let matching = select("*")
+            .distinct_on(("country_id", "name"))
+            .from("City")
+            .order_by(("name", "country_id".desc(), "population"));
+
+        assert_eq!(matching.verify(), Ok(()));
+        assert_correct_postgresql(
+            &matching.to_string(),
+            "SELECT DISTINCT ON (country_id, name) * FROM City ORDER BY name, country_id DESC, population",
+        );
+
+        let mismatched = select("*")
+            .distinct_on(("country_id", "name"))
+            .from("City")
+            .order_by(("country_id", "population"));
+
+        assert_eq!(
+            mismatched.verify(),
+            Err(BuildError::DistinctOnMismatch {
+                order_by: "population".to_owned()
+            })
+        );
+
+        let repeated = select("*")
+            .distinct_on(("country_id", "name"))
+            .from("City")
+            .order_by(("country_id", "country_id".desc(), "population"));
+
+        assert_eq!(
+            repeated.verify(),
+            Err(BuildError::DistinctOnMismatch {
+                order_by: "population".to_owned()
+            })
+        );
+
+        let repeated = select("*")
+            .distinct_on(("country_id", "name"))
+            .from("City")
+            .order_by(("country_id", "country_id", "name", "population"));
+
+        assert_eq!(repeated.verify(), Ok(()));
+        assert_correct_postgresql(
+            &repeated.to_string(),
+            "SELECT DISTINCT ON (country_id, name) * FROM City ORDER BY country_id, country_id, name, population",
+        );
+
+        let short = select("*")
+            .distinct_on(("country_id", "name"))
+            .from("City")
+            .order_by("name");
+
+        assert_eq!(short.verify(), Ok(()));
+    }
+
+    #[test]
+    fn subquery_expressions() {
+        let sql = select(("name", select("max(amount)").from("Sale")))
+            .from("Person")
+            .where_(select("max(amount)").from("Sale").is_not_null())
+            .where_("region".in_(select("region").from("TopRegion")))
+            .order_by(Expression::from(select("1")).desc())
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "SELECT name, (SELECT max(amount) FROM Sale) FROM Person WHERE (SELECT max(amount) FROM Sale) IS NOT NULL AND region IN (SELECT region FROM TopRegion) ORDER BY (SELECT 1) DESC",
+        );
+    }
+
+    #[test]
+    fn paginate() {
+        let sql = select("*").from("City").paginate(1, 10).to_string();
+        assert_correct_postgresql(&sql, "SELECT * FROM City LIMIT 10 OFFSET 0");
+
+        let sql = select("*").from("City").paginate(0, 10).to_string();
+        assert_correct_postgresql(&sql, "SELECT * FROM City LIMIT 10 OFFSET 0");
+
+        let sql = select("*")
+            .from("City")
+            .paginate(usize::MAX, 10)
+            .to_string();
+        assert_correct_postgresql(
+            &sql,
+            &format!("SELECT * FROM City LIMIT 10 OFFSET {}", i64::MAX),
+        );
+    }
+
+    #[test]
+    fn to_count() {
+        let query = with("big")
+            .as_(select("id").from("City").where_("population > 1000000"))
+            .select("name")
+            .distinct_on("country_id")
+            .from("City".inner_join("big").on("big.id = City.id"))
+            .order_by(("country_id", "name".desc()))
+            .limit(10)
+            .offset(20);
+
+        assert_correct_postgresql(
+            &query.to_count().to_string(),
+            "SELECT count(*) FROM (WITH big AS (SELECT id FROM City WHERE population > 1000000) SELECT DISTINCT ON (country_id) name FROM City INNER JOIN big ON big.id = City.id) AS sub",
+        );
+    }
+
+    #[test]
+    fn to_exists() {
+        let query = select("id")
+            .from("City")
+            .where_("population > $1")
+            .order_by("name")
+            .limit(1)
+            .offset(10);
+
+        assert_correct_postgresql(
+            &query.to_exists().to_string(),
+            "SELECT EXISTS (SELECT id FROM City WHERE population > $1 LIMIT 1 OFFSET 10)",
+        );
+    }
+
+    #[test]
+    fn seek() {
+        let sql = select("*")
+            .from("Post")
+            .where_("author_id = $1")
+            .seek([("created_at", Direction::Asc, "$2")])
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "SELECT * FROM Post WHERE author_id = $1 AND created_at > $2 ORDER BY created_at ASC",
+        );
+
+        let sql = select("*")
+            .from("Post")
+            .seek_before([
+                ("pinned", Direction::Desc, "$1"),
+                ("likes", Direction::Desc, "$2"),
+                ("id", Direction::Asc, "$3"),
+            ])
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "SELECT * FROM Post WHERE (pinned > $1 OR (pinned = $1 AND likes > $2) OR (pinned = $1 AND likes = $2 AND id < $3)) ORDER BY pinned ASC, likes ASC, id DESC",
+        );
+    }
+
+    #[test]
+    fn equality_and_hashing() {
+        use std::collections::HashSet;
+
+        let query = |limit| {
+            select("id")
+                .from("Post")
+                .where_("author_id = $1")
+                .limit(limit)
+        };
+
+        assert_eq!(query(10), query(10));
+        assert_ne!(query(10), query(20));
+        assert_eq!(
+            select("*").where_(("a", "b")),
+            select("*").where_("a").where_("b")
+        );
+
+        let cache: HashSet<_> = vec![query(10), query(20), query(10)].into_iter().collect();
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.contains(&query(20)));
+    }
 }
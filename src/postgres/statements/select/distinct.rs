@@ -3,7 +3,8 @@ use std::fmt::{self, Display, Formatter};
 use crate::{postgres::general::Expression, tools::joined};
 
 /// An `ALL` | `DISTINCT` | `DISTINCT ON (...)` clause for `SELECT` statements
-#[derive(Debug, Clone)]
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Distinct {
     All,
     Distinct,
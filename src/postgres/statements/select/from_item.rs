@@ -1,21 +1,23 @@
 use std::fmt::{self, Display, Formatter};
 use std::iter::{once, Once};
+use std::sync::Arc;
 
 use crate::postgres::general::{Alias, TableName};
 use crate::tools::{joined, IntoIteratorOfSameType};
 
 use super::join::Join;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct FromItem {
-    table_name: TableName,
+    // Shared, so that cloning statements with big subselects is cheap
+    table_name: Arc<str>,
     joins: Vec<Join>,
 }
 
 impl FromItem {
     fn new(table_name: impl Into<TableName>) -> FromItem {
         FromItem {
-            table_name: table_name.into(),
+            table_name: Arc::from(table_name.into()),
             joins: Vec::new(),
         }
     }
@@ -34,7 +36,13 @@ impl Display for FromItem {
         write!(f, "{}", self.table_name)?;
 
         if self.has_joins() {
-            write!(f, " {}", joined(&self.joins, " "))?;
+            if f.alternate() {
+                for join in &self.joins {
+                    write!(f, "\n    {}", join)?;
+                }
+            } else {
+                write!(f, " {}", joined(&self.joins, " "))?;
+            }
         }
 
         Ok(())
@@ -71,3 +79,19 @@ where
         once(self.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::FromItem;
+    use crate::postgres::{select, Aliasable};
+
+    #[test]
+    fn clone_shares_subselect() {
+        let item = FromItem::from(select("*").from("Big").as_("big"));
+        let copy = item.clone();
+
+        assert!(Arc::ptr_eq(&item.table_name, &copy.table_name));
+    }
+}
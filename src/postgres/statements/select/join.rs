@@ -5,7 +5,7 @@ use crate::tools::{joined, IntoIteratorOfSameType};
 use super::FromItem;
 
 /// `JOIN` of some type with its condition if necessary
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Join {
     type_: JoinType,
     to: FromItem,
@@ -28,7 +28,7 @@ impl Display for Join {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 enum JoinType {
     Unspecified,
     Inner,
@@ -61,7 +61,7 @@ impl Display for JoinType {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 enum JoinCondition {
     On(String),
     Using(Vec<String>),
@@ -1,7 +1,7 @@
 use std::fmt::{self, Display, Formatter};
 
 /// `LIMIT` expression and things that can be converted into one
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Limit(String);
 
 impl From<&str> for Limit {
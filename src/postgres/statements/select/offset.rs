@@ -1,7 +1,7 @@
 use std::fmt::{self, Display, Formatter};
 
 /// `OFFSET` expression and things that can be converted into one
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Offset(String);
 
 impl From<&str> for Offset {
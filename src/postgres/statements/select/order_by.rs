@@ -7,7 +7,7 @@ use crate::tools::IntoIteratorOfSameType;
 /// `ORDER BY` clause for `SELECT` statements
 ///
 /// See [`Orderable`] trait for details
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct OrderBy {
     expression: SortExpression,
     direction: Option<Direction>,
@@ -23,6 +23,15 @@ impl OrderBy {
         }
     }
 
+    pub(crate) fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    pub(crate) fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
     fn desc(mut self) -> Self {
         self.direction = Some(Direction::Desc);
         self
@@ -60,7 +69,7 @@ impl Display for OrderBy {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 enum Nulls {
     First,
     Last,
@@ -75,13 +84,23 @@ impl Display for Nulls {
     }
 }
 
-#[derive(Debug, Clone)]
-enum Direction {
+/// Sorting direction, `ASC` or `DESC`
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Direction {
     Asc,
     Desc,
     // TODO: USING?
 }
 
+impl Direction {
+    pub(crate) fn reversed(self) -> Direction {
+        match self {
+            Direction::Asc => Direction::Desc,
+            Direction::Desc => Direction::Asc,
+        }
+    }
+}
+
 impl Display for Direction {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
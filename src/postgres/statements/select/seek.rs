@@ -0,0 +1,102 @@
+use crate::postgres::general::{Condition, Expression};
+use crate::tools::joined;
+
+use super::{Direction, OrderBy, Orderable};
+
+/// Sorting key of keyset pagination together with its value in the last seen row
+///
+/// Made out of `(column, direction, value)` tuples, see [`Select::seek`][super::Select::seek].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SeekKey {
+    column: Expression,
+    direction: Direction,
+    value: Expression,
+}
+
+impl<C, V> From<(C, Direction, V)> for SeekKey
+where
+    C: Into<Expression>,
+    V: Into<Expression>,
+{
+    fn from((column, direction, value): (C, Direction, V)) -> Self {
+        SeekKey {
+            column: column.into(),
+            direction,
+            value: value.into(),
+        }
+    }
+}
+
+pub(crate) fn seek_order_by(
+    keys: &[SeekKey],
+    backward: bool,
+) -> impl Iterator<Item = OrderBy> + '_ {
+    keys.iter().map(move |key| {
+        let column = key.column.to_string();
+
+        match direction(key, backward) {
+            Direction::Asc => column.asc(),
+            Direction::Desc => column.desc(),
+        }
+    })
+}
+
+pub(crate) fn seek_condition(keys: &[SeekKey], backward: bool) -> Option<Condition> {
+    let first = keys.first()?;
+
+    if keys.len() == 1 {
+        return Some(comparison(first, backward));
+    }
+
+    if keys.iter().all(|key| key.direction == first.direction) {
+        return Some(format!(
+            "({}) {} ({})",
+            joined(keys.iter().map(|key| &key.column), ", "),
+            operator(direction(first, backward)),
+            joined(keys.iter().map(|key| &key.value), ", "),
+        ));
+    }
+
+    // Mixed directions can't be expressed with a single row comparison:
+    // (a < $1 OR (a = $1 AND b > $2) OR ...)
+    let alternatives = (0..keys.len()).map(|i| {
+        if i == 0 {
+            return comparison(&keys[0], backward);
+        }
+
+        let equalities = keys[..i]
+            .iter()
+            .map(|key| format!("{} = {}", key.column, key.value));
+        format!(
+            "({} AND {})",
+            joined(equalities, " AND "),
+            comparison(&keys[i], backward)
+        )
+    });
+
+    Some(format!("({})", joined(alternatives, " OR ")))
+}
+
+fn direction(key: &SeekKey, backward: bool) -> Direction {
+    if backward {
+        key.direction.reversed()
+    } else {
+        key.direction
+    }
+}
+
+fn comparison(key: &SeekKey, backward: bool) -> Condition {
+    format!(
+        "{} {} {}",
+        key.column,
+        operator(direction(key, backward)),
+        key.value
+    )
+}
+
+fn operator(direction: Direction) -> &'static str {
+    match direction {
+        Direction::Asc => ">",
+        Direction::Desc => "<",
+    }
+}
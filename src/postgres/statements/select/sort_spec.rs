@@ -0,0 +1,158 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use super::{Direction, OrderBy};
+
+/// Allow-list of fields that users may sort by, e.g. through a `?sort=` parameter of an API
+///
+/// Each field maps a public name to the [`OrderBy`] used for it by default, including its
+/// direction and `NULLS` handling. Names that are not on the list are refused, so user input never
+/// ends up in SQL.
+///
+/// ```
+/// use scooby::postgres::{select, Orderable};
+/// use scooby::postgres::statements::SortSpec;
+///
+/// let sort = SortSpec::new()
+///     .field("name", "Person.name")
+///     .field("created", "Person.created_at".desc().nulls_last());
+///
+/// let sql = select("*").from("Person").order_by(sort.parse("created,-name").unwrap()).to_string();
+/// assert_eq!(sql, "SELECT * FROM Person ORDER BY Person.created_at DESC NULLS LAST, Person.name DESC");
+///
+/// assert!(sort.parse("password").is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct SortSpec {
+    fields: Vec<(String, OrderBy)>,
+}
+
+impl SortSpec {
+    /// Create an empty allow-list
+    pub fn new() -> SortSpec {
+        SortSpec::default()
+    }
+
+    /// Allow sorting by the given name, using the given `ORDER BY` options by default
+    ///
+    /// Adding a name again replaces its options.
+    pub fn field(mut self, name: impl Into<String>, order_by: impl Into<OrderBy>) -> SortSpec {
+        let name = name.into();
+        let order_by = order_by.into();
+
+        match self
+            .fields
+            .iter_mut()
+            .find(|(existing, _)| *existing == name)
+        {
+            Some((_, existing)) => *existing = order_by,
+            None => self.fields.push((name, order_by)),
+        }
+
+        self
+    }
+
+    /// Get the `ORDER BY` of a field by its name, in the given direction or in its default one
+    ///
+    /// ```
+    /// use scooby::postgres::Direction;
+    /// use scooby::postgres::statements::SortSpec;
+    ///
+    /// let sort = SortSpec::new().field("name", "Person.name");
+    ///
+    /// assert_eq!(sort.order_by("name", None).unwrap().to_string(), "Person.name");
+    /// assert_eq!(sort.order_by("name", Some(Direction::Desc)).unwrap().to_string(), "Person.name DESC");
+    /// ```
+    pub fn order_by(
+        &self,
+        name: &str,
+        direction: Option<Direction>,
+    ) -> Result<OrderBy, UnknownSortField> {
+        let (_, order_by) = self
+            .fields
+            .iter()
+            .find(|(existing, _)| existing == name)
+            .ok_or_else(|| UnknownSortField {
+                name: name.to_owned(),
+            })?;
+
+        Ok(match direction {
+            Some(direction) => order_by.clone().with_direction(direction),
+            None => order_by.clone(),
+        })
+    }
+
+    /// Parse a comma-separated list of field names into `ORDER BY` expressions
+    ///
+    /// A name prefixed with `-` is sorted in descending order, prefixed with `+` in ascending order,
+    /// and sorted in its default direction without a prefix. Whitespace around names and empty names are ignored.
+    pub fn parse(&self, input: &str) -> Result<Vec<OrderBy>, UnknownSortField> {
+        input
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(|name| {
+                if let Some(name) = name.strip_prefix('-') {
+                    self.order_by(name, Some(Direction::Desc))
+                } else if let Some(name) = name.strip_prefix('+') {
+                    self.order_by(name, Some(Direction::Asc))
+                } else {
+                    self.order_by(name, None)
+                }
+            })
+            .collect()
+    }
+}
+
+/// Error of sorting by a field not allowed by a [`SortSpec`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UnknownSortField {
+    /// The name that was asked for
+    pub name: String,
+}
+
+impl Display for UnknownSortField {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "sorting by {} is not allowed", self.name)
+    }
+}
+
+impl Error for UnknownSortField {}
+
+#[cfg(test)]
+mod tests {
+    use super::{SortSpec, UnknownSortField};
+
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{select, Orderable};
+
+    #[test]
+    fn parse() {
+        let sort = SortSpec::new()
+            .field("name", "name".asc())
+            .field("age", "age".nulls_first())
+            .field("name", "lower(name)");
+
+        let sql = select("*")
+            .from("Dummy")
+            .order_by(sort.parse(" -age , ,+name").unwrap())
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "SELECT * FROM Dummy ORDER BY age DESC NULLS FIRST, lower(name) ASC",
+        );
+    }
+
+    #[test]
+    fn unknown_field() {
+        let sort = SortSpec::new().field("name", "name");
+
+        assert_eq!(
+            sort.parse("name,-id; DROP TABLE Dummy").unwrap_err(),
+            UnknownSortField {
+                name: "id; DROP TABLE Dummy".to_owned()
+            }
+        );
+    }
+}
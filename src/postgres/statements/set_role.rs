@@ -0,0 +1,196 @@
+use std::fmt::{self, Display, Formatter};
+
+/// Create a new `SET ROLE name` statement, changing the current user of the session
+///
+/// Role names are rendered as is; use [`quote_ident`][crate::postgres::quote_ident] for names that need quoting.
+///
+/// ```
+/// use scooby::postgres::set_role;
+///
+/// assert_eq!(set_role("tenant_42").to_string(), "SET ROLE tenant_42");
+/// assert_eq!(set_role("tenant_42").local().to_string(), "SET LOCAL ROLE tenant_42");
+/// ```
+pub fn set_role(role: impl Into<String>) -> SetRole {
+    SetRole {
+        role: Some(role.into()),
+        local: false,
+    }
+}
+
+/// Create a new `RESET ROLE` statement, changing the current user back to the session user
+///
+/// ```
+/// use scooby::postgres::reset_role;
+///
+/// assert_eq!(reset_role().to_string(), "RESET ROLE");
+/// assert_eq!(reset_role().local().to_string(), "SET LOCAL ROLE NONE");
+/// ```
+pub fn reset_role() -> SetRole {
+    SetRole {
+        role: None,
+        local: false,
+    }
+}
+
+/// Create a new `SET SESSION AUTHORIZATION name` statement, changing both the session and the current user
+///
+/// ```
+/// use scooby::postgres::set_session_authorization;
+///
+/// assert_eq!(set_session_authorization("app").to_string(), "SET SESSION AUTHORIZATION app");
+/// ```
+pub fn set_session_authorization(user: impl Into<String>) -> SetSessionAuthorization {
+    SetSessionAuthorization {
+        user: Some(user.into()),
+        local: false,
+    }
+}
+
+/// Create a new `RESET SESSION AUTHORIZATION` statement, changing both users back to the one that logged in
+///
+/// ```
+/// use scooby::postgres::reset_session_authorization;
+///
+/// assert_eq!(reset_session_authorization().to_string(), "RESET SESSION AUTHORIZATION");
+/// ```
+pub fn reset_session_authorization() -> SetSessionAuthorization {
+    SetSessionAuthorization {
+        user: None,
+        local: false,
+    }
+}
+
+/// `SET ROLE` or `RESET ROLE` statement, created with [`set_role`] or [`reset_role`]
+#[must_use = "Making a SET ROLE statement without using it is pointless"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SetRole {
+    role: Option<String>,
+    local: bool,
+}
+
+impl SetRole {
+    /// Only change the role until the end of the current transaction, i.e. `SET LOCAL ROLE`
+    pub fn local(mut self) -> Self {
+        self.local = true;
+        self
+    }
+
+    /// Get the role this statement changes to, or `None` if it resets the role
+    pub fn role(&self) -> Option<&str> {
+        self.role.as_deref()
+    }
+
+    pub(crate) fn is_reset(&self) -> bool {
+        self.role.is_none() && !self.local
+    }
+}
+
+impl Display for SetRole {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_set(f, "ROLE", self.role.as_deref(), "NONE", self.local)
+    }
+}
+
+/// `SET SESSION AUTHORIZATION` or `RESET SESSION AUTHORIZATION` statement,
+/// created with [`set_session_authorization`] or [`reset_session_authorization`]
+#[must_use = "Making a SET SESSION AUTHORIZATION statement without using it is pointless"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SetSessionAuthorization {
+    user: Option<String>,
+    local: bool,
+}
+
+impl SetSessionAuthorization {
+    /// Only change the users until the end of the current transaction, i.e. `SET LOCAL SESSION AUTHORIZATION`
+    ///
+    /// ```
+    /// use scooby::postgres::{reset_session_authorization, set_session_authorization};
+    ///
+    /// assert_eq!(set_session_authorization("app").local().to_string(), "SET LOCAL SESSION AUTHORIZATION app");
+    /// assert_eq!(reset_session_authorization().local().to_string(), "SET LOCAL SESSION AUTHORIZATION DEFAULT");
+    /// ```
+    pub fn local(mut self) -> Self {
+        self.local = true;
+        self
+    }
+
+    /// Get the user this statement changes to, or `None` if it resets the users
+    pub fn user(&self) -> Option<&str> {
+        self.user.as_deref()
+    }
+
+    pub(crate) fn is_reset(&self) -> bool {
+        self.user.is_none() && !self.local
+    }
+}
+
+impl Display for SetSessionAuthorization {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_set(
+            f,
+            "SESSION AUTHORIZATION",
+            self.user.as_deref(),
+            "DEFAULT",
+            self.local,
+        )
+    }
+}
+
+fn write_set(
+    f: &mut Formatter<'_>,
+    parameter: &str,
+    value: Option<&str>,
+    reset_value: &str,
+    local: bool,
+) -> fmt::Result {
+    if value.is_none() && !local {
+        return write!(f, "RESET {}", parameter);
+    }
+
+    write!(f, "SET ")?;
+
+    if local {
+        write!(f, "LOCAL ")?;
+    }
+
+    write!(f, "{} {}", parameter, value.unwrap_or(reset_value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{reset_role, reset_session_authorization, set_role, set_session_authorization};
+
+    use crate::postgres::statements::StatementKind;
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::Statement;
+
+    #[test]
+    fn role() {
+        assert_eq!(set_role("dummy").kind(), StatementKind::Set);
+        assert_correct_postgresql(&set_role("dummy").to_string(), "SET ROLE dummy");
+        assert_correct_postgresql(
+            &set_role("dummy").local().to_string(),
+            "SET LOCAL ROLE dummy",
+        );
+    }
+
+    #[test]
+    fn reset() {
+        assert_eq!(reset_role().kind(), StatementKind::Reset);
+        assert_eq!(reset_role().local().kind(), StatementKind::Set);
+        assert_correct_postgresql(&reset_role().to_string(), "RESET ROLE");
+        assert_correct_postgresql(&reset_role().local().to_string(), "SET LOCAL ROLE NONE");
+        assert_correct_postgresql(
+            &reset_session_authorization().to_string(),
+            "RESET SESSION AUTHORIZATION",
+        );
+    }
+
+    #[test]
+    fn session_authorization() {
+        assert_correct_postgresql(
+            &set_session_authorization("dummy").local().to_string(),
+            "SET LOCAL SESSION AUTHORIZATION dummy",
+        );
+    }
+}
@@ -0,0 +1,231 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::postgres::statements::{
+    AlterDefaultPrivileges, BuildError, CreateTable, DeleteFrom, DoBlock, InsertInto, Select,
+    SetRole, SetSessionAuthorization, TableCommand, Update, Values,
+};
+use crate::postgres::tools::{find_empty_in_list, Render};
+
+/// Any statement built with this crate
+///
+/// Useful for generic code, such as logging or execution adapters, that accepts all statements alike.
+///
+/// ```
+/// use scooby::postgres::{delete_from, select, Statement};
+///
+/// fn log(statement: &impl Statement) -> String {
+///     format!("[{}] {}", statement.kind(), statement.to_sql())
+/// }
+///
+/// assert_eq!(log(&select("1")), "[SELECT] SELECT 1");
+/// assert_eq!(log(&delete_from("Person")), "[DELETE] DELETE FROM Person");
+/// ```
+pub trait Statement: Render {
+    /// Get the kind of this statement
+    fn kind(&self) -> StatementKind;
+
+    /// Render this statement into SQL, same as `to_string`
+    fn to_sql(&self) -> String {
+        self.to_string()
+    }
+
+    /// Check this statement for mistakes that PostgreSQL would reject it for, without rendering it
+    ///
+    /// See `verify` methods of specific statements, such as [`Select::verify`], for what is checked.
+    fn verify(&self) -> Result<(), BuildError> {
+        Ok(())
+    }
+
+    /// Render this statement into SQL, unless [`verify`][Statement::verify] finds a mistake in it
+    ///
+    /// Conditions are kept as the strings they were made of, so an `IN ()` list without items,
+    /// e.g. from formatting an empty list of parameters, is found in the rendered SQL instead.
+    ///
+    /// ```
+    /// use scooby::postgres::{insert_into, select, Orderable, Statement};
+    /// use scooby::postgres::statements::BuildError;
+    ///
+    /// let statement = select("*").distinct_on("city").from("Person").order_by("age".desc());
+    /// assert_eq!(statement.try_build(), Err(BuildError::DistinctOnMismatch { order_by: "age".to_owned() }));
+    ///
+    /// let statement = insert_into("Person").values(["$1"]).on_conflict().do_update_set([("age", "$1")]);
+    /// assert_eq!(statement.try_build(), Err(BuildError::MissingConflictTarget));
+    ///
+    /// let statement = select("*").from("Person").where_(format!("id IN ({})", ""));
+    /// assert_eq!(statement.try_build(), Err(BuildError::EmptyInList { position: 30 }));
+    ///
+    /// let statement = insert_into("Person").columns("name").values(["$1"]);
+    /// assert_eq!(statement.try_build().unwrap(), "INSERT INTO Person (name) VALUES ($1)");
+    /// ```
+    fn try_build(&self) -> Result<String, BuildError> {
+        self.verify()?;
+
+        let sql = self.to_sql();
+
+        match find_empty_in_list(&sql) {
+            Some(position) => Err(BuildError::EmptyInList { position }),
+            None => Ok(sql),
+        }
+    }
+}
+
+/// Kind of a [`Statement`]
+///
+/// Displayed as the SQL command, such as `SELECT` or `CREATE TABLE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StatementKind {
+    Select,
+    Insert,
+    Update,
+    Delete,
+    CreateTable,
+    Table,
+    Do,
+    Set,
+    Reset,
+    AlterDefaultPrivileges,
+}
+
+impl StatementKind {
+    /// Get the SQL command of this kind, such as `SELECT`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StatementKind::Select => "SELECT",
+            StatementKind::Insert => "INSERT",
+            StatementKind::Update => "UPDATE",
+            StatementKind::Delete => "DELETE",
+            StatementKind::CreateTable => "CREATE TABLE",
+            StatementKind::Table => "TABLE",
+            StatementKind::Do => "DO",
+            StatementKind::Set => "SET",
+            StatementKind::Reset => "RESET",
+            StatementKind::AlterDefaultPrivileges => "ALTER DEFAULT PRIVILEGES",
+        }
+    }
+}
+
+impl Display for StatementKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Statement for Select {
+    fn kind(&self) -> StatementKind {
+        StatementKind::Select
+    }
+
+    fn verify(&self) -> Result<(), BuildError> {
+        Select::verify(self)
+    }
+}
+
+impl<V: Values> Statement for InsertInto<V> {
+    fn kind(&self) -> StatementKind {
+        StatementKind::Insert
+    }
+
+    fn verify(&self) -> Result<(), BuildError> {
+        InsertInto::verify(self)
+    }
+}
+
+impl Statement for Update {
+    fn kind(&self) -> StatementKind {
+        StatementKind::Update
+    }
+
+    fn verify(&self) -> Result<(), BuildError> {
+        Update::verify(self)
+    }
+}
+
+impl Statement for DeleteFrom {
+    fn kind(&self) -> StatementKind {
+        StatementKind::Delete
+    }
+
+    fn verify(&self) -> Result<(), BuildError> {
+        DeleteFrom::verify(self)
+    }
+}
+
+impl Statement for CreateTable {
+    fn kind(&self) -> StatementKind {
+        StatementKind::CreateTable
+    }
+}
+
+impl Statement for TableCommand {
+    fn kind(&self) -> StatementKind {
+        StatementKind::Table
+    }
+}
+
+impl Statement for DoBlock {
+    fn kind(&self) -> StatementKind {
+        StatementKind::Do
+    }
+}
+
+impl Statement for AlterDefaultPrivileges {
+    fn kind(&self) -> StatementKind {
+        StatementKind::AlterDefaultPrivileges
+    }
+}
+
+impl Statement for SetRole {
+    fn kind(&self) -> StatementKind {
+        if self.is_reset() {
+            StatementKind::Reset
+        } else {
+            StatementKind::Set
+        }
+    }
+}
+
+impl Statement for SetSessionAuthorization {
+    fn kind(&self) -> StatementKind {
+        if self.is_reset() {
+            StatementKind::Reset
+        } else {
+            StatementKind::Set
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Statement, StatementKind};
+
+    use crate::postgres::{create_table, insert_into, select, with};
+
+    fn kind_and_sql(statement: impl Statement) -> (StatementKind, String) {
+        (statement.kind(), statement.to_sql())
+    }
+
+    #[test]
+    fn kinds() {
+        assert_eq!(
+            kind_and_sql(insert_into("Dummy").default_values()),
+            (
+                StatementKind::Insert,
+                "INSERT INTO Dummy DEFAULT VALUES".to_string()
+            )
+        );
+        assert_eq!(
+            kind_and_sql(with("x").as_(select("1")).update("Dummy").set("a", "1")),
+            (
+                StatementKind::Update,
+                "WITH x AS (SELECT 1) UPDATE Dummy SET a = 1".to_string()
+            )
+        );
+        assert_eq!(
+            kind_and_sql(create_table("Dummy").columns([("a", "integer")]))
+                .0
+                .to_string(),
+            "CREATE TABLE"
+        );
+    }
+}
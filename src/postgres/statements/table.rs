@@ -0,0 +1,104 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::postgres::general::{Expression, TableName};
+
+/// Create a new `TABLE name` command, a shorthand for `SELECT * FROM name`
+///
+/// Mostly useful inside `WITH` clauses and subqueries.
+///
+/// ```
+/// use scooby::postgres::{table, with};
+///
+/// let sql = with("archived")
+///     .as_(table("Event"))
+///     .insert_into("ArchivedEvent")
+///     .values(["(SELECT count(*) FROM archived)"])
+///     .to_string();
+///
+/// assert_eq!(sql, "WITH archived AS (TABLE Event) INSERT INTO ArchivedEvent VALUES ((SELECT count(*) FROM archived))");
+///
+/// assert_eq!(table("Event").to_string(), "TABLE Event");
+/// ```
+pub fn table(table_name: impl Into<TableName>) -> TableCommand {
+    TableCommand {
+        table_name: table_name.into(),
+        only: false,
+    }
+}
+
+/// `TABLE name` command, created with [`table`]
+#[must_use = "Making a TABLE command without using it is pointless"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TableCommand {
+    table_name: TableName,
+    only: bool,
+}
+
+impl TableCommand {
+    /// Only return rows of this table, not of tables inheriting from it, i.e. `TABLE ONLY name`
+    ///
+    /// ```
+    /// use scooby::postgres::table;
+    ///
+    /// assert_eq!(table("Event").only().to_string(), "TABLE ONLY Event");
+    /// ```
+    pub fn only(mut self) -> Self {
+        self.only = true;
+        self
+    }
+
+    /// Get the name of the table this command returns
+    pub fn table_name(&self) -> &str {
+        &self.table_name
+    }
+}
+
+impl Display for TableCommand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "TABLE ")?;
+
+        if self.only {
+            write!(f, "ONLY ")?;
+        }
+
+        write!(f, "{}", self.table_name)
+    }
+}
+
+impl From<TableCommand> for Expression {
+    fn from(command: TableCommand) -> Self {
+        Expression::from(format!("({})", command))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::table;
+
+    use crate::postgres::general::Expression;
+    use crate::postgres::statements::StatementKind;
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{select, with, Statement};
+
+    #[test]
+    fn table_command() {
+        let command = table("Dummy").only();
+
+        assert_eq!(command.kind(), StatementKind::Table);
+        assert_correct_postgresql(&command.to_string(), "TABLE ONLY Dummy");
+    }
+
+    #[test]
+    fn in_cte_and_subquery() {
+        let sql = with("copy")
+            .as_(table("Dummy"))
+            .select(select("count(*)").from("copy"))
+            .and_select(Expression::from(table("copy")))
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "WITH copy AS (TABLE Dummy) SELECT (SELECT count(*) FROM copy), (TABLE copy)",
+        );
+    }
+}
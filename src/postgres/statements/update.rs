@@ -1,9 +1,12 @@
 use std::fmt::{self, Display, Formatter};
 
 use crate::postgres::general::{
-    Column, Condition, Expression, OutputExpression, TableName, WithClause,
+    Column, Comment, Comments, Condition, Expression, OutputExpression, TableName, WithClause,
 };
-use crate::tools::{joined, IntoIteratorOfSameType};
+use crate::postgres::tools::Parameters;
+use crate::tools::{clause_break, write_clause, ClauseVec, IntoIteratorOfSameType};
+
+use super::BuildError;
 
 /// Start building a new `UPDATE` statement with the given table name.
 ///
@@ -93,6 +96,61 @@ impl BareUpdate {
             self.with,
         )
     }
+
+    /// Add a `SET` clause to the statement, setting the column to the next placeholder from `params`.
+    ///
+    /// ```
+    /// use scooby::postgres::{update, Parameters};
+    ///
+    /// let mut params = Parameters::new();
+    ///
+    /// let sql = update("Dummy").set_param("x", &mut params).to_string();
+    ///
+    /// assert_eq!(sql, "UPDATE Dummy SET x = $1");
+    /// ```
+    pub fn set_param(self, column: impl Into<Column>, params: &mut Parameters) -> Update {
+        self.set(column, params.next())
+    }
+
+    /// Add a `SET` clause to the statement with the first column, setting it to the result of an operator applied to itself
+    ///
+    /// See [`Update::set_expr_self`] for details.
+    ///
+    /// ```
+    /// use scooby::postgres::update;
+    ///
+    /// let sql = update("Dummy").set_expr_self("tags", "||", "$1").to_string();
+    ///
+    /// assert_eq!(sql, "UPDATE Dummy SET tags = tags || $1");
+    /// ```
+    pub fn set_expr_self(
+        self,
+        column: impl Into<Column>,
+        operator: &str,
+        operand: impl Into<Expression>,
+    ) -> Update {
+        let column = column.into();
+        let value = self_expression(&column, operator, operand.into());
+        self.set(column, value)
+    }
+
+    /// Add a `SET` clause to the statement with the first column, increasing it, i.e. `SET x = x + 1`
+    ///
+    /// ```
+    /// use scooby::postgres::update;
+    ///
+    /// let sql = update("Post").set_add("views", "1").where_("id = $1").to_string();
+    ///
+    /// assert_eq!(sql, "UPDATE Post SET views = views + 1 WHERE id = $1");
+    /// ```
+    pub fn set_add(self, column: impl Into<Column>, amount: impl Into<Expression>) -> Update {
+        self.set_expr_self(column, "+", amount)
+    }
+
+    /// Add a `SET` clause to the statement with the first column, decreasing it, i.e. `SET x = x - 1`
+    pub fn set_sub(self, column: impl Into<Column>, amount: impl Into<Expression>) -> Update {
+        self.set_expr_self(column, "-", amount)
+    }
 }
 
 /// `UPDATE` statement with at least one set of values, and possibly additional clauses.
@@ -101,13 +159,15 @@ impl BareUpdate {
 ///
 /// See [`update`] docs for more details and examples.
 #[must_use = "Making an UPDATE statement without using it is pointless"]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Update {
     table_name: TableName,
-    with: Option<WithClause>,
+    pub(super) with: Option<WithClause>,
     values: Vec<(Column, Expression)>,
-    where_: Vec<Condition>,
-    returning: Vec<OutputExpression>,
+    where_: ClauseVec<Condition>,
+    returning: ClauseVec<OutputExpression>,
+    comments: Comments,
+    allow_full_table: bool,
 }
 
 impl Update {
@@ -120,8 +180,10 @@ impl Update {
             table_name,
             values,
             with,
-            where_: Vec::new(),
-            returning: Vec::new(),
+            where_: ClauseVec::new(),
+            returning: ClauseVec::new(),
+            comments: Comments::default(),
+            allow_full_table: false,
         }
     }
 
@@ -139,6 +201,125 @@ impl Update {
         self
     }
 
+    /// Add a column to the `SET` clause of this statement, setting it to the result of an operator applied to itself
+    ///
+    /// Renders as `x = x <operator> <operand>`. The operand is not parenthesized, so compound ones need
+    /// to be, e.g. `"(a - b)"` for `set_sub`. The column on the right-hand side refers to the value before the update.
+    ///
+    /// ```
+    /// use scooby::postgres::update;
+    ///
+    /// let sql = update("Account")
+    ///     .set("touched_at", "now()")
+    ///     .set_expr_self("balance", "*", "$1")
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "UPDATE Account SET touched_at = now(), balance = balance * $1");
+    /// ```
+    pub fn set_expr_self(
+        self,
+        column: impl Into<Column>,
+        operator: &str,
+        operand: impl Into<Expression>,
+    ) -> Self {
+        let column = column.into();
+        let value = self_expression(&column, operator, operand.into());
+        self.set(column, value)
+    }
+
+    /// Add a column to the `SET` clause of this statement, increasing it, i.e. `x = x + 1`
+    ///
+    /// ```
+    /// use scooby::postgres::update;
+    ///
+    /// let sql = update("Post").set("seen", "true").set_add("views", "$1").to_string();
+    ///
+    /// assert_eq!(sql, "UPDATE Post SET seen = true, views = views + $1");
+    /// ```
+    pub fn set_add(self, column: impl Into<Column>, amount: impl Into<Expression>) -> Self {
+        self.set_expr_self(column, "+", amount)
+    }
+
+    /// Add a column to the `SET` clause of this statement, decreasing it, i.e. `x = x - 1`
+    ///
+    /// ```
+    /// use scooby::postgres::update;
+    ///
+    /// let sql = update("Item").set("sold", "true").set_sub("stock", "(ordered - returned)").to_string();
+    ///
+    /// assert_eq!(sql, "UPDATE Item SET sold = true, stock = stock - (ordered - returned)");
+    /// ```
+    pub fn set_sub(self, column: impl Into<Column>, amount: impl Into<Expression>) -> Self {
+        self.set_expr_self(column, "-", amount)
+    }
+
+    /// Add a column + expression pair to the `SET` clause of this statement if there is an expression,
+    /// or leave the statement as it is otherwise
+    ///
+    /// ```
+    /// use scooby::postgres::update;
+    ///
+    /// let name: Option<&str> = Some("$2");
+    /// let email: Option<&str> = None;
+    ///
+    /// let sql = update("Person")
+    ///     .set("updated_at", "now()")
+    ///     .set_opt("name", name)
+    ///     .set_opt("email", email)
+    ///     .where_("id = $1")
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "UPDATE Person SET updated_at = now(), name = $2 WHERE id = $1");
+    /// ```
+    pub fn set_opt(self, column: impl Into<Column>, value: Option<impl Into<Expression>>) -> Self {
+        match value {
+            Some(value) => self.set(column, value),
+            None => self,
+        }
+    }
+
+    /// Add column + expression pairs from an iterator, such as a map of them, to the `SET` clause of this statement
+    ///
+    /// ```
+    /// use scooby::postgres::update;
+    ///
+    /// let patch = vec![("name", "$2"), ("email", "$3")];
+    ///
+    /// let sql = update("Person").set("updated_at", "now()").set_many(patch).where_("id = $1").to_string();
+    ///
+    /// assert_eq!(sql, "UPDATE Person SET updated_at = now(), name = $2, email = $3 WHERE id = $1");
+    /// ```
+    pub fn set_many<C, E>(mut self, pairs: impl IntoIterator<Item = (C, E)>) -> Self
+    where
+        C: Into<Column>,
+        E: Into<Expression>,
+    {
+        self.values.extend(
+            pairs
+                .into_iter()
+                .map(|(column, value)| (column.into(), value.into())),
+        );
+        self
+    }
+
+    /// Add a column to the `SET` clause of this statement, setting it to the next placeholder from `params`
+    ///
+    /// ```
+    /// use scooby::postgres::{update, Parameters};
+    ///
+    /// let mut params = Parameters::new();
+    ///
+    /// let sql = update("Dummy")
+    ///     .set_param("x", &mut params)
+    ///     .set_param("y", &mut params)
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "UPDATE Dummy SET x = $1, y = $2");
+    /// ```
+    pub fn set_param(self, column: impl Into<Column>, params: &mut Parameters) -> Self {
+        self.set(column, params.next())
+    }
+
     /// Add one or more `WHERE` conditions, `AND`'ed together with themselves and existing conditions.
     ///
     /// ```
@@ -157,6 +338,26 @@ impl Update {
         self
     }
 
+    /// Add a `column = $n` condition with the next placeholder from `params`, `AND`'ed with existing conditions.
+    ///
+    /// ```
+    /// use scooby::postgres::{update, Parameters};
+    ///
+    /// let mut params = Parameters::new();
+    ///
+    /// let sql = update("Dummy")
+    ///     .set_param("x", &mut params)
+    ///     .where_eq("id", &mut params)
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "UPDATE Dummy SET x = $1 WHERE id = $2");
+    /// ```
+    pub fn where_eq(mut self, column: impl Into<Expression>, params: &mut Parameters) -> Self {
+        self.where_
+            .push(format!("{} = {}", column.into(), params.next()));
+        self
+    }
+
     /// Add one or more `RETURNING` expressions.
     ///
     /// ```
@@ -174,42 +375,177 @@ impl Update {
         self.returning.extend(expressions.into_some_iter());
         self
     }
+
+    /// Remove all `WHERE` conditions added so far
+    ///
+    /// ```
+    /// use scooby::postgres::update;
+    ///
+    /// let sql = update("Dummy").set("x", "$1").where_("x > 1").clear_where().to_string();
+    ///
+    /// assert_eq!(sql, "UPDATE Dummy SET x = $1");
+    /// ```
+    pub fn clear_where(mut self) -> Self {
+        self.where_.clear();
+        self
+    }
+
+    /// Remove all `RETURNING` expressions added so far
+    pub fn clear_returning(mut self) -> Self {
+        self.returning.clear();
+        self
+    }
+
+    /// Annotate this statement with a `key:value` pair, e.g. to identify its origin in `pg_stat_activity`.
+    ///
+    /// All annotations are rendered together in a single trailing `/* ... */` comment.
+    /// Anything that would end the comment early is broken up, so arbitrary values are safe to use.
+    ///
+    /// ```
+    /// use scooby::postgres::update;
+    ///
+    /// let sql = update("Dummy")
+    ///     .set("x", "$1")
+    ///     .comment("application", "billing")
+    ///     .to_string();
+    ///
+    /// assert_eq!(sql, "UPDATE Dummy SET x = $1 /* application:billing */");
+    /// ```
+    pub fn comment(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.comments.push(Comment::new(key, value));
+        self
+    }
+
+    /// Mark this statement as meant to affect every row of the table when it has no `WHERE` conditions
+    ///
+    /// Without this, [`verify`][Update::verify] and [`try_build`][crate::postgres::Statement::try_build]
+    /// refuse statements without conditions, which are usually a forgotten `where_` call.
+    ///
+    /// ```
+    /// use scooby::postgres::{update, Statement};
+    /// use scooby::postgres::statements::BuildError;
+    ///
+    /// assert_eq!(update("Person").set("active", "false").try_build(), Err(BuildError::MissingWhere));
+    /// assert_eq!(update("Person").set("active", "false").allow_full_table().try_build().unwrap(), "UPDATE Person SET active = false");
+    /// ```
+    pub fn allow_full_table(mut self) -> Self {
+        self.allow_full_table = true;
+        self
+    }
+
+    /// Get the name of the table this statement operates on
+    ///
+    /// ```
+    /// use scooby::postgres::update;
+    ///
+    /// let sql = update("Dummy").set("x", "$1");
+    ///
+    /// assert_eq!(sql.table_name(), "Dummy");
+    /// ```
+    pub fn table_name(&self) -> &str {
+        &self.table_name
+    }
+
+    /// Get conditions of the `WHERE` clause added so far
+    pub fn where_conditions(&self) -> &[Condition] {
+        &self.where_
+    }
+
+    /// Check whether this statement has a `WHERE` clause
+    ///
+    /// ```
+    /// use scooby::postgres::update;
+    ///
+    /// let sql = update("Dummy").set("x", "$1");
+    ///
+    /// assert!(!sql.has_where());
+    /// assert!(sql.where_("x > 0").has_where());
+    /// ```
+    pub fn has_where(&self) -> bool {
+        !self.where_.is_empty()
+    }
+
+    /// Get column + expression pairs of the `SET` clause added so far
+    ///
+    /// ```
+    /// use scooby::postgres::update;
+    ///
+    /// let sql = update("Dummy").set("x", "$1").set("y", "y + 1");
+    ///
+    /// assert_eq!(sql.set_values().len(), 2);
+    /// assert_eq!(sql.set_values()[1].1.to_string(), "y + 1");
+    /// ```
+    pub fn set_values(&self) -> &[(Column, Expression)] {
+        &self.values
+    }
+
+    /// Get expressions of the `RETURNING` clause added so far
+    pub fn returning_expressions(&self) -> &[OutputExpression] {
+        &self.returning
+    }
+
+    /// Check this statement for mistakes that would make it affect more rows than intended
+    ///
+    /// Currently checks that there are `WHERE` conditions, unless [`allow_full_table`][Update::allow_full_table] is called.
+    pub fn verify(&self) -> Result<(), BuildError> {
+        if self.where_.is_empty() && !self.allow_full_table {
+            return Err(BuildError::MissingWhere);
+        }
+
+        Ok(())
+    }
+}
+
+fn self_expression(column: &Column, operator: &str, operand: Expression) -> Expression {
+    Expression::from(format!("{} {} {}", column, operator, operand))
 }
 
 impl Display for Update {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         if let Some(with_clause) = &self.with {
-            write!(f, "{} ", with_clause)?;
+            with_clause.fmt(f)?;
+            clause_break(f)?;
         }
 
-        write!(
+        write!(f, "UPDATE {}", self.table_name)?;
+
+        write_clause(
             f,
-            "UPDATE {} SET {}",
-            self.table_name,
-            joined(
-                self.values
-                    .iter()
-                    .map(|(col, val)| format!("{} = {}", col, val)),
-                ", "
-            )
+            "SET",
+            self.values
+                .iter()
+                .map(|(column, value)| Assignment(column, value)),
+            ", ",
         )?;
 
         if !self.where_.is_empty() {
-            write!(f, " WHERE {}", joined(&self.where_, " AND "))?;
+            write_clause(f, "WHERE", &self.where_, " AND ")?;
         }
 
         if !self.returning.is_empty() {
-            write!(f, " RETURNING {}", joined(&self.returning, ", "))?;
+            write_clause(f, "RETURNING", &self.returning, ", ")?;
         }
 
+        self.comments.fmt(f)?;
+
         Ok(())
     }
 }
 
+/// Single `column = value` item of a `SET` clause
+#[derive(Clone)]
+struct Assignment<'a>(&'a Column, &'a Expression);
+
+impl<'a> Display for Assignment<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = {}", self.0, self.1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::postgres::tools::tests::assert_correct_postgresql;
-    use crate::postgres::{select, update, with};
+    use crate::postgres::{select, update, with, Parameters};
 
     #[test]
     fn update_single_value() {
@@ -245,4 +581,92 @@ mod tests {
 
         assert_correct_postgresql(&sql, "WITH thing AS (SELECT 1 + 1) UPDATE Dummy SET x = y");
     }
+
+    #[test]
+    fn pretty() {
+        let sql = update("Dummy")
+            .set("x", "$1")
+            .set("y", "$2")
+            .where_(("a > 1", "b > 1"))
+            .returning("id");
+
+        assert_eq!(
+            format!("{:#}", sql),
+            "\
+UPDATE Dummy
+SET
+    x = $1,
+    y = $2
+WHERE
+    a > 1 AND
+    b > 1
+RETURNING
+    id"
+        );
+    }
+
+    #[test]
+    fn comment() {
+        let sql = update("Dummy")
+            .set("x", "$1")
+            .where_("id = $2")
+            .comment("trace_id", "*/ DROP TABLE Dummy; --")
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "UPDATE Dummy SET x = $1 WHERE id = $2 /* trace_id:* / DROP TABLE Dummy; -- */",
+        );
+    }
+
+    #[test]
+    fn with_parameters() {
+        let mut params = Parameters::new();
+        let sql = update("Dummy")
+            .set_param("x", &mut params)
+            .set("y", "y + 1")
+            .set_param("z", &mut params)
+            .where_eq("id", &mut params)
+            .returning("id")
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "UPDATE Dummy SET x = $1, y = y + 1, z = $2 WHERE id = $3 RETURNING id",
+        );
+    }
+
+    #[test]
+    fn set_many_from_map() {
+        let patch: std::collections::BTreeMap<&str, String> = [("b", "$2"), ("a", "$1")]
+            .iter()
+            .map(|(k, v)| (*k, v.to_string()))
+            .collect();
+
+        let sql = update("Dummy").set("x", "1").set_many(patch).to_string();
+
+        assert_correct_postgresql(&sql, "UPDATE Dummy SET x = 1, a = $1, b = $2");
+    }
+
+    #[test]
+    fn set_opt() {
+        let sql = update("Dummy")
+            .set("x", "1")
+            .set_opt("y", None::<&str>)
+            .set_opt("z", Some(String::from("$1")))
+            .to_string();
+
+        assert_correct_postgresql(&sql, "UPDATE Dummy SET x = 1, z = $1");
+    }
+
+    #[test]
+    fn arithmetic() {
+        let sql = update("Dummy")
+            .set_add("x", "1")
+            .set_sub("y", "$1")
+            .set_expr_self("z", "||", "'!'")
+            .to_string();
+
+        assert_correct_postgresql(&sql, "UPDATE Dummy SET x = x + 1, y = y - $1, z = z || '!'");
+    }
 }
@@ -0,0 +1,284 @@
+//! Helpers for the [TimescaleDB](https://www.timescale.com/) extension
+//!
+//! Available with the `timescale` feature.
+//!
+//! TimescaleDB is managed by calling its functions, so statements built here are `SELECT`s of those calls.
+//!
+//! ```
+//! use scooby::postgres::{select, Aliasable};
+//! use scooby::postgres::timescale::{add_continuous_aggregate_policy, create_hypertable, time_bucket};
+//!
+//! let sql = create_hypertable("Reading", "taken_at").if_not_exists().to_string();
+//! assert_eq!(sql, "SELECT create_hypertable('Reading', 'taken_at', if_not_exists => TRUE)");
+//!
+//! let sql = select((time_bucket("INTERVAL '1 hour'", "taken_at").as_("hour"), "avg(value)"))
+//!     .from("Reading")
+//!     .group_by("hour")
+//!     .to_string();
+//! assert_eq!(sql, "SELECT time_bucket(INTERVAL '1 hour', taken_at) AS hour, avg(value) FROM Reading GROUP BY hour");
+//!
+//! let sql = add_continuous_aggregate_policy("HourlyReading", "INTERVAL '3 days'", "INTERVAL '1 hour'", "INTERVAL '1 hour'")
+//!     .to_string();
+//! assert_eq!(
+//!     sql,
+//!     "SELECT add_continuous_aggregate_policy('HourlyReading', \
+//!      start_offset => INTERVAL '3 days', end_offset => INTERVAL '1 hour', schedule_interval => INTERVAL '1 hour')",
+//! );
+//! ```
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::postgres::general::{func, Expression, FunctionCall, TableName};
+use crate::postgres::statements::StatementKind;
+use crate::postgres::tools::Render;
+use crate::postgres::Statement;
+use crate::tools::literal;
+
+/// Group timestamps into buckets of the given width, `time_bucket(width, time)` style
+///
+/// ```
+/// use scooby::postgres::timescale::time_bucket;
+///
+/// assert_eq!(time_bucket("INTERVAL '5 minutes'", "taken_at").to_string(), "time_bucket(INTERVAL '5 minutes', taken_at)");
+/// ```
+pub fn time_bucket(
+    bucket_width: impl Into<Expression>,
+    time: impl Into<Expression>,
+) -> FunctionCall {
+    func("time_bucket", (bucket_width.into(), time.into()))
+}
+
+/// Create a new `SELECT create_hypertable(...)` statement, turning a regular table into a hypertable
+/// partitioned by the given time column
+///
+/// Both names are passed to TimescaleDB as string literals.
+pub fn create_hypertable(
+    table_name: impl Into<TableName>,
+    time_column: impl Into<String>,
+) -> CreateHypertable {
+    CreateHypertable {
+        table_name: table_name.into(),
+        time_column: time_column.into(),
+        options: NamedArguments::default(),
+    }
+}
+
+/// Create a new `SELECT add_continuous_aggregate_policy(...)` statement, refreshing a continuous aggregate on schedule
+///
+/// Every `schedule_interval`, rows between `start_offset` and `end_offset` ago are refreshed.
+/// Either offset may be `NULL` to leave that end of the refreshed window open.
+pub fn add_continuous_aggregate_policy(
+    view_name: impl Into<TableName>,
+    start_offset: impl Into<Expression>,
+    end_offset: impl Into<Expression>,
+    schedule_interval: impl Into<Expression>,
+) -> ContinuousAggregatePolicy {
+    let mut options = NamedArguments::default();
+    options.set("start_offset", start_offset);
+    options.set("end_offset", end_offset);
+    options.set("schedule_interval", schedule_interval);
+
+    ContinuousAggregatePolicy {
+        view_name: view_name.into(),
+        options,
+    }
+}
+
+/// `SELECT create_hypertable(...)` statement, created with [`create_hypertable`]
+#[must_use = "Making a create_hypertable statement without using it is pointless"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreateHypertable {
+    table_name: TableName,
+    time_column: String,
+    options: NamedArguments,
+}
+
+impl CreateHypertable {
+    /// Set the time range covered by each chunk, i.e. `chunk_time_interval => x`
+    ///
+    /// ```
+    /// use scooby::postgres::timescale::create_hypertable;
+    ///
+    /// let sql = create_hypertable("Reading", "taken_at").chunk_time_interval("INTERVAL '1 day'").to_string();
+    ///
+    /// assert_eq!(sql, "SELECT create_hypertable('Reading', 'taken_at', chunk_time_interval => INTERVAL '1 day')");
+    /// ```
+    pub fn chunk_time_interval(mut self, interval: impl Into<Expression>) -> Self {
+        self.options.set("chunk_time_interval", interval);
+        self
+    }
+
+    /// Do nothing instead of failing if the table is already a hypertable, i.e. `if_not_exists => TRUE`
+    pub fn if_not_exists(mut self) -> Self {
+        self.options.set("if_not_exists", "TRUE");
+        self
+    }
+
+    /// Move rows already in the table into chunks, i.e. `migrate_data => TRUE`
+    pub fn migrate_data(mut self) -> Self {
+        self.options.set("migrate_data", "TRUE");
+        self
+    }
+}
+
+impl Display for CreateHypertable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "SELECT create_hypertable({}, {}{})",
+            literal(&self.table_name),
+            literal(&self.time_column),
+            self.options
+        )
+    }
+}
+
+/// `SELECT add_continuous_aggregate_policy(...)` statement, created with [`add_continuous_aggregate_policy`]
+#[must_use = "Making an add_continuous_aggregate_policy statement without using it is pointless"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ContinuousAggregatePolicy {
+    view_name: TableName,
+    options: NamedArguments,
+}
+
+impl ContinuousAggregatePolicy {
+    /// Do nothing instead of failing if the continuous aggregate already has a policy, i.e. `if_not_exists => TRUE`
+    ///
+    /// ```
+    /// use scooby::postgres::timescale::add_continuous_aggregate_policy;
+    ///
+    /// let sql = add_continuous_aggregate_policy("HourlyReading", "NULL", "INTERVAL '1 hour'", "INTERVAL '1 hour'")
+    ///     .if_not_exists()
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     sql,
+    ///     "SELECT add_continuous_aggregate_policy('HourlyReading', \
+    ///      start_offset => NULL, end_offset => INTERVAL '1 hour', schedule_interval => INTERVAL '1 hour', if_not_exists => TRUE)",
+    /// );
+    /// ```
+    pub fn if_not_exists(mut self) -> Self {
+        self.options.set("if_not_exists", "TRUE");
+        self
+    }
+}
+
+impl Display for ContinuousAggregatePolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "SELECT add_continuous_aggregate_policy({}{})",
+            literal(&self.view_name),
+            self.options
+        )
+    }
+}
+
+impl Render for CreateHypertable {}
+impl Render for ContinuousAggregatePolicy {}
+
+impl Statement for CreateHypertable {
+    fn kind(&self) -> StatementKind {
+        StatementKind::Select
+    }
+}
+
+impl Statement for ContinuousAggregatePolicy {
+    fn kind(&self) -> StatementKind {
+        StatementKind::Select
+    }
+}
+
+/// Named arguments of a function call following positional ones, rendered as `, name => value` each
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+struct NamedArguments(Vec<(&'static str, Expression)>);
+
+impl NamedArguments {
+    /// Set an argument, replacing its value in place if it has been set already
+    fn set(&mut self, name: &'static str, value: impl Into<Expression>) {
+        let value = value.into();
+
+        match self.0.iter_mut().find(|(existing, _)| *existing == name) {
+            Some((_, existing)) => *existing = value,
+            None => self.0.push((name, value)),
+        }
+    }
+}
+
+impl Display for NamedArguments {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (name, value) in &self.0 {
+            write!(f, ", {} => {}", name, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{add_continuous_aggregate_policy, create_hypertable, time_bucket};
+
+    use crate::postgres::schema::Table;
+    use crate::postgres::statements::StatementKind;
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{select, Statement};
+
+    #[test]
+    fn hypertable() {
+        let table = Table::new("Reading").in_schema("metrics");
+        let statement = create_hypertable(&table, "taken_at")
+            .chunk_time_interval("INTERVAL '1 day'")
+            .if_not_exists()
+            .migrate_data();
+
+        assert_eq!(statement.kind(), StatementKind::Select);
+        assert_correct_postgresql(
+            &statement.to_string(),
+            "SELECT create_hypertable('metrics.Reading', 'taken_at', chunk_time_interval => INTERVAL '1 day', if_not_exists => TRUE, migrate_data => TRUE)",
+        );
+    }
+
+    #[test]
+    fn repeated_options() {
+        let sql = create_hypertable("Reading", "taken_at")
+            .chunk_time_interval("INTERVAL '1 day'")
+            .if_not_exists()
+            .chunk_time_interval("INTERVAL '1 week'")
+            .if_not_exists()
+            .migrate_data()
+            .migrate_data()
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "SELECT create_hypertable('Reading', 'taken_at', chunk_time_interval => INTERVAL '1 week', if_not_exists => TRUE, migrate_data => TRUE)",
+        );
+    }
+
+    #[test]
+    fn continuous_aggregate() {
+        let sql = select((time_bucket("INTERVAL '1 hour'", "taken_at"), "max(value)"))
+            .from("Reading")
+            .group_by("1")
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "SELECT time_bucket(INTERVAL '1 hour', taken_at), max(value) FROM Reading GROUP BY 1",
+        );
+
+        let sql = add_continuous_aggregate_policy(
+            "Hourly",
+            "INTERVAL '1 day'",
+            "NULL",
+            "INTERVAL '1 hour'",
+        )
+        .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            "SELECT add_continuous_aggregate_policy('Hourly', start_offset => INTERVAL '1 day', end_offset => NULL, schedule_interval => INTERVAL '1 hour')",
+        );
+    }
+}
@@ -1,8 +1,26 @@
 //! PostgreSQL-specific tools to make your life easier
 
+mod cached;
+mod fingerprint;
+mod identifiers;
+mod lint;
 mod parameters;
+mod render;
+mod set_local;
+mod template;
+
+#[cfg(feature = "test-helpers")]
+pub mod testing;
 
 #[cfg(test)]
 pub mod tests;
 
+pub(crate) use lint::find_empty_in_list;
+
+pub use crate::tools::{items, Items};
+pub use cached::Cached;
+pub use identifiers::{checked_ident, quote_ident, InvalidIdentifier};
 pub use parameters::Parameters;
+pub use render::Render;
+pub use set_local::SetLocal;
+pub use template::{slot, Template};
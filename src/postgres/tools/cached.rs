@@ -0,0 +1,82 @@
+use std::fmt::{self, Display, Formatter};
+use std::sync::Arc;
+
+use super::Render;
+
+/// Statement rendered once, together with its SQL, created with [`Render::cached`]
+///
+/// Rendering it again, or cloning its SQL, doesn't re-render the statement. To change the statement,
+/// take it out with [`into_inner`][Cached::into_inner] and cache it anew when done.
+///
+/// ```
+/// use std::sync::Arc;
+/// use scooby::postgres::{select, Render};
+///
+/// let query = select("*").from("City").where_("id = $1").cached();
+///
+/// let a = query.sql();
+/// let b = query.sql();
+///
+/// assert_eq!(&*a, "SELECT * FROM City WHERE id = $1");
+/// assert!(Arc::ptr_eq(&a, &b));
+///
+/// let changed = query.into_inner().limit(1).cached();
+/// assert_eq!(changed.to_string(), "SELECT * FROM City WHERE id = $1 LIMIT 1");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cached<S> {
+    statement: S,
+    sql: Arc<str>,
+}
+
+impl<S: Render> Cached<S> {
+    pub(crate) fn new(statement: S) -> Cached<S> {
+        let sql = Arc::from(statement.to_string());
+        Cached { statement, sql }
+    }
+
+    /// Get the rendered SQL, shared between all callers
+    pub fn sql(&self) -> Arc<str> {
+        Arc::clone(&self.sql)
+    }
+
+    /// Get the statement
+    pub fn statement(&self) -> &S {
+        &self.statement
+    }
+
+    /// Take the statement out, e.g. to change it
+    pub fn into_inner(self) -> S {
+        self.statement
+    }
+}
+
+impl<S> Display for Cached<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.sql)
+    }
+}
+
+impl<S> AsRef<str> for Cached<S> {
+    fn as_ref(&self) -> &str {
+        &self.sql
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{update, Render};
+
+    #[test]
+    fn rendered_once() {
+        let statement = update("Dummy").set("x", "$1").where_("id = $2").cached();
+        let copy = statement.clone();
+
+        assert!(Arc::ptr_eq(&statement.sql(), &copy.sql()));
+        assert_eq!(statement.as_ref(), statement.statement().to_string());
+        assert_correct_postgresql(statement.as_ref(), "UPDATE Dummy SET x = $1 WHERE id = $2");
+    }
+}
@@ -0,0 +1,248 @@
+use super::lint::{dollar_tag_end, is_identifier_byte, skip_block_comment, skip_quoted};
+
+/// Normalize SQL to its shape: collapse whitespace, drop comments, and replace literals with `?`
+///
+/// Parameters such as `$1` and quoted identifiers are kept as they are.
+pub(crate) fn normalize(sql: &str) -> String {
+    replace_literals(sql, false).trim_end().to_owned()
+}
+
+/// Replace literals and contents of comments in SQL with `?`, keeping whitespace, parameters and quoted identifiers
+/// as they are
+pub(crate) fn redact(sql: &str) -> String {
+    replace_literals(sql, true)
+}
+
+fn replace_literals(sql: &str, keep_layout: bool) -> String {
+    let bytes = sql.as_bytes();
+    let mut replaced = String::with_capacity(sql.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let byte = bytes[i];
+        let follows_identifier = i > 0 && is_identifier_byte(bytes[i - 1]);
+
+        if byte.is_ascii_whitespace() {
+            let start = i;
+            while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+                i += 1;
+            }
+            if keep_layout {
+                replaced.push_str(&sql[start..i]);
+            } else {
+                push_space(&mut replaced);
+            }
+            continue;
+        }
+
+        let literal_end = match byte {
+            // Comments may carry values too, e.g. annotations, so only their delimiters are kept
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i = skip_block_comment(bytes, i).unwrap_or(bytes.len());
+                if keep_layout {
+                    replaced.push_str("/* ? */");
+                } else {
+                    push_space(&mut replaced);
+                }
+                continue;
+            }
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                i = sql[i..].find('\n').map_or(bytes.len(), |offset| i + offset);
+                if keep_layout {
+                    replaced.push_str("-- ?");
+                } else {
+                    push_space(&mut replaced);
+                }
+                continue;
+            }
+            b'\'' => {
+                let escapes = follows_identifier
+                    && matches!(bytes[i - 1], b'E' | b'e')
+                    && (i < 2 || !is_identifier_byte(bytes[i - 2]));
+                if escapes {
+                    replaced.pop();
+                }
+                skip_quoted(bytes, i, b'\'', escapes)
+            }
+            b'"' => {
+                let end = skip_quoted(bytes, i, b'"', false).unwrap_or(bytes.len());
+                replaced.push_str(&sql[i..end]);
+                i = end;
+                continue;
+            }
+            b'$' if !follows_identifier => match dollar_tag_end(bytes, i) {
+                Some(tag_end) => {
+                    let tag = &sql[i..tag_end];
+                    sql[tag_end..]
+                        .find(tag)
+                        .map(|offset| tag_end + offset + tag.len())
+                }
+                None => {
+                    let end = skip_digits(bytes, i + 1);
+                    replaced.push_str(&sql[i..end]);
+                    i = end;
+                    continue;
+                }
+            },
+            b'0'..=b'9' if !follows_identifier => Some(skip_number(bytes, i)),
+            _ => {
+                let end = i + utf8_len(byte);
+                replaced.push_str(&sql[i..end]);
+                i = end;
+                continue;
+            }
+        };
+
+        replaced.push('?');
+        i = literal_end.unwrap_or(bytes.len());
+    }
+
+    replaced
+}
+
+/// Hash normalized SQL with 64-bit FNV-1a, which unlike `std` hashers is stable across Rust versions
+pub(crate) fn fingerprint(normalized: &str) -> u64 {
+    normalized
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        })
+}
+
+fn push_space(normalized: &mut String) {
+    if !normalized.is_empty() && !normalized.ends_with(' ') {
+        normalized.push(' ');
+    }
+}
+
+fn skip_digits(bytes: &[u8], mut i: usize) -> usize {
+    while i < bytes.len() && bytes[i].is_ascii_digit() {
+        i += 1;
+    }
+    i
+}
+
+fn skip_number(bytes: &[u8], start: usize) -> usize {
+    let mut i = skip_digits(bytes, start);
+
+    if bytes.get(i) == Some(&b'.') {
+        i = skip_digits(bytes, i + 1);
+    }
+
+    if matches!(bytes.get(i), Some(b'e') | Some(b'E')) {
+        let exponent = match bytes.get(i + 1) {
+            Some(b'+') | Some(b'-') => i + 2,
+            _ => i + 1,
+        };
+        if matches!(bytes.get(exponent), Some(byte) if byte.is_ascii_digit()) {
+            i = skip_digits(bytes, exponent);
+        }
+    }
+
+    i
+}
+
+fn utf8_len(first_byte: u8) -> usize {
+    match first_byte {
+        0x00..=0x7f => 1,
+        0xc0..=0xdf => 2,
+        0xe0..=0xef => 3,
+        _ => 4,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fingerprint, normalize, redact};
+
+    use crate::postgres::{insert_into, select, Render};
+
+    #[test]
+    fn literals_and_whitespace() {
+        let cases = [
+            (
+                "SELECT  1,\n\t2.5e-3, 'it''s', E'a\\'b', $$x$$",
+                "SELECT ?, ?, ?, ?, ?",
+            ),
+            (
+                "SELECT t1.a1, $1, $12 FROM t1",
+                "SELECT t1.a1, $1, $12 FROM t1",
+            ),
+            (
+                "SELECT \"1 'a'\" /* x */ FROM /* y */ t",
+                "SELECT \"1 'a'\" FROM t",
+            ),
+            (
+                "SELECT a -- don't log\nFROM t WHERE b = 'x' --",
+                "SELECT a FROM t WHERE b = ?",
+            ),
+            ("/*+ SeqScan(t) */ SELECT été FROM t", "SELECT été FROM t"),
+        ];
+
+        for (sql, expected) in cases {
+            assert_eq!(normalize(sql), expected, "{}", sql);
+        }
+    }
+
+    #[test]
+    fn redacted_literals() {
+        let cases = [
+            (
+                "SELECT  1,\n\t2.5e-3, 'it''s', E'a\\'b', $tag$x$tag$ ",
+                "SELECT  ?,\n\t?, ?, ?, ? ",
+            ),
+            (
+                "SELECT t1.a1, \"x'y\", $1 FROM t1 WHERE d = DATE '2024-01-31' /* id:42 */",
+                "SELECT t1.a1, \"x'y\", $1 FROM t1 WHERE d = DATE ? /* ? */",
+            ),
+            (
+                "SELECT a -- don't log\nFROM t WHERE b = 'x'",
+                "SELECT a -- ?\nFROM t WHERE b = ?",
+            ),
+        ];
+
+        for (sql, expected) in cases {
+            assert_eq!(redact(sql), expected, "{}", sql);
+        }
+    }
+
+    #[test]
+    fn redacted_comments() {
+        let statement = select("*")
+            .from("Person")
+            .where_("age > 30")
+            .comment("user", "alice@example.com");
+
+        assert_eq!(
+            statement.to_redacted_string(),
+            "SELECT * FROM Person WHERE age > ? /* ? */"
+        );
+    }
+
+    #[test]
+    fn stable_fingerprint() {
+        assert_eq!(fingerprint(""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fingerprint("a"), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn pretty_and_plain_match() {
+        let statement = insert_into("Dummy")
+            .columns(("a", "b"))
+            .values([("1", "'x'"), ("2", "'y'")])
+            .returning("id");
+
+        assert_eq!(
+            normalize(&format!("{:#}", statement)),
+            statement.normalized()
+        );
+        assert_eq!(
+            statement.normalized(),
+            "INSERT INTO Dummy (a, b) VALUES (?, ?), (?, ?) RETURNING id"
+        );
+        assert_eq!(
+            select("*").from("t").where_("x = 1").fingerprint(),
+            select("*").from("t").where_("x = 2").fingerprint()
+        );
+    }
+}
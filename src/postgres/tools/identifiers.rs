@@ -0,0 +1,278 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use crate::tools::quoted;
+
+/// Quote an identifier, such as a table, column or alias name, escaping any double quotes in it
+///
+/// Unlike PostgreSQL's own `quote_ident`, this always quotes, so the case of letters is always preserved,
+/// and reserved words become valid names.
+///
+/// To have all names of a table and its columns quoted, declare it with
+/// [`Table::quoted`][crate::postgres::schema::Table::quoted] instead.
+///
+/// ```
+/// use scooby::postgres::{select, quote_ident, Aliasable};
+///
+/// let sql = select(quote_ident("order").as_(&quote_ident("Order")))
+///     .from(quote_ident("user"))
+///     .to_string();
+///
+/// assert_eq!(sql, r#"SELECT "order" AS "Order" FROM "user""#);
+/// assert_eq!(quote_ident(r#"weird"name"#), r#""weird""name""#);
+/// ```
+pub fn quote_ident(identifier: impl AsRef<str>) -> String {
+    quoted(identifier.as_ref()).to_string()
+}
+
+/// Check that a string is a plain unquoted identifier, such as a table, column or alias name
+///
+/// Meant for names that come from configuration or other outside sources. Only letters, digits,
+/// underscores and `$` are accepted, and the name may not start with a digit or `$`. Everything else,
+/// including whitespace, quotes, semicolons and dots, is rejected, so qualify checked names with
+/// [`Qualifiable::in_schema`][crate::postgres::Qualifiable::in_schema] or
+/// [`Column::from`][crate::postgres::general::Column] pairs afterwards.
+///
+/// Reserved keywords such as `user` or `order` are rejected too, since they are not valid names unless quoted
+/// with [`quote_ident`], and so are names longer than 63 bytes, which PostgreSQL would silently truncate.
+///
+/// ```
+/// use scooby::postgres::{checked_ident, select, Qualifiable};
+///
+/// let table = checked_ident("Person").unwrap().in_schema(checked_ident("app").unwrap());
+/// assert_eq!(select("*").from(table).to_string(), "SELECT * FROM app.Person");
+///
+/// assert!(checked_ident("Person; DROP TABLE Person").is_err());
+/// assert!(checked_ident(r#"Person""#).is_err());
+/// assert!(checked_ident("").is_err());
+/// assert!(checked_ident("user").is_err());
+/// ```
+pub fn checked_ident(identifier: impl Into<String>) -> Result<String, InvalidIdentifier> {
+    let identifier = identifier.into();
+    let mut chars = identifier.chars();
+
+    let is_valid = match chars.next() {
+        Some(first) => {
+            (first.is_alphabetic() || first == '_')
+                && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+        }
+        None => false,
+    };
+
+    if is_valid && identifier.len() <= MAX_IDENTIFIER_LEN && !is_reserved(&identifier) {
+        Ok(identifier)
+    } else {
+        Err(InvalidIdentifier { identifier })
+    }
+}
+
+/// Longest identifier PostgreSQL keeps without truncating it, `NAMEDATALEN - 1` in a default build
+const MAX_IDENTIFIER_LEN: usize = 63;
+
+/// Keywords that can't be used as table or column names unquoted, sorted for binary search
+///
+/// These are reserved keywords of PostgreSQL, including ones that may only be function or type names.
+const RESERVED_KEYWORDS: &[&str] = &[
+    "ALL",
+    "ANALYSE",
+    "ANALYZE",
+    "AND",
+    "ANY",
+    "ARRAY",
+    "AS",
+    "ASC",
+    "ASYMMETRIC",
+    "AUTHORIZATION",
+    "BINARY",
+    "BOTH",
+    "CASE",
+    "CAST",
+    "CHECK",
+    "COLLATE",
+    "COLLATION",
+    "COLUMN",
+    "CONCURRENTLY",
+    "CONSTRAINT",
+    "CREATE",
+    "CROSS",
+    "CURRENT_CATALOG",
+    "CURRENT_DATE",
+    "CURRENT_ROLE",
+    "CURRENT_SCHEMA",
+    "CURRENT_TIME",
+    "CURRENT_TIMESTAMP",
+    "CURRENT_USER",
+    "DEFAULT",
+    "DEFERRABLE",
+    "DESC",
+    "DISTINCT",
+    "DO",
+    "ELSE",
+    "END",
+    "EXCEPT",
+    "FALSE",
+    "FETCH",
+    "FOR",
+    "FOREIGN",
+    "FREEZE",
+    "FROM",
+    "FULL",
+    "GRANT",
+    "GROUP",
+    "HAVING",
+    "ILIKE",
+    "IN",
+    "INITIALLY",
+    "INNER",
+    "INTERSECT",
+    "INTO",
+    "IS",
+    "ISNULL",
+    "JOIN",
+    "LATERAL",
+    "LEADING",
+    "LEFT",
+    "LIKE",
+    "LIMIT",
+    "LOCALTIME",
+    "LOCALTIMESTAMP",
+    "NATURAL",
+    "NOT",
+    "NOTNULL",
+    "NULL",
+    "OFFSET",
+    "ON",
+    "ONLY",
+    "OR",
+    "ORDER",
+    "OUTER",
+    "OVERLAPS",
+    "PLACING",
+    "PRIMARY",
+    "REFERENCES",
+    "RETURNING",
+    "RIGHT",
+    "SELECT",
+    "SESSION_USER",
+    "SIMILAR",
+    "SOME",
+    "SYMMETRIC",
+    "SYSTEM_USER",
+    "TABLE",
+    "TABLESAMPLE",
+    "THEN",
+    "TO",
+    "TRAILING",
+    "TRUE",
+    "UNION",
+    "UNIQUE",
+    "USER",
+    "USING",
+    "VARIADIC",
+    "VERBOSE",
+    "WHEN",
+    "WHERE",
+    "WINDOW",
+    "WITH",
+];
+
+fn is_reserved(identifier: &str) -> bool {
+    RESERVED_KEYWORDS
+        .binary_search(&identifier.to_ascii_uppercase().as_str())
+        .is_ok()
+}
+
+/// Error of [`checked_ident`] rejecting a string that is not a plain identifier
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InvalidIdentifier {
+    /// The string that was rejected
+    pub identifier: String,
+}
+
+impl Display for InvalidIdentifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} is not a valid identifier", self.identifier)
+    }
+}
+
+impl Error for InvalidIdentifier {}
+
+#[cfg(test)]
+mod tests {
+    use super::{checked_ident, InvalidIdentifier};
+
+    use crate::postgres::general::Column;
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{delete_from, insert_into, quote_ident, select, update, Aliasable};
+
+    #[test]
+    fn checked() {
+        let sql = select("count(*)".as_checked("ÄÖ$1").unwrap())
+            .from(checked_ident("_Person").unwrap())
+            .to_string();
+
+        assert_correct_postgresql(&sql, "SELECT count(*) AS ÄÖ$1 FROM _Person");
+
+        let sql = update("Person")
+            .set(Column::checked("nom_de_famille").unwrap(), "$1")
+            .to_string();
+
+        assert_correct_postgresql(&sql, "UPDATE Person SET nom_de_famille = $1");
+    }
+
+    #[test]
+    fn rejected() {
+        for identifier in &[
+            "", "1st", "$1", "a b", "a;", "a'", "a\"b", "a.b", "a--", "a/*", "a\0",
+        ] {
+            assert_eq!(
+                checked_ident(*identifier),
+                Err(InvalidIdentifier {
+                    identifier: identifier.to_string()
+                })
+            );
+        }
+
+        assert!(Column::checked("a b").is_err());
+        assert!("x".as_checked("a b").is_err());
+    }
+
+    #[test]
+    fn rejected_keywords_and_long_names() {
+        for identifier in &["user", "Select", "ORDER", "from", "current_user", "left"] {
+            assert!(checked_ident(*identifier).is_err(), "{}", identifier);
+        }
+
+        assert!(checked_ident("users").is_ok());
+        assert!(checked_ident("a".repeat(63)).is_ok());
+        assert!(checked_ident("a".repeat(64)).is_err());
+        assert!(checked_ident("ä".repeat(32)).is_err());
+    }
+
+    #[test]
+    fn reserved_words() {
+        let sql = insert_into(quote_ident("user"))
+            .columns((quote_ident("order"), quote_ident("Group")))
+            .values([("$1", "$2")])
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            r#"INSERT INTO "user" ("order", "Group") VALUES ($1, $2)"#,
+        );
+
+        let sql = update(quote_ident("user"))
+            .set(quote_ident("order"), "$1")
+            .where_(format!("{} = $2", quote_ident("select")))
+            .to_string();
+
+        assert_correct_postgresql(
+            &sql,
+            r#"UPDATE "user" SET "order" = $1 WHERE "select" = $2"#,
+        );
+
+        let sql = delete_from(quote_ident("user")).to_string();
+
+        assert_correct_postgresql(&sql, r#"DELETE FROM "user""#);
+    }
+}
@@ -0,0 +1,221 @@
+//! Scanning of rendered SQL for fragments that are unlikely to be intended
+
+/// Find the first suspicious thing in the given SQL, returning its byte position and a description
+///
+/// Flags statement separators and line comments outside of strings, quoted identifiers and block comments,
+/// as well as strings, quoted identifiers and block comments that are never closed.
+pub(crate) fn find_suspicious(sql: &str) -> Option<(usize, &'static str)> {
+    let bytes = sql.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let start = i;
+
+        match bytes[i] {
+            b';' => return Some((start, "semicolon outside of a string")),
+            b'-' if bytes.get(i + 1) == Some(&b'-') => return Some((start, "line comment")),
+            b'/' if bytes.get(i + 1) == Some(&b'*') => match skip_block_comment(bytes, i) {
+                Some(end) => i = end,
+                None => return Some((start, "unterminated block comment")),
+            },
+            b'\'' => match skip_quoted(bytes, i, b'\'', has_escapes(bytes, i)) {
+                Some(end) => i = end,
+                None => return Some((start, "unterminated string literal")),
+            },
+            b'"' => match skip_quoted(bytes, i, b'"', false) {
+                Some(end) => i = end,
+                None => return Some((start, "unterminated quoted identifier")),
+            },
+            b'$' if i == 0 || !is_identifier_byte(bytes[i - 1]) => {
+                if let Some(tag_end) = dollar_tag_end(bytes, i) {
+                    let tag = &sql[i..tag_end];
+                    match sql[tag_end..].find(tag) {
+                        Some(offset) => i = tag_end + offset + tag.len(),
+                        None => return Some((start, "unterminated dollar-quoted string")),
+                    }
+                } else {
+                    i += 1;
+                }
+            }
+            _ => i += 1,
+        }
+    }
+
+    None
+}
+
+/// Find the first `IN ()` list without any items in the given SQL, returning its byte position
+///
+/// Strings, quoted identifiers and comments are skipped. Scanning stops at the first one that is never closed,
+/// which [`find_suspicious`] reports instead.
+pub(crate) fn find_empty_in_list(sql: &str) -> Option<usize> {
+    let bytes = sql.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let next = match bytes[i] {
+            b'-' if bytes.get(i + 1) == Some(&b'-') => sql[i..].find('\n').map(|offset| i + offset),
+            b'/' if bytes.get(i + 1) == Some(&b'*') => skip_block_comment(bytes, i),
+            b'\'' => skip_quoted(bytes, i, b'\'', has_escapes(bytes, i)),
+            b'"' => skip_quoted(bytes, i, b'"', false),
+            b'$' if i == 0 || !is_identifier_byte(bytes[i - 1]) => match dollar_tag_end(bytes, i) {
+                Some(tag_end) => {
+                    let tag = &sql[i..tag_end];
+                    sql[tag_end..]
+                        .find(tag)
+                        .map(|offset| tag_end + offset + tag.len())
+                }
+                None => Some(i + 1),
+            },
+            _ if is_empty_in_list(bytes, i) => return Some(i),
+            _ => Some(i + 1),
+        };
+
+        i = next?;
+    }
+
+    None
+}
+
+/// Check whether an `IN` keyword followed by `()` starts at the given position
+fn is_empty_in_list(bytes: &[u8], start: usize) -> bool {
+    let is_keyword = (start == 0 || !is_identifier_byte(bytes[start - 1]))
+        && matches!(bytes.get(start..start + 2), Some(word) if word.eq_ignore_ascii_case(b"IN"));
+
+    if !is_keyword {
+        return false;
+    }
+
+    let mut rest = bytes[start + 2..]
+        .iter()
+        .filter(|byte| !byte.is_ascii_whitespace());
+
+    rest.next() == Some(&b'(') && rest.next() == Some(&b')')
+}
+
+/// Check whether a string starting at the given position is an `E'...'` one, where backslashes escape quotes
+fn has_escapes(bytes: &[u8], start: usize) -> bool {
+    start > 0
+        && matches!(bytes[start - 1], b'E' | b'e')
+        && (start < 2 || !is_identifier_byte(bytes[start - 2]))
+}
+
+pub(super) fn is_identifier_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'$' || byte >= 0x80
+}
+
+/// Position right after the closing quote, if there is one
+pub(super) fn skip_quoted(bytes: &[u8], start: usize, quote: u8, escapes: bool) -> Option<usize> {
+    let mut i = start + 1;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if escapes => i += 2,
+            byte if byte == quote => {
+                if bytes.get(i + 1) == Some(&quote) {
+                    i += 2;
+                } else {
+                    return Some(i + 1);
+                }
+            }
+            _ => i += 1,
+        }
+    }
+
+    None
+}
+
+/// Position right after the end of a possibly nested comment, if there is one
+pub(super) fn skip_block_comment(bytes: &[u8], start: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut i = start;
+
+    while i + 1 < bytes.len() {
+        match (bytes[i], bytes[i + 1]) {
+            (b'/', b'*') => {
+                depth += 1;
+                i += 2;
+            }
+            (b'*', b'/') => {
+                depth -= 1;
+                i += 2;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => i += 1,
+        }
+    }
+
+    None
+}
+
+/// Position right after an opening `$tag$` of a dollar-quoted string, if it is one rather than a `$1` parameter
+pub(super) fn dollar_tag_end(bytes: &[u8], start: usize) -> Option<usize> {
+    let mut i = start + 1;
+
+    if matches!(bytes.get(i), Some(byte) if byte.is_ascii_digit()) {
+        return None;
+    }
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'$' => return Some(i + 1),
+            byte if byte.is_ascii_alphanumeric() || byte == b'_' || byte >= 0x80 => i += 1,
+            _ => return None,
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_empty_in_list, find_suspicious};
+
+    #[test]
+    fn allowed() {
+        for sql in [
+            "SELECT 'a;b', \"x--y\", $1 WHERE a - -1 > 0",
+            "SELECT 'it''s', E'\\';' FROM \"we\"\"ird\"",
+            "SELECT 1 /* comment; /* nested */ -- */",
+            "SELECT $$ ; -- ' $$, $tag$ $$ $tag$, a$b$c",
+        ] {
+            assert_eq!(find_suspicious(sql), None, "{}", sql);
+        }
+    }
+
+    #[test]
+    fn suspicious() {
+        let cases = [
+            ("SELECT 1; DROP TABLE x", 8, "semicolon outside of a string"),
+            ("SELECT 1 -- comment", 9, "line comment"),
+            ("SELECT 'abc", 7, "unterminated string literal"),
+            ("SELECT 'a'' FROM x", 7, "unterminated string literal"),
+            ("SELECT E'\\'", 8, "unterminated string literal"),
+            ("SELECT \"abc", 7, "unterminated quoted identifier"),
+            ("SELECT /* /* */", 7, "unterminated block comment"),
+            ("SELECT $x$ abc", 7, "unterminated dollar-quoted string"),
+        ];
+
+        for (sql, position, problem) in cases {
+            assert_eq!(find_suspicious(sql), Some((position, problem)), "{}", sql);
+        }
+    }
+
+    #[test]
+    fn empty_in_lists() {
+        let cases = [
+            ("SELECT 1 WHERE id IN ()", Some(18)),
+            ("SELECT 1 WHERE id NOT in ( ) AND x IN ($1)", Some(22)),
+            ("SELECT 1 WHERE id IN ($1, $2)", None),
+            ("SELECT min() FROM x WHERE join()", None),
+            ("SELECT 'IN ()', \"in\"(), $$ IN () $$ -- IN ()", None),
+            ("SELECT 1 /* IN () */ WHERE x IN ()", Some(29)),
+        ];
+
+        for (sql, position) in cases {
+            assert_eq!(find_empty_in_list(sql), position, "{}", sql);
+        }
+    }
+}
@@ -1,4 +1,6 @@
-use crate::tools::{build_array, joined};
+use std::fmt::Write;
+
+use crate::tools::build_array;
 
 /// Generator of PostgreSQL parameter placeholders for dynamic statements with multiple values
 ///
@@ -23,6 +25,9 @@ pub struct Parameters {
 }
 
 impl Parameters {
+    /// Largest number of parameters PostgreSQL accepts in a single statement
+    pub const MAX: usize = 65535;
+
     /// Make a new Parameters counter, starting with 1
     pub fn new() -> Parameters {
         Parameters { current: 1 }
@@ -34,8 +39,10 @@ impl Parameters {
     }
 
     /// Return the current parameter placeholder in `$x` format, and increase the internal counter
+    #[allow(clippy::should_implement_trait)]
     pub fn next(&mut self) -> String {
-        let s = format!("${}", self.current);
+        let mut s = String::with_capacity(placeholder_len(self.current));
+        push_placeholder(&mut s, self.current);
         self.current += 1;
         s
     }
@@ -43,8 +50,16 @@ impl Parameters {
     /// Return N next placeholders in `$x, $y, $z` format
     pub fn next_n(&mut self, n: usize) -> String {
         let last = self.current + n;
-        // TODO: This allocates a bunch of strings totally unnecessarily
-        let s = joined((self.current..last).map(|x| format!("${}", x)), ", ").to_string();
+        let len: usize = (self.current..last).map(placeholder_len).sum();
+        let mut s = String::with_capacity(len + n.saturating_sub(1) * 2);
+
+        for x in self.current..last {
+            if x > self.current {
+                s.push_str(", ");
+            }
+            push_placeholder(&mut s, x);
+        }
+
         self.current = last;
         s
     }
@@ -55,6 +70,20 @@ impl Parameters {
     }
 }
 
+/// Length of the `$x` placeholder for the given number
+fn placeholder_len(mut number: usize) -> usize {
+    let mut len = 2;
+    while number >= 10 {
+        number /= 10;
+        len += 1;
+    }
+    len
+}
+
+fn push_placeholder(s: &mut String, number: usize) {
+    write!(s, "${}", number).expect("Writing to a String should never fail");
+}
+
 impl Default for Parameters {
     fn default() -> Self {
         Self::new()
@@ -97,6 +126,16 @@ mod tests {
         assert_eq!(params.next_n(3), "$4, $5, $6");
     }
 
+    #[test]
+    fn next_n_exact_capacity() {
+        let mut params = Parameters::starting_from(8);
+        let p = params.next_n(4);
+        assert_eq!(p, "$8, $9, $10, $11");
+        assert_eq!(p.capacity(), p.len());
+        assert_eq!(params.next(), "$12");
+        assert_eq!(params.next_n(0), "");
+    }
+
     #[test]
     fn next_arr() {
         let mut params = Parameters::new();
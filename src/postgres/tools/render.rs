@@ -0,0 +1,191 @@
+use std::fmt::{self, Display, Write};
+
+use crate::postgres::statements::{
+    AlterDefaultPrivileges, BuildError, CreateTable, DeleteFrom, DoBlock, InsertInto, Select,
+    SetRole, SetSessionAuthorization, TableCommand, Update, Values,
+};
+
+use super::fingerprint::{fingerprint, normalize, redact};
+use super::lint::find_suspicious;
+use super::{Cached, SetLocal};
+
+/// Rendering of statements into existing buffers
+///
+/// Implemented by all statements. Useful on hot paths to reuse one buffer instead of
+/// allocating a new `String` with every `to_string` call.
+///
+/// ```
+/// use scooby::postgres::{select, Render};
+///
+/// let mut buffer = String::new();
+///
+/// for table in ["Person", "City"] {
+///     let statement = select("*").from(table);
+///
+///     buffer.clear();
+///     buffer.reserve(statement.rendered_len());
+///     statement.write_to(&mut buffer).unwrap();
+///
+///     assert_eq!(buffer, format!("SELECT * FROM {}", table));
+/// }
+/// ```
+pub trait Render: Display {
+    /// Append the SQL of this statement to the given buffer
+    fn write_to<W: Write + ?Sized>(&self, buffer: &mut W) -> fmt::Result {
+        write!(buffer, "{}", self)
+    }
+
+    /// Calculate the exact length of the SQL of this statement in bytes, without allocating it
+    fn rendered_len(&self) -> usize {
+        let mut counter = Counter(0);
+        self.write_to(&mut counter)
+            .expect("Counting length of a statement should never fail");
+        counter.0
+    }
+
+    /// Render this statement, refusing SQL that looks like an injection or a mistake
+    ///
+    /// Strict rendering rejects `;` and `--` outside of strings, quoted identifiers and comments,
+    /// as well as strings, quoted identifiers and comments that are never closed. It's a guard rail
+    /// for statements assembled from many places, not a replacement for parameters.
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Render};
+    /// use scooby::postgres::statements::BuildError;
+    ///
+    /// let sql = select("*").from("Person").where_("name = 'a;b'").to_strict_string();
+    /// assert_eq!(sql.unwrap(), "SELECT * FROM Person WHERE name = 'a;b'");
+    ///
+    /// let name = "x'; DROP TABLE Person; --";
+    /// let sql = select("*").from("Person").where_(format!("name = '{}'", name)).to_strict_string();
+    /// assert_eq!(sql, Err(BuildError::SuspiciousSql { position: 37, problem: "semicolon outside of a string" }));
+    /// ```
+    fn to_strict_string(&self) -> Result<String, BuildError> {
+        let sql = self.to_string();
+
+        match find_suspicious(&sql) {
+            Some((position, problem)) => Err(BuildError::SuspiciousSql { position, problem }),
+            None => Ok(sql),
+        }
+    }
+
+    /// Render the shape of this statement: whitespace collapsed, comments dropped, and literals replaced with `?`
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Render};
+    ///
+    /// let statement = select("*").from("City").where_(("population > 1000000", "name = $1")).limit(10);
+    ///
+    /// assert_eq!(statement.normalized(), "SELECT * FROM City WHERE population > ? AND name = $1 LIMIT ?");
+    /// ```
+    fn normalized(&self) -> String {
+        normalize(&self.to_string())
+    }
+
+    /// Render this statement with literals replaced by `?`, e.g. to log it without leaking values
+    ///
+    /// Fragments of statements are plain SQL, so literals are recognized in the rendered SQL: string constants,
+    /// including escape and dollar-quoted ones, and numbers. Contents of comments, including annotations added with
+    /// `comment`, are replaced as well. Parameters, identifiers, keywords such as `NULL` and whitespace are kept as they are.
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Render};
+    ///
+    /// let statement = select(("id", "'vip'"))
+    ///     .from("Person")
+    ///     .where_(("email = 'alice@example.com'", "age > 30", "city_id = $1"));
+    ///
+    /// assert_eq!(
+    ///     statement.to_redacted_string(),
+    ///     "SELECT id, ? FROM Person WHERE email = ? AND age > ? AND city_id = $1",
+    /// );
+    /// ```
+    fn to_redacted_string(&self) -> String {
+        redact(&self.to_string())
+    }
+
+    /// Hash the [normalized][Render::normalized] shape of this statement, e.g. to group metrics by it
+    ///
+    /// The hash is the same for all statements differing only in literals, whitespace and comments,
+    /// and stays the same between versions of Rust and of this crate, unless its rendering changes.
+    ///
+    /// ```
+    /// use scooby::postgres::{select, Render};
+    ///
+    /// let a = select("*").from("City").where_("population > 10").comment("route", "/a");
+    /// let b = select("*").from("City").where_("population > 20");
+    /// let c = select("*").from("City").where_("area > 10");
+    ///
+    /// assert_eq!(a.fingerprint(), b.fingerprint());
+    /// assert_ne!(a.fingerprint(), c.fingerprint());
+    /// ```
+    fn fingerprint(&self) -> u64 {
+        fingerprint(&self.normalized())
+    }
+
+    /// Render this statement once, and keep its SQL around for repeated use, see [`Cached`]
+    fn cached(self) -> Cached<Self>
+    where
+        Self: Sized,
+    {
+        Cached::new(self)
+    }
+
+    /// Precede this statement with a `SET LOCAL name = 'value'` statement, see [`SetLocal`]
+    fn set_local(self, name: impl Into<String>, value: impl Into<String>) -> SetLocal<Self>
+    where
+        Self: Sized,
+    {
+        SetLocal::new(self, name.into(), value.into())
+    }
+}
+
+struct Counter(usize);
+
+impl Write for Counter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+impl Render for Select {}
+impl<V: Values> Render for InsertInto<V> {}
+impl Render for Update {}
+impl Render for DeleteFrom {}
+impl Render for CreateTable {}
+impl Render for TableCommand {}
+impl Render for DoBlock {}
+impl Render for SetRole {}
+impl Render for AlterDefaultPrivileges {}
+impl Render for SetSessionAuthorization {}
+
+#[cfg(test)]
+mod tests {
+    use super::Render;
+
+    use crate::postgres::{create_table, delete_from, insert_into, update};
+
+    #[test]
+    fn write_to_appends() {
+        let mut buffer = String::from("EXPLAIN ");
+
+        delete_from("Dummy")
+            .where_("x > 1")
+            .write_to(&mut buffer)
+            .unwrap();
+
+        assert_eq!(buffer, "EXPLAIN DELETE FROM Dummy WHERE x > 1");
+    }
+
+    #[test]
+    fn rendered_len() {
+        let insert = insert_into("Dummy").values([("a", "b")]);
+        let update = update("Dummy").set("x", "$1");
+        let create = create_table("Dummy").columns([("было", "text")]);
+
+        assert_eq!(insert.rendered_len(), insert.to_string().len());
+        assert_eq!(update.rendered_len(), update.to_string().len());
+        assert_eq!(create.rendered_len(), create.to_string().len());
+    }
+}
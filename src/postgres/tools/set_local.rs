@@ -0,0 +1,92 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::tools::literal;
+
+use super::Render;
+
+/// Statement preceded by `SET LOCAL` statements, created with [`Render::set_local`]
+///
+/// Renders into a script of several statements, to be executed together inside a transaction,
+/// as `SET LOCAL` settings only last until its end. Values are always rendered as string literals,
+/// which PostgreSQL accepts for settings of all types.
+///
+/// ```
+/// use scooby::postgres::{select, Render};
+///
+/// let script = select("*")
+///     .from("Sale")
+///     .set_local("statement_timeout", "30s")
+///     .set_local("work_mem", "256MB")
+///     .to_string();
+///
+/// assert_eq!(
+///     script,
+///     "SET LOCAL statement_timeout = '30s'; SET LOCAL work_mem = '256MB'; SELECT * FROM Sale",
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SetLocal<S> {
+    settings: Vec<(String, String)>,
+    statement: S,
+}
+
+impl<S: Render> SetLocal<S> {
+    pub(crate) fn new(statement: S, name: String, value: String) -> SetLocal<S> {
+        SetLocal {
+            settings: vec![(name, value)],
+            statement,
+        }
+    }
+
+    /// Add another `SET LOCAL` statement
+    pub fn set_local(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.settings.push((name.into(), value.into()));
+        self
+    }
+
+    /// Get the statement
+    pub fn statement(&self) -> &S {
+        &self.statement
+    }
+
+    /// Take the statement out, without its settings
+    pub fn into_inner(self) -> S {
+        self.statement
+    }
+}
+
+impl<S: Render> Display for SetLocal<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let separator = if f.alternate() { ";\n" } else { "; " };
+
+        for (name, value) in &self.settings {
+            write!(f, "SET LOCAL {} = {}{}", name, literal(value), separator)?;
+        }
+
+        self.statement.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{update, Render};
+
+    #[test]
+    fn set_local() {
+        let script = update("Dummy")
+            .set("x", "1")
+            .allow_full_table()
+            .set_local("application_name", "it's me");
+
+        assert_eq!(
+            script.to_string(),
+            "SET LOCAL application_name = 'it''s me'; UPDATE Dummy SET x = 1"
+        );
+        assert_eq!(
+            format!("{:#}", script),
+            "SET LOCAL application_name = 'it''s me';\nUPDATE Dummy\nSET\n    x = 1"
+        );
+        assert_correct_postgresql(&script.statement().to_string(), "UPDATE Dummy SET x = 1");
+    }
+}
@@ -0,0 +1,144 @@
+use crate::postgres::general::{raw, Raw};
+use crate::postgres::statements::BuildError;
+
+use super::Render;
+
+/// Character used to delimit slot names in rendered SQL, from the Unicode private use area,
+/// so it never appears in SQL by accident
+const SLOT_DELIMITER: char = '\u{E000}';
+
+/// Create a named slot, to be filled later in a [`Template`]
+///
+/// Usable anywhere a hand-written fragment of SQL is, such as table names, expressions and conditions.
+pub fn slot(name: &str) -> Raw {
+    raw(format!("{0}{1}{0}", SLOT_DELIMITER, name))
+}
+
+/// Statement rendered once with named [`slot`]s, to be filled with concrete SQL many times over
+///
+/// Filling a template only glues strings together, without running the builder again.
+///
+/// ```
+/// use scooby::postgres::select;
+/// use scooby::postgres::tools::{slot, Template};
+///
+/// let template = Template::new(&select("*").from(slot("table")).where_(("id = $1", slot("extra"))));
+///
+/// let sql = template.fill([("table", "tenant_1.Person"), ("extra", "deleted_at IS NULL")]).unwrap();
+/// assert_eq!(sql, "SELECT * FROM tenant_1.Person WHERE id = $1 AND deleted_at IS NULL");
+///
+/// let sql = template.fill([("table", "tenant_2.Person"), ("extra", "true")]).unwrap();
+/// assert_eq!(sql, "SELECT * FROM tenant_2.Person WHERE id = $1 AND true");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Template {
+    parts: Vec<Part>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Part {
+    Sql(String),
+    Slot(String),
+}
+
+impl Template {
+    /// Render the statement and remember where its slots are
+    pub fn new(statement: &impl Render) -> Template {
+        let sql = statement.to_string();
+        let mut parts = vec![];
+
+        for (index, piece) in sql.split(SLOT_DELIMITER).enumerate() {
+            if index % 2 == 1 {
+                parts.push(Part::Slot(piece.to_string()));
+            } else if !piece.is_empty() {
+                parts.push(Part::Sql(piece.to_string()));
+            }
+        }
+
+        Template { parts }
+    }
+
+    /// Get names of all slots of this template, in order of appearance
+    pub fn slots(&self) -> impl Iterator<Item = &str> {
+        self.parts.iter().filter_map(|part| match part {
+            Part::Slot(name) => Some(name.as_str()),
+            Part::Sql(_) => None,
+        })
+    }
+
+    /// Fill slots with the given SQL fragments by their names, and get the resulting SQL
+    ///
+    /// Fragments are included as they are. Returns [`BuildError::UnfilledSlot`] if any slot is left without one.
+    ///
+    /// ```
+    /// use scooby::postgres::delete_from;
+    /// use scooby::postgres::statements::BuildError;
+    /// use scooby::postgres::tools::{slot, Template};
+    ///
+    /// let template = Template::new(&delete_from(slot("table")));
+    ///
+    /// assert_eq!(template.fill([("table", "Person")]), Ok("DELETE FROM Person".to_string()));
+    /// assert_eq!(template.fill([("tabel", "Person")]), Err(BuildError::UnfilledSlot { name: "table".to_string() }));
+    /// ```
+    pub fn fill<K, V>(&self, values: impl IntoIterator<Item = (K, V)>) -> Result<String, BuildError>
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let values: Vec<(K, V)> = values.into_iter().collect();
+        let mut sql = String::new();
+
+        for part in &self.parts {
+            match part {
+                Part::Sql(piece) => sql.push_str(piece),
+                Part::Slot(name) => {
+                    let (_, value) = values
+                        .iter()
+                        .find(|(key, _)| key.as_ref() == name)
+                        .ok_or_else(|| BuildError::UnfilledSlot { name: name.clone() })?;
+                    sql.push_str(value.as_ref());
+                }
+            }
+        }
+
+        Ok(sql)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{slot, Template};
+
+    use crate::postgres::tools::tests::assert_correct_postgresql;
+    use crate::postgres::{insert_into, raw, update};
+
+    #[test]
+    fn repeated_and_adjacent_slots() {
+        let template = Template::new(
+            &update(slot("table"))
+                .set("x", slot("a"))
+                .set("y", raw(format!("{}{}", slot("a"), slot("b")))),
+        );
+
+        assert_eq!(
+            template.slots().collect::<Vec<_>>(),
+            ["table", "a", "a", "b"]
+        );
+
+        let sql = template
+            .fill([("table", "Dummy"), ("a", "1"), ("b", "0")])
+            .unwrap();
+        assert_correct_postgresql(&sql, "UPDATE Dummy SET x = 1, y = 10");
+    }
+
+    #[test]
+    fn no_slots() {
+        let template = Template::new(&insert_into("Dummy").default_values());
+
+        assert_eq!(template.slots().count(), 0);
+        assert_correct_postgresql(
+            &template.fill(Vec::<(&str, &str)>::new()).unwrap(),
+            "INSERT INTO Dummy DEFAULT VALUES",
+        );
+    }
+}
@@ -0,0 +1,47 @@
+//! Helpers to check statements against a real PostgreSQL server in tests
+//!
+//! Available with the `test-helpers` feature.
+//!
+//! ```no_run
+//! use postgres::{Client, NoTls};
+//! use scooby::postgres::select;
+//! use scooby::postgres::tools::testing::assert_correct_postgresql;
+//!
+//! let mut client = Client::connect("host=localhost user=postgres", NoTls).unwrap();
+//!
+//! let sql = select("*").from("Person").where_("id = $1").to_string();
+//!
+//! assert_correct_postgresql(&mut client, &sql, "SELECT * FROM Person WHERE id = $1");
+//! ```
+
+use postgres::{error::SqlState, Client, Error};
+
+/// Check that PostgreSQL accepts syntax of the given statement by preparing it, without executing
+///
+/// Only syntax errors and errors talking to the server are returned. Other errors, such as
+/// missing tables or columns, are ignored, so statements may be checked against an empty database.
+pub fn check_postgresql_syntax(client: &mut Client, sql: &str) -> Result<(), Error> {
+    match client.prepare(sql) {
+        Ok(_) => Ok(()),
+        Err(e) => match e.code() {
+            Some(&SqlState::SYNTAX_ERROR) | None => Err(e),
+            Some(_) => Ok(()),
+        },
+    }
+}
+
+/// Assert that a statement was rendered as expected, and that PostgreSQL accepts its syntax
+///
+/// # Panics
+///
+/// Panics if `sql` is not equal to `expected`, or if [`check_postgresql_syntax`] fails.
+pub fn assert_correct_postgresql(client: &mut Client, sql: &str, expected: &str) {
+    assert_eq!(sql, expected);
+
+    if let Err(e) = check_postgresql_syntax(client, sql) {
+        match e.as_db_error() {
+            Some(db_error) => panic!("invalid SQL syntax: {}", db_error.message()),
+            None => panic!("could not check SQL syntax: {}", e),
+        }
+    }
+}
@@ -4,6 +4,14 @@ mod into_iterator_of_same_type;
 mod into_non_zero_array;
 
 pub use arrays::{build_array, transform_array};
-pub use display::joined;
-pub use into_iterator_of_same_type::IntoIteratorOfSameType;
+pub use display::{clause_break, joined, listed, literal, parenthesized, quoted, write_clause};
+pub use into_iterator_of_same_type::{items, IntoIteratorOfSameType, Items};
 pub use into_non_zero_array::IntoNonZeroArray;
+
+/// Storage for items of a clause, which usually only has a few of them
+#[cfg(feature = "smallvec")]
+pub type ClauseVec<T> = smallvec::SmallVec<[T; 4]>;
+
+/// Storage for items of a clause, which usually only has a few of them
+#[cfg(not(feature = "smallvec"))]
+pub type ClauseVec<T> = Vec<T>;
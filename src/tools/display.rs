@@ -1,4 +1,4 @@
-use std::fmt::{self, Display, Formatter};
+use std::fmt::{self, Display, Formatter, Write};
 
 pub fn joined<I, T>(iter: I, sep: &str) -> Joined<'_, I::IntoIter, T>
 where
@@ -40,6 +40,118 @@ where
     }
 }
 
+/// Wrap something in parentheses when rendering, i.e. `(x)`
+pub fn parenthesized<T: Display>(inner: T) -> Parenthesized<T> {
+    Parenthesized(inner)
+}
+
+#[derive(Clone)]
+pub struct Parenthesized<T: Display>(T);
+
+impl<T: Display> Display for Parenthesized<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("(")?;
+        self.0.fmt(f)?;
+        f.write_str(")")
+    }
+}
+
+/// Render a string as a quoted identifier, i.e. `"x"`, doubling any quotes inside it
+pub fn quoted(identifier: &str) -> Quoted<'_> {
+    Quoted(identifier, '"')
+}
+
+/// Render a string as a string literal, i.e. `'x'`, doubling any quotes inside it
+pub fn literal(value: &str) -> Quoted<'_> {
+    Quoted(value, '\'')
+}
+
+pub struct Quoted<'a>(&'a str, char);
+
+impl<'a> Display for Quoted<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let Quoted(text, quote) = *self;
+
+        f.write_char(quote)?;
+        for (i, part) in text.split(quote).enumerate() {
+            if i > 0 {
+                f.write_char(quote)?;
+                f.write_char(quote)?;
+            }
+            f.write_str(part)?;
+        }
+        f.write_char(quote)
+    }
+}
+
+/// Write a break between clauses of a statement: a space, or a new line in alternate (`{:#}`) mode
+pub fn clause_break(f: &mut Formatter<'_>) -> fmt::Result {
+    if f.alternate() {
+        f.write_str("\n")
+    } else {
+        f.write_str(" ")
+    }
+}
+
+/// Write a clause consisting of a keyword and a list of items, preceded by a clause break
+///
+/// Items are listed inline, or one per line with indentation in alternate (`{:#}`) mode.
+pub fn write_clause<I, T>(f: &mut Formatter<'_>, keyword: &str, iter: I, sep: &str) -> fmt::Result
+where
+    I: IntoIterator<Item = T>,
+    I::IntoIter: Clone,
+    T: Display,
+{
+    clause_break(f)?;
+    f.write_str(keyword)?;
+    listed(iter, sep).fmt(f)
+}
+
+/// List of items of a clause, rendered as `" a, b"`, or as `"\n    a,\n    b"` in alternate (`{:#}`) mode
+pub fn listed<I, T>(iter: I, sep: &str) -> Listed<'_, I::IntoIter, T>
+where
+    I: IntoIterator<Item = T>,
+    I::IntoIter: Clone,
+    T: Display,
+{
+    Listed {
+        iter: iter.into_iter(),
+        sep,
+    }
+}
+
+pub struct Listed<'a, I, T>
+where
+    I: Iterator<Item = T> + Clone,
+    T: Display,
+{
+    iter: I,
+    sep: &'a str,
+}
+
+impl<'a, I, T> Display for Listed<'a, I, T>
+where
+    I: Iterator<Item = T> + Clone,
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            let sep = self.sep.trim_end();
+            for (i, item) in self.iter.clone().enumerate() {
+                if i > 0 {
+                    f.write_str(sep)?;
+                }
+                f.write_str("\n    ")?;
+                item.fmt(f)?;
+            }
+            Ok(())
+        } else {
+            f.write_str(" ")?;
+            joined(self.iter.clone(), self.sep).fmt(f)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -63,6 +175,43 @@ mod tests {
         assert_eq!(joined(iter, ", ").to_string(), "0, 1");
     }
 
+    struct Clause(Vec<&'static str>);
+
+    impl Display for Clause {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            f.write_str("X")?;
+            write_clause(f, "WHERE", &self.0, " AND ")
+        }
+    }
+
+    #[test]
+    fn parenthesized_joined() {
+        assert_eq!(parenthesized(joined(&[1, 2], ", ")).to_string(), "(1, 2)");
+    }
+
+    #[test]
+    fn quoted_identifier() {
+        assert_eq!(quoted("a").to_string(), "\"a\"");
+        assert_eq!(quoted("a\"b\"").to_string(), "\"a\"\"b\"\"\"");
+    }
+
+    #[test]
+    fn string_literal() {
+        assert_eq!(literal("it's").to_string(), "'it''s'");
+    }
+
+    #[test]
+    fn clause() {
+        let clause = Clause(vec!["a", "b"]);
+        assert_eq!(clause.to_string(), "X WHERE a AND b");
+    }
+
+    #[test]
+    fn pretty_clause() {
+        let clause = Clause(vec!["a", "b"]);
+        assert_eq!(format!("{:#}", clause), "X\nWHERE\n    a AND\n    b");
+    }
+
     #[test]
     fn a_few_items() {
         let iter = successors(Some(0), |x| Some(x + 1)).take(10);
@@ -56,292 +56,102 @@ where
     }
 }
 
-// Generic tuples
-// TODO: A macro
-
-impl<T> IntoIteratorOfSameType<T> for () {
-    type Iterator = Empty<T>;
-
-    fn into_some_iter(self) -> Self::Iterator {
-        empty()
-    }
-}
+// Vectors, for dynamically built lists
 
-impl<T, U> IntoIteratorOfSameType<T> for (U,)
+impl<T, U> IntoIteratorOfSameType<T> for Vec<U>
 where
     U: Into<T>,
 {
-    type Iterator = std::array::IntoIter<T, 1>;
-
-    fn into_some_iter(self) -> Self::Iterator {
-        IntoIterator::into_iter([self.0.into()])
-    }
-}
-
-impl<T, U1, U2> IntoIteratorOfSameType<T> for (U1, U2)
-where
-    U1: Into<T>,
-    U2: Into<T>,
-{
-    type Iterator = std::array::IntoIter<T, 2>;
-
-    fn into_some_iter(self) -> Self::Iterator {
-        IntoIterator::into_iter([self.0.into(), self.1.into()])
-    }
-}
-
-impl<T, U1, U2, U3> IntoIteratorOfSameType<T> for (U1, U2, U3)
-where
-    U1: Into<T>,
-    U2: Into<T>,
-    U3: Into<T>,
-{
-    type Iterator = std::array::IntoIter<T, 3>;
-
-    fn into_some_iter(self) -> Self::Iterator {
-        IntoIterator::into_iter([self.0.into(), self.1.into(), self.2.into()])
-    }
-}
-
-impl<T, U1, U2, U3, U4> IntoIteratorOfSameType<T> for (U1, U2, U3, U4)
-where
-    U1: Into<T>,
-    U2: Into<T>,
-    U3: Into<T>,
-    U4: Into<T>,
-{
-    type Iterator = std::array::IntoIter<T, 4>;
-
-    fn into_some_iter(self) -> Self::Iterator {
-        IntoIterator::into_iter([self.0.into(), self.1.into(), self.2.into(), self.3.into()])
-    }
-}
-
-impl<T, U1, U2, U3, U4, U5> IntoIteratorOfSameType<T> for (U1, U2, U3, U4, U5)
-where
-    U1: Into<T>,
-    U2: Into<T>,
-    U3: Into<T>,
-    U4: Into<T>,
-    U5: Into<T>,
-{
-    type Iterator = std::array::IntoIter<T, 5>;
+    type Iterator = Map<std::vec::IntoIter<U>, fn(U) -> T>;
 
     fn into_some_iter(self) -> Self::Iterator {
-        IntoIterator::into_iter([
-            self.0.into(),
-            self.1.into(),
-            self.2.into(),
-            self.3.into(),
-            self.4.into(),
-        ])
+        self.into_iter().map(U::into)
     }
 }
 
-impl<T, U1, U2, U3, U4, U5, U6> IntoIteratorOfSameType<T> for (U1, U2, U3, U4, U5, U6)
-where
-    U1: Into<T>,
-    U2: Into<T>,
-    U3: Into<T>,
-    U4: Into<T>,
-    U5: Into<T>,
-    U6: Into<T>,
-{
-    type Iterator = std::array::IntoIter<T, 6>;
-
-    fn into_some_iter(self) -> Self::Iterator {
-        IntoIterator::into_iter([
-            self.0.into(),
-            self.1.into(),
-            self.2.into(),
-            self.3.into(),
-            self.4.into(),
-            self.5.into(),
-        ])
-    }
-}
-
-impl<T, U1, U2, U3, U4, U5, U6, U7> IntoIteratorOfSameType<T> for (U1, U2, U3, U4, U5, U6, U7)
-where
-    U1: Into<T>,
-    U2: Into<T>,
-    U3: Into<T>,
-    U4: Into<T>,
-    U5: Into<T>,
-    U6: Into<T>,
-    U7: Into<T>,
-{
-    type Iterator = std::array::IntoIter<T, 7>;
-
-    fn into_some_iter(self) -> Self::Iterator {
-        IntoIterator::into_iter([
-            self.0.into(),
-            self.1.into(),
-            self.2.into(),
-            self.3.into(),
-            self.4.into(),
-            self.5.into(),
-            self.6.into(),
-        ])
-    }
-}
+// Any other iterators, which can't be accepted directly without conflicting with strings and tuples
 
-impl<T, U1, U2, U3, U4, U5, U6, U7, U8> IntoIteratorOfSameType<T>
-    for (U1, U2, U3, U4, U5, U6, U7, U8)
-where
-    U1: Into<T>,
-    U2: Into<T>,
-    U3: Into<T>,
-    U4: Into<T>,
-    U5: Into<T>,
-    U6: Into<T>,
-    U7: Into<T>,
-    U8: Into<T>,
-{
-    type Iterator = std::array::IntoIter<T, 8>;
+/// Items of a clause from any iterator, created with [`items`]
+#[derive(Debug, Clone)]
+pub struct Items<I>(I);
 
-    fn into_some_iter(self) -> Self::Iterator {
-        IntoIterator::into_iter([
-            self.0.into(),
-            self.1.into(),
-            self.2.into(),
-            self.3.into(),
-            self.4.into(),
-            self.5.into(),
-            self.6.into(),
-            self.7.into(),
-        ])
-    }
+/// Pass items of a clause from any iterator, such as a `.map(...)` over some data, without collecting them first
+///
+/// ```
+/// use scooby::postgres::select;
+/// use scooby::postgres::tools::items;
+///
+/// let filters = [("age", ">"), ("height", "<")];
+///
+/// let sql = select("*")
+///     .from("Person")
+///     .where_(items(filters.iter().map(|(column, op)| format!("{} {} $1", column, op))))
+///     .to_string();
+///
+/// assert_eq!(sql, "SELECT * FROM Person WHERE age > $1 AND height < $1");
+/// ```
+pub fn items<I: IntoIterator>(items: I) -> Items<I::IntoIter> {
+    Items(items.into_iter())
 }
 
-impl<T, U1, U2, U3, U4, U5, U6, U7, U8, U9> IntoIteratorOfSameType<T>
-    for (U1, U2, U3, U4, U5, U6, U7, U8, U9)
+impl<T, I> IntoIteratorOfSameType<T> for Items<I>
 where
-    U1: Into<T>,
-    U2: Into<T>,
-    U3: Into<T>,
-    U4: Into<T>,
-    U5: Into<T>,
-    U6: Into<T>,
-    U7: Into<T>,
-    U8: Into<T>,
-    U9: Into<T>,
+    I: Iterator,
+    I::Item: Into<T>,
 {
-    type Iterator = std::array::IntoIter<T, 9>;
+    type Iterator = Map<I, fn(I::Item) -> T>;
 
     fn into_some_iter(self) -> Self::Iterator {
-        IntoIterator::into_iter([
-            self.0.into(),
-            self.1.into(),
-            self.2.into(),
-            self.3.into(),
-            self.4.into(),
-            self.5.into(),
-            self.6.into(),
-            self.7.into(),
-            self.8.into(),
-        ])
+        self.0.map(Into::into)
     }
 }
 
-impl<T, U1, U2, U3, U4, U5, U6, U7, U8, U9, U10> IntoIteratorOfSameType<T>
-    for (U1, U2, U3, U4, U5, U6, U7, U8, U9, U10)
-where
-    U1: Into<T>,
-    U2: Into<T>,
-    U3: Into<T>,
-    U4: Into<T>,
-    U5: Into<T>,
-    U6: Into<T>,
-    U7: Into<T>,
-    U8: Into<T>,
-    U9: Into<T>,
-    U10: Into<T>,
-{
-    type Iterator = std::array::IntoIter<T, 10>;
-
-    fn into_some_iter(self) -> Self::Iterator {
-        IntoIterator::into_iter([
-            self.0.into(),
-            self.1.into(),
-            self.2.into(),
-            self.3.into(),
-            self.4.into(),
-            self.5.into(),
-            self.6.into(),
-            self.7.into(),
-            self.8.into(),
-            self.9.into(),
-        ])
-    }
-}
+// Generic tuples
 
-impl<T, U1, U2, U3, U4, U5, U6, U7, U8, U9, U10, U11> IntoIteratorOfSameType<T>
-    for (U1, U2, U3, U4, U5, U6, U7, U8, U9, U10, U10, U11)
-where
-    U1: Into<T>,
-    U2: Into<T>,
-    U3: Into<T>,
-    U4: Into<T>,
-    U5: Into<T>,
-    U6: Into<T>,
-    U7: Into<T>,
-    U8: Into<T>,
-    U9: Into<T>,
-    U10: Into<T>,
-    U11: Into<T>,
-{
-    type Iterator = std::array::IntoIter<T, 11>;
+impl<T> IntoIteratorOfSameType<T> for () {
+    type Iterator = Empty<T>;
 
     fn into_some_iter(self) -> Self::Iterator {
-        IntoIterator::into_iter([
-            self.0.into(),
-            self.1.into(),
-            self.2.into(),
-            self.3.into(),
-            self.4.into(),
-            self.5.into(),
-            self.6.into(),
-            self.7.into(),
-            self.8.into(),
-            self.9.into(),
-            self.10.into(),
-        ])
+        empty()
     }
 }
 
-impl<T, U1, U2, U3, U4, U5, U6, U7, U8, U9, U10, U11, U12> IntoIteratorOfSameType<T>
-    for (U1, U2, U3, U4, U5, U6, U7, U8, U9, U10, U10, U11, U12)
-where
-    U1: Into<T>,
-    U2: Into<T>,
-    U3: Into<T>,
-    U4: Into<T>,
-    U5: Into<T>,
-    U6: Into<T>,
-    U7: Into<T>,
-    U8: Into<T>,
-    U9: Into<T>,
-    U10: Into<T>,
-    U11: Into<T>,
-    U12: Into<T>,
-{
-    type Iterator = std::array::IntoIter<T, 12>;
-
-    fn into_some_iter(self) -> Self::Iterator {
-        IntoIterator::into_iter([
-            self.0.into(),
-            self.1.into(),
-            self.2.into(),
-            self.3.into(),
-            self.4.into(),
-            self.5.into(),
-            self.6.into(),
-            self.7.into(),
-            self.8.into(),
-            self.9.into(),
-            self.10.into(),
-            self.11.into(),
-        ])
-    }
-}
+macro_rules! impl_into_iterator_of_same_type_for_tuple {
+    ($n:literal; $($index:tt $u:ident),+) => {
+        impl<T, $($u),+> IntoIteratorOfSameType<T> for ($($u,)+)
+        where
+            $($u: Into<T>,)+
+        {
+            type Iterator = std::array::IntoIter<T, $n>;
+
+            fn into_some_iter(self) -> Self::Iterator {
+                IntoIterator::into_iter([$(self.$index.into()),+])
+            }
+        }
+    };
+}
+
+impl_into_iterator_of_same_type_for_tuple!(1; 0 U1);
+impl_into_iterator_of_same_type_for_tuple!(2; 0 U1, 1 U2);
+impl_into_iterator_of_same_type_for_tuple!(3; 0 U1, 1 U2, 2 U3);
+impl_into_iterator_of_same_type_for_tuple!(4; 0 U1, 1 U2, 2 U3, 3 U4);
+impl_into_iterator_of_same_type_for_tuple!(5; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5);
+impl_into_iterator_of_same_type_for_tuple!(6; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6);
+impl_into_iterator_of_same_type_for_tuple!(7; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7);
+impl_into_iterator_of_same_type_for_tuple!(8; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8);
+impl_into_iterator_of_same_type_for_tuple!(9; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9);
+impl_into_iterator_of_same_type_for_tuple!(10; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10);
+impl_into_iterator_of_same_type_for_tuple!(11; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11);
+impl_into_iterator_of_same_type_for_tuple!(12; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11, 11 U12);
+impl_into_iterator_of_same_type_for_tuple!(13; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11, 11 U12, 12 U13);
+impl_into_iterator_of_same_type_for_tuple!(14; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11, 11 U12, 12 U13, 13 U14);
+impl_into_iterator_of_same_type_for_tuple!(15; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11, 11 U12, 12 U13, 13 U14, 14 U15);
+impl_into_iterator_of_same_type_for_tuple!(16; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11, 11 U12, 12 U13, 13 U14, 14 U15, 15 U16);
+impl_into_iterator_of_same_type_for_tuple!(17; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11, 11 U12, 12 U13, 13 U14, 14 U15, 15 U16, 16 U17);
+impl_into_iterator_of_same_type_for_tuple!(18; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11, 11 U12, 12 U13, 13 U14, 14 U15, 15 U16, 16 U17, 17 U18);
+impl_into_iterator_of_same_type_for_tuple!(19; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11, 11 U12, 12 U13, 13 U14, 14 U15, 15 U16, 16 U17, 17 U18, 18 U19);
+impl_into_iterator_of_same_type_for_tuple!(20; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11, 11 U12, 12 U13, 13 U14, 14 U15, 15 U16, 16 U17, 17 U18, 18 U19, 19 U20);
+impl_into_iterator_of_same_type_for_tuple!(21; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11, 11 U12, 12 U13, 13 U14, 14 U15, 15 U16, 16 U17, 17 U18, 18 U19, 19 U20, 20 U21);
+impl_into_iterator_of_same_type_for_tuple!(22; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11, 11 U12, 12 U13, 13 U14, 14 U15, 15 U16, 16 U17, 17 U18, 18 U19, 19 U20, 20 U21, 21 U22);
+impl_into_iterator_of_same_type_for_tuple!(23; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11, 11 U12, 12 U13, 13 U14, 14 U15, 15 U16, 16 U17, 17 U18, 18 U19, 19 U20, 20 U21, 21 U22, 22 U23);
+impl_into_iterator_of_same_type_for_tuple!(24; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11, 11 U12, 12 U13, 13 U14, 14 U15, 15 U16, 16 U17, 17 U18, 18 U19, 19 U20, 20 U21, 21 U22, 22 U23, 23 U24);
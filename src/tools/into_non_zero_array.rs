@@ -1,3 +1,9 @@
+use super::transform_array;
+
+/// Things that can be converted into an array of a size known at compile time, which can't be empty
+///
+/// Implemented for single strings, arrays of 1 to 32 items and tuples of 1 to 24 items.
+/// Longer arrays are not supported, and have to go through dynamically sized alternatives instead.
 pub trait IntoNonZeroArray<T, const N: usize> {
     fn into_non_zero_array(self) -> [T; N];
 }
@@ -16,270 +22,63 @@ impl IntoNonZeroArray<String, 1> for &str {
     }
 }
 
-// Generic arrays
-
-impl<T, const N: usize> IntoNonZeroArray<T, N> for [T; N] {
-    fn into_non_zero_array(self) -> [T; N] {
-        if N == 0 {
-            panic!("Should not be creating 0-sized arrays");
-        }
+// Generic arrays, implemented for specific sizes so that empty ones are rejected at compile time
 
-        self
-    }
+macro_rules! impl_into_non_zero_array_for_arrays {
+    ($($n:literal)+) => {
+        $(
+            impl<T, U> IntoNonZeroArray<T, $n> for [U; $n]
+            where
+                U: Into<T>,
+            {
+                fn into_non_zero_array(self) -> [T; $n] {
+                    transform_array(self, U::into)
+                }
+            }
+        )+
+    };
 }
 
-// Generic tuples
-
-impl<T, U> IntoNonZeroArray<T, 1> for (U,)
-where
-    U: Into<T>,
-{
-    fn into_non_zero_array(self) -> [T; 1] {
-        [self.0.into()]
-    }
-}
-
-impl<T, U1, U2> IntoNonZeroArray<T, 2> for (U1, U2)
-where
-    U1: Into<T>,
-    U2: Into<T>,
-{
-    fn into_non_zero_array(self) -> [T; 2] {
-        [self.0.into(), self.1.into()]
-    }
-}
+impl_into_non_zero_array_for_arrays!(
+    1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+);
 
-impl<T, U1, U2, U3> IntoNonZeroArray<T, 3> for (U1, U2, U3)
-where
-    U1: Into<T>,
-    U2: Into<T>,
-    U3: Into<T>,
-{
-    fn into_non_zero_array(self) -> [T; 3] {
-        [self.0.into(), self.1.into(), self.2.into()]
-    }
-}
-
-impl<T, U1, U2, U3, U4> IntoNonZeroArray<T, 4> for (U1, U2, U3, U4)
-where
-    U1: Into<T>,
-    U2: Into<T>,
-    U3: Into<T>,
-    U4: Into<T>,
-{
-    fn into_non_zero_array(self) -> [T; 4] {
-        [self.0.into(), self.1.into(), self.2.into(), self.3.into()]
-    }
-}
-
-impl<T, U1, U2, U3, U4, U5> IntoNonZeroArray<T, 5> for (U1, U2, U3, U4, U5)
-where
-    U1: Into<T>,
-    U2: Into<T>,
-    U3: Into<T>,
-    U4: Into<T>,
-    U5: Into<T>,
-{
-    fn into_non_zero_array(self) -> [T; 5] {
-        [
-            self.0.into(),
-            self.1.into(),
-            self.2.into(),
-            self.3.into(),
-            self.4.into(),
-        ]
-    }
-}
-
-impl<T, U1, U2, U3, U4, U5, U6> IntoNonZeroArray<T, 6> for (U1, U2, U3, U4, U5, U6)
-where
-    U1: Into<T>,
-    U2: Into<T>,
-    U3: Into<T>,
-    U4: Into<T>,
-    U5: Into<T>,
-    U6: Into<T>,
-{
-    fn into_non_zero_array(self) -> [T; 6] {
-        [
-            self.0.into(),
-            self.1.into(),
-            self.2.into(),
-            self.3.into(),
-            self.4.into(),
-            self.5.into(),
-        ]
-    }
-}
-
-impl<T, U1, U2, U3, U4, U5, U6, U7> IntoNonZeroArray<T, 7> for (U1, U2, U3, U4, U5, U6, U7)
-where
-    U1: Into<T>,
-    U2: Into<T>,
-    U3: Into<T>,
-    U4: Into<T>,
-    U5: Into<T>,
-    U6: Into<T>,
-    U7: Into<T>,
-{
-    fn into_non_zero_array(self) -> [T; 7] {
-        [
-            self.0.into(),
-            self.1.into(),
-            self.2.into(),
-            self.3.into(),
-            self.4.into(),
-            self.5.into(),
-            self.6.into(),
-        ]
-    }
-}
-
-impl<T, U1, U2, U3, U4, U5, U6, U7, U8> IntoNonZeroArray<T, 8> for (U1, U2, U3, U4, U5, U6, U7, U8)
-where
-    U1: Into<T>,
-    U2: Into<T>,
-    U3: Into<T>,
-    U4: Into<T>,
-    U5: Into<T>,
-    U6: Into<T>,
-    U7: Into<T>,
-    U8: Into<T>,
-{
-    fn into_non_zero_array(self) -> [T; 8] {
-        [
-            self.0.into(),
-            self.1.into(),
-            self.2.into(),
-            self.3.into(),
-            self.4.into(),
-            self.5.into(),
-            self.6.into(),
-            self.7.into(),
-        ]
-    }
-}
-
-impl<T, U1, U2, U3, U4, U5, U6, U7, U8, U9> IntoNonZeroArray<T, 9>
-    for (U1, U2, U3, U4, U5, U6, U7, U8, U9)
-where
-    U1: Into<T>,
-    U2: Into<T>,
-    U3: Into<T>,
-    U4: Into<T>,
-    U5: Into<T>,
-    U6: Into<T>,
-    U7: Into<T>,
-    U8: Into<T>,
-    U9: Into<T>,
-{
-    fn into_non_zero_array(self) -> [T; 9] {
-        [
-            self.0.into(),
-            self.1.into(),
-            self.2.into(),
-            self.3.into(),
-            self.4.into(),
-            self.5.into(),
-            self.6.into(),
-            self.7.into(),
-            self.8.into(),
-        ]
-    }
-}
-
-impl<T, U1, U2, U3, U4, U5, U6, U7, U8, U9, U10> IntoNonZeroArray<T, 10>
-    for (U1, U2, U3, U4, U5, U6, U7, U8, U9, U10)
-where
-    U1: Into<T>,
-    U2: Into<T>,
-    U3: Into<T>,
-    U4: Into<T>,
-    U5: Into<T>,
-    U6: Into<T>,
-    U7: Into<T>,
-    U8: Into<T>,
-    U9: Into<T>,
-    U10: Into<T>,
-{
-    fn into_non_zero_array(self) -> [T; 10] {
-        [
-            self.0.into(),
-            self.1.into(),
-            self.2.into(),
-            self.3.into(),
-            self.4.into(),
-            self.5.into(),
-            self.6.into(),
-            self.7.into(),
-            self.8.into(),
-            self.9.into(),
-        ]
-    }
-}
-
-impl<T, U1, U2, U3, U4, U5, U6, U7, U8, U9, U10, U11> IntoNonZeroArray<T, 11>
-    for (U1, U2, U3, U4, U5, U6, U7, U8, U9, U10, U11)
-where
-    U1: Into<T>,
-    U2: Into<T>,
-    U3: Into<T>,
-    U4: Into<T>,
-    U5: Into<T>,
-    U6: Into<T>,
-    U7: Into<T>,
-    U8: Into<T>,
-    U9: Into<T>,
-    U10: Into<T>,
-    U11: Into<T>,
-{
-    fn into_non_zero_array(self) -> [T; 11] {
-        [
-            self.0.into(),
-            self.1.into(),
-            self.2.into(),
-            self.3.into(),
-            self.4.into(),
-            self.5.into(),
-            self.6.into(),
-            self.7.into(),
-            self.8.into(),
-            self.9.into(),
-            self.10.into(),
-        ]
-    }
-}
+// Generic tuples
 
-impl<T, U1, U2, U3, U4, U5, U6, U7, U8, U9, U10, U11, U12> IntoNonZeroArray<T, 12>
-    for (U1, U2, U3, U4, U5, U6, U7, U8, U9, U10, U11, U12)
-where
-    U1: Into<T>,
-    U2: Into<T>,
-    U3: Into<T>,
-    U4: Into<T>,
-    U5: Into<T>,
-    U6: Into<T>,
-    U7: Into<T>,
-    U8: Into<T>,
-    U9: Into<T>,
-    U10: Into<T>,
-    U11: Into<T>,
-    U12: Into<T>,
-{
-    fn into_non_zero_array(self) -> [T; 12] {
-        [
-            self.0.into(),
-            self.1.into(),
-            self.2.into(),
-            self.3.into(),
-            self.4.into(),
-            self.5.into(),
-            self.6.into(),
-            self.7.into(),
-            self.8.into(),
-            self.9.into(),
-            self.10.into(),
-            self.11.into(),
-        ]
-    }
-}
+macro_rules! impl_into_non_zero_array_for_tuple {
+    ($n:literal; $($index:tt $u:ident),+) => {
+        impl<T, $($u),+> IntoNonZeroArray<T, $n> for ($($u,)+)
+        where
+            $($u: Into<T>,)+
+        {
+            fn into_non_zero_array(self) -> [T; $n] {
+                [$(self.$index.into()),+]
+            }
+        }
+    };
+}
+
+impl_into_non_zero_array_for_tuple!(1; 0 U1);
+impl_into_non_zero_array_for_tuple!(2; 0 U1, 1 U2);
+impl_into_non_zero_array_for_tuple!(3; 0 U1, 1 U2, 2 U3);
+impl_into_non_zero_array_for_tuple!(4; 0 U1, 1 U2, 2 U3, 3 U4);
+impl_into_non_zero_array_for_tuple!(5; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5);
+impl_into_non_zero_array_for_tuple!(6; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6);
+impl_into_non_zero_array_for_tuple!(7; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7);
+impl_into_non_zero_array_for_tuple!(8; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8);
+impl_into_non_zero_array_for_tuple!(9; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9);
+impl_into_non_zero_array_for_tuple!(10; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10);
+impl_into_non_zero_array_for_tuple!(11; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11);
+impl_into_non_zero_array_for_tuple!(12; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11, 11 U12);
+impl_into_non_zero_array_for_tuple!(13; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11, 11 U12, 12 U13);
+impl_into_non_zero_array_for_tuple!(14; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11, 11 U12, 12 U13, 13 U14);
+impl_into_non_zero_array_for_tuple!(15; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11, 11 U12, 12 U13, 13 U14, 14 U15);
+impl_into_non_zero_array_for_tuple!(16; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11, 11 U12, 12 U13, 13 U14, 14 U15, 15 U16);
+impl_into_non_zero_array_for_tuple!(17; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11, 11 U12, 12 U13, 13 U14, 14 U15, 15 U16, 16 U17);
+impl_into_non_zero_array_for_tuple!(18; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11, 11 U12, 12 U13, 13 U14, 14 U15, 15 U16, 16 U17, 17 U18);
+impl_into_non_zero_array_for_tuple!(19; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11, 11 U12, 12 U13, 13 U14, 14 U15, 15 U16, 16 U17, 17 U18, 18 U19);
+impl_into_non_zero_array_for_tuple!(20; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11, 11 U12, 12 U13, 13 U14, 14 U15, 15 U16, 16 U17, 17 U18, 18 U19, 19 U20);
+impl_into_non_zero_array_for_tuple!(21; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11, 11 U12, 12 U13, 13 U14, 14 U15, 15 U16, 16 U17, 17 U18, 18 U19, 19 U20, 20 U21);
+impl_into_non_zero_array_for_tuple!(22; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11, 11 U12, 12 U13, 13 U14, 14 U15, 15 U16, 16 U17, 17 U18, 18 U19, 19 U20, 20 U21, 21 U22);
+impl_into_non_zero_array_for_tuple!(23; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11, 11 U12, 12 U13, 13 U14, 14 U15, 15 U16, 16 U17, 17 U18, 18 U19, 19 U20, 20 U21, 21 U22, 22 U23);
+impl_into_non_zero_array_for_tuple!(24; 0 U1, 1 U2, 2 U3, 3 U4, 4 U5, 5 U6, 6 U7, 7 U8, 8 U9, 9 U10, 10 U11, 11 U12, 12 U13, 13 U14, 14 U15, 15 U16, 16 U17, 17 U18, 18 U19, 19 U20, 20 U21, 21 U22, 22 U23, 23 U24);